@@ -3,8 +3,8 @@
 precision mediump float;
 
 const float EPSILON = 1e-4;
-// Reflectance of a diamond at light incidence of theta = 0
-const vec3 F0 = vec3( 0.1724 );
+// Reflectance of the gem at light incidence of theta = 0, computed from `ior` in main
+vec3 F0;
 // Max distance to the surface in cubeNormalMap
 // This value was calculated during generation of the map
 const float MAX_DISTANCE = 5.7610855;
@@ -17,7 +17,10 @@ uniform mat4x4 modelMatrix;
 uniform mat4x4 inverseModelMatrix;
 
 uniform float envMapIntensity;
-uniform float rainbowDelta;
+// Refractive index of the gem for the green light
+uniform float ior;
+// Difference of the refractive indices for the blue and the red light
+uniform float dispersion;
 uniform float squashFactor;
 uniform float radius;
 uniform float geometryFactor;
@@ -156,8 +159,8 @@ vec3 getRefractionColor( vec3 rayHitPoint, vec3 rayDirection, vec3 hitPointNorma
 
   // Refractive index of air
   const float n1 = 1.0;
-  // Refractive index of a diamond
-  const float n2 = 2.42;
+  // Refractive index of the gem
+  float n2 = ior;
 
   float iorRatioAtoD = n1 / n2;
   float iorRatioDtoA = n2 / n1;
@@ -221,8 +224,9 @@ vec3 getRefractionColor( vec3 rayHitPoint, vec3 rayDirection, vec3 hitPointNorma
     else if( RaydotN < 0.99 ) 
     {
       vec3 dirGreen = newRefractedDirection;
-      vec3 dirRed = refract( newRayDirection, -surfaceNormal, ( n2 + rainbowDelta ) / n1 );
-      vec3 dirBlue = refract( newRayDirection, -surfaceNormal, ( n2 - rainbowDelta ) / n1 );
+      // Shorter wavelengths are refracted stronger
+      vec3 dirRed = refract( newRayDirection, -surfaceNormal, ( n2 - 0.5 * dispersion ) / n1 );
+      vec3 dirBlue = refract( newRayDirection, -surfaceNormal, ( n2 + 0.5 * dispersion ) / n1 );
 
       vec3 sampleColor = vec3
       (
@@ -253,6 +257,8 @@ vec3 getRefractionColor( vec3 rayHitPoint, vec3 rayDirection, vec3 hitPointNorma
 
 void main() 
 {
+  F0 = vec3( pow( ( ior - 1.0 ) / ( ior + 1.0 ), 2.0 ) );
+
   vec3 viewDirection = normalize( vWorldPosition - cameraPosition );
   vec3 reflectedDirection = reflect( viewDirection, vWorldNormal );
 
@@ -7,6 +7,58 @@ use gl::
   JsValue
 };
 
+/// Optical properties of the gem material
+#[ derive( Debug, Clone, Copy, PartialEq ) ]
+#[ allow( dead_code ) ]
+enum Gem
+{
+  Diamond,
+  Sapphire,
+  Ruby,
+  Emerald,
+}
+
+impl Gem
+{
+  /// Refractive index for the green light
+  fn ior( &self ) -> f32
+  {
+    match self
+    {
+      Gem::Diamond => 2.417,
+      Gem::Sapphire | Gem::Ruby => 1.770,
+      Gem::Emerald => 1.580,
+    }
+  }
+
+  /// Difference of the refractive indices between the blue and the red Fraunhofer lines,
+  /// responsible for the "fire" of the stone
+  fn dispersion( &self ) -> f32
+  {
+    match self
+    {
+      Gem::Diamond => 0.044,
+      Gem::Sapphire | Gem::Ruby => 0.018,
+      Gem::Emerald => 0.014,
+    }
+  }
+
+  /// Portion of the light of each channel that passes a unit of distance through the stone
+  fn color_absorption( &self ) -> [ f32; 3 ]
+  {
+    match self
+    {
+      Gem::Diamond => [ 0.9911, 0.9911, 0.9911 ],
+      Gem::Sapphire => [ 0.90, 0.94, 0.99 ],
+      Gem::Ruby => [ 0.99, 0.90, 0.92 ],
+      Gem::Emerald => [ 0.92, 0.99, 0.94 ],
+    }
+  }
+}
+
+/// Gem to render, the cut is defined by the model and its normal cube map
+const GEM : Gem = Gem::Diamond;
+
 async fn load_cube_texture( name : &str ) -> Result< [ image::RgbaImage; 6 ], JsValue >
 {
   let px = gl::file::load( &format!( "{}/PX.png", name ) ).await.expect( "Failed to load PX face" );
@@ -28,10 +80,9 @@ async fn load_cube_texture( name : &str ) -> Result< [ image::RgbaImage; 6 ], Js
   Ok( [ px, nx, py, ny, pz, nz ] )
 }
 
-fn upload_cube_texture( gl : &GL, faces : &[ image::RgbaImage ], location: u32 )
+fn upload_cube_texture( gl : &GL, faces : &[ image::RgbaImage ] ) -> Option< gl::web_sys::WebGlTexture >
 {
   let texture = gl.create_texture();
-  gl.active_texture( gl::TEXTURE0 + location );
   gl.bind_texture( gl::TEXTURE_CUBE_MAP, texture.as_ref() );
 
   for i in 0..faces.len() 
@@ -57,13 +108,16 @@ fn upload_cube_texture( gl : &GL, faces : &[ image::RgbaImage ], location: u32 )
   gl.tex_parameteri( gl::TEXTURE_CUBE_MAP, gl::TEXTURE_WRAP_S, gl::CLAMP_TO_EDGE as i32 );
   gl.tex_parameteri( gl::TEXTURE_CUBE_MAP, gl::TEXTURE_WRAP_T, gl::CLAMP_TO_EDGE as i32 );
   gl.tex_parameteri( gl::TEXTURE_CUBE_MAP, gl::TEXTURE_WRAP_R, gl::CLAMP_TO_EDGE as i32 );
+
+  texture
 }
 
 
 async fn run() -> Result< (), gl::WebglError >
 {
   gl::browser::setup( Default::default() );
-  let gl = gl::context::retrieve_or_make()?;
+  let canvas = gl::canvas::retrieve_or_make()?;
+  let gl = gl::context::from_canvas( &canvas )?;
 
   // Vertex and fragment shaders
   let vertex_shader_src = include_str!( "../shaders/shader.vert" );
@@ -126,7 +180,8 @@ async fn run() -> Result< (), gl::WebglError >
   let inverse_model_matrix_location = gl.get_uniform_location( &program, "inverseModelMatrix" );
 
   let env_map_intensity_location = gl.get_uniform_location( &program, "envMapIntensity" );
-  let rainbow_delta_location = gl.get_uniform_location( &program, "rainbowDelta" );
+  let ior_location = gl.get_uniform_location( &program, "ior" );
+  let dispersion_location = gl.get_uniform_location( &program, "dispersion" );
   let squash_factor_location = gl.get_uniform_location( &program, "squashFactor" );
   let radius_location = gl.get_uniform_location( &program, "radius" );
   let geometry_factor_location = gl.get_uniform_location( &program, "geometryFactor" );
@@ -135,26 +190,11 @@ async fn run() -> Result< (), gl::WebglError >
   let color_absorption_location = gl.get_uniform_location( &program, "colorAbsorption" );
   let camera_position_location = gl.get_uniform_location( &program, "cameraPosition" );
 
-  let env_map_location = 0;
-  let cube_normal_map_location = 1;
-
-  let width = gl.drawing_buffer_width() as f32;
-  let height = gl.drawing_buffer_height() as f32;
-
   // Camera setup
   
   let eye = glam::Vec3::new(  0.0, 3.0, 10.0 );
   let up = glam::Vec3::Y;
 
-  let aspect_ratio = width / height;
-  let perspective_matrix = glam::Mat4::perspective_rh_gl
-  (
-     70.0f32.to_radians(),  
-     aspect_ratio, 
-     0.1, 
-     1000.0
-  );
-
   let model_matrix = glam::Mat4::from_scale_rotation_translation
   (
     glam::Vec3::ONE, 
@@ -163,23 +203,45 @@ async fn run() -> Result< (), gl::WebglError >
   );
 
 
-  // Update uniform values
-  gl::uniform::matrix_upload( &gl, projection_matrix_location, &perspective_matrix.to_cols_array()[ .. ], true ).unwrap();
+  // Keep the drawing buffer, the viewport and the projection in sync with the size of the canvas
+  let responsive = gl::canvas::ResponsiveCanvas::new( &canvas, 2.0,
+  {
+    let gl = gl.clone();
+    move | width, height |
+    {
+      gl.viewport( 0, 0, width as i32, height as i32 );
+      let aspect_ratio = width as f32 / height as f32;
+      let perspective_matrix = glam::Mat4::perspective_rh_gl
+      (
+         70.0f32.to_radians(),  
+         aspect_ratio, 
+         0.1, 
+         1000.0
+      );
+      gl::uniform::matrix_upload( &gl, projection_matrix_location.clone(), &perspective_matrix.to_cols_array()[ .. ], true ).unwrap();
+    }
+  })?;
 
+  // Update uniform values
   gl::uniform::upload( &gl, env_map_intensity_location.clone(), &0.7 ).unwrap();
-  gl::uniform::upload( &gl, rainbow_delta_location.clone(), &0.01 ).unwrap();
+  gl::uniform::upload( &gl, ior_location.clone(), &GEM.ior() ).unwrap();
+  gl::uniform::upload( &gl, dispersion_location.clone(), &GEM.dispersion() ).unwrap();
   gl::uniform::upload( &gl, squash_factor_location.clone(), &0.8 ).unwrap();
   gl::uniform::upload( &gl, radius_location.clone(), &7.0 ).unwrap();
   gl::uniform::upload( &gl, geometry_factor_location.clone(), &0.5 ).unwrap();
   gl::uniform::upload( &gl, absorption_factor_location.clone(), &0.8 ).unwrap();
 
-  gl::uniform::upload( &gl, color_absorption_location.clone(), &[ 0.9911, 0.9911, 0.9911 ][ .. ] ).unwrap();
+  gl::uniform::upload( &gl, color_absorption_location.clone(), &GEM.color_absorption()[ .. ] ).unwrap();
 
-  gl.uniform1i( gl.get_uniform_location( &program, "envMap" ).as_ref(), env_map_location );
-  gl.uniform1i( gl.get_uniform_location( &program, "cubeNormalMap" ).as_ref(), cube_normal_map_location );
+  let mut texture_units = gl::texture::unit::TextureUnits::new( &program );
+  texture_units.assign( &gl, "envMap", gl::TEXTURE_CUBE_MAP )?;
+  texture_units.assign( &gl, "cubeNormalMap", gl::TEXTURE_CUBE_MAP )?;
 
-  upload_cube_texture( &gl, &env_map, env_map_location as u32 );
-  upload_cube_texture( &gl, &cube_normal_map, cube_normal_map_location as u32 );
+  // Uploading binds a texture to the active unit, so bind them to their units after all uploads
+  let env_map = upload_cube_texture( &gl, &env_map );
+  let cube_normal_map = upload_cube_texture( &gl, &cube_normal_map );
+  texture_units.bind( &gl, "envMap", env_map.as_ref() );
+  texture_units.bind( &gl, "cubeNormalMap", cube_normal_map.as_ref() );
 
   gl.enable( gl::DEPTH_TEST );
 
@@ -189,6 +251,8 @@ async fn run() -> Result< (), gl::WebglError >
     let indices_amount = indices.len();
     move | t : f64 |
     {
+      // Resizing stops when the observer is dropped, so it lives as long as the loop
+      let _ = &responsive;
       let time = t as f32 / 1000.0;
       let rotation = glam::Mat3::from_rotation_y( time );
       let eye = rotation * eye;
@@ -33,7 +33,8 @@ static INDICES : [ u16; 24 ] =
 fn run() -> Result< (), gl::WebglError >
 {
   gl::browser::setup( Default::default() );
-  let gl = gl::context::retrieve_or_make()?;
+  let canvas = gl::canvas::retrieve_or_make()?;
+  let gl = gl::context::from_canvas( &canvas )?;
 
   // Vertex and fragment shader source code
   let vertex_shader_src = include_str!( "../shaders/shader.vert" );
@@ -48,23 +49,30 @@ fn run() -> Result< (), gl::WebglError >
   let indeces_buffer = gl::buffer::create( &gl )?;
   gl::index::upload( &gl, &indeces_buffer, &INDICES, gl::GL::STATIC_DRAW );
 
-  let width = gl.drawing_buffer_width() as f32;
-  let height = gl.drawing_buffer_height() as f32;
-  let aspect_ratio = width / height;
-  let fov_y : f32 = 45.0;
-  let near_z = 0.1;
-  let far_z = 100.0;
-  let projection_matrix = gl::math::mat3x3h::perspective_rh_gl
-  (
-    fov_y.to_radians(),
-    aspect_ratio,
-    near_z,
-    far_z
-  );
-
   let projection_matrix_location = gl.get_uniform_location( &program, "projection_matrix" );
   let angle_location = gl.get_uniform_location( &program, "angle" );
-  gl::uniform::matrix_upload( &gl, projection_matrix_location, projection_matrix.raw_slice(), true ).unwrap();
+
+  // Keep the drawing buffer, the viewport and the projection in sync with the size of the canvas
+  let responsive = gl::canvas::ResponsiveCanvas::new( &canvas, 2.0,
+  {
+    let gl = gl.clone();
+    move | width, height |
+    {
+      gl.viewport( 0, 0, width as i32, height as i32 );
+      let aspect_ratio = width as f32 / height as f32;
+      let fov_y : f32 = 45.0;
+      let near_z = 0.1;
+      let far_z = 100.0;
+      let projection_matrix = gl::math::mat3x3h::perspective_rh_gl
+      (
+        fov_y.to_radians(),
+        aspect_ratio,
+        near_z,
+        far_z
+      );
+      gl::uniform::matrix_upload( &gl, projection_matrix_location.clone(), projection_matrix.raw_slice(), true ).unwrap();
+    }
+  })?;
 
   gl.enable( gl::DEPTH_TEST );
 
@@ -76,6 +84,8 @@ fn run() -> Result< (), gl::WebglError >
 
     move | mut t : f64 |
     {
+      // Resizing stops when the observer is dropped, so it lives as long as the loop
+      let _ = &responsive;
       t *= 0.001;
       angle = t;
 
@@ -21,17 +21,16 @@ async fn run() -> Result< (), gl::WebglError >
 
   let video_element = gl::dom::create_video_element( path, video_width, video_height )
   .expect( "Failed to create video element" );
-  let texture = gl.create_texture().expect( "Failed to create texture" );
-  gl.bind_texture( gl::TEXTURE_2D, Some( &texture ) );
-  gl::texture::d2::default_parameters( &gl );
-  
+  let mut video = gl::texture::video::VideoTexture::new( &gl, video_element )?;
+
   let update_and_draw =
   {
     move | _ |
     {
       gl.clear_color( 0.8, 0.8, 0.8, 1.0 );
       gl.clear( gl::COLOR_BUFFER_BIT );
-      gl::texture::d2::update_video( &gl, &texture, &video_element );
+      video.update( &gl );
+      gl.bind_texture( gl::TEXTURE_2D, Some( video.texture() ) );
 
       gl.draw_arrays( gl::TRIANGLE_STRIP, 0, 4 );
 
@@ -17,10 +17,16 @@ mod private
   }
 
   /// Setup both logging and panic.
+  /// If panic reports have `history`, it is registered as a log sink.
   pub fn setup( config : Config )
   {
-    panic::setup( config.panic );
-    log::setup( config.log );
+    let Config { mut log, panic } = config;
+    if let Some( history ) = &panic.history
+    {
+      log = log.sink( history.clone() );
+    }
+    panic::setup( panic );
+    log::setup( log );
   }
 
 }
@@ -38,5 +44,7 @@ mod_interface!
   layer log;
   /// Panic hook handling in Browser.
   layer panic;
+  /// Performance tracing.
+  layer trace;
 
 }
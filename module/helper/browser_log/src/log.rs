@@ -6,6 +6,8 @@
 //!
 //! - **Configurable Logging Levels**: Supports multiple log levels (Trace, Debug, Info, Warn, Error) with customizable styles.
 //! - **Target Filtering**: Allows filtering logs based on module paths, enabling focused logging for specific parts of your application.
+//! - **Rate Limiting**: `*_once!` and `*_limited!` macros keep per-frame warnings from flooding the console.
+//! - **Pluggable Sinks**: Records go to any number of destinations implementing `Sink`: the browser console, an on-screen overlay, a remote endpoint.
 //!
 //! ## Usage
 //!
@@ -19,11 +21,15 @@
 //!
 //! // Setup with target filtering
 //! browser_log::log::setup( browser_log::log::Config::default().target_filter( "lib_name" ) );
+//!
+//! // Setup with on-screen overlay in addition to the console, useful on mobile browsers without devtools
+//! browser_log::log::setup( browser_log::log::Config::default().sink( browser_log::log::OverlaySink::new( "log" ) ) );
 //! ```
 //!
 //! ## Configuration
 //!
-//! - **Config**: Use the `Config` struct to specify the maximum log level and optional target filtering.
+//! - **Config**: Use the `Config` struct to specify the maximum log level, optional target filtering and sinks.
+//! - **Sink**: Implement the `Sink` trait to forward records somewhere else, for example to a remote endpoint.
 //! - **Predefined**: Log messages are styled with CSS for better readability in the console.
 //!
 //! This module leverages the `web_sys` crate to interact with the browser's console, ensuring that log messages are displayed with appropriate styling and context.
@@ -38,6 +44,10 @@ crate::mod_interface!
 
   layer debug_log;
   layer setup;
+  layer sink;
+  layer overlay;
+  layer history;
+  layer rate;
 
   exposed use ::web_sys::console;
   orphan use ::log::*;
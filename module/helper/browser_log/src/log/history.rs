@@ -0,0 +1,76 @@
+//! Memory of the latest log records.
+
+/// Internal namespace.
+mod private
+{
+  use crate::log::Sink;
+  use ::log::Record;
+  use std::
+  {
+    collections::VecDeque,
+    sync::{ Arc, Mutex },
+  };
+
+  #[ derive( Debug ) ]
+  struct Inner
+  {
+    capacity : usize,
+    lines : Mutex< VecDeque< String > >,
+  }
+
+  /// Sink remembering formatted text of the last `capacity` records.
+  ///
+  /// Clones share the same buffer. Pass a clone to `panic::Config::history` to attach the latest log lines to panic reports.
+  #[ derive( Debug, Clone ) ]
+  pub struct HistorySink
+  {
+    inner : Arc< Inner >,
+  }
+
+  impl HistorySink
+  {
+    /// Remember up to `capacity` records.
+    pub fn new( capacity : usize ) -> Self
+    {
+      let inner = Inner { capacity, lines : Mutex::new( VecDeque::with_capacity( capacity ) ) };
+      Self { inner : Arc::new( inner ) }
+    }
+
+    /// Remembered records, the oldest first.
+    pub fn lines( &self ) -> Vec< String >
+    {
+      // Poisoning is not a reason to lose the history, it is needed the most while panicking.
+      let lines = self.inner.lines.lock().unwrap_or_else( | e | e.into_inner() );
+      lines.iter().cloned().collect()
+    }
+  }
+
+  impl Sink for HistorySink
+  {
+    fn write( &self, record : &Record< '_ > )
+    {
+      if self.inner.capacity == 0
+      {
+        return;
+      }
+      let line = format!( "{} {}: {}", record.level(), record.target(), record.args() );
+      let mut lines = self.inner.lines.lock().unwrap_or_else( | e | e.into_inner() );
+      if lines.len() == self.inner.capacity
+      {
+        lines.pop_front();
+      }
+      lines.push_back( line );
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  orphan use
+  {
+    HistorySink,
+  };
+
+}
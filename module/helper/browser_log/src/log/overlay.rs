@@ -0,0 +1,236 @@
+//! On-screen log console.
+//!
+//! Mobile browsers usually have no devtools, so `OverlaySink` renders the latest log records into a fixed DOM panel on top of the page.
+//! The panel keeps only the last `capacity` records and has its own level filter which can be changed at runtime
+//! through any clone of the sink.
+//!
+//! ```rust, no_run
+//! use browser_log::log::{ Config, Level, OverlaySink };
+//!
+//! let overlay = OverlaySink::new( "log_overlay" ).capacity( 50 );
+//! browser_log::log::setup( Config::default().sink( overlay.clone() ) );
+//! // Later, for example from a debug menu.
+//! overlay.set_level( Level::Warn );
+//! ```
+
+/// Internal namespace.
+mod private
+{
+  use crate::log::Sink;
+  use ::log::{ Level, Record };
+  use std::
+  {
+    collections::VecDeque,
+    sync::{ Arc, Mutex },
+  };
+
+  const PANEL_STYLE : &str = "position: fixed; left: 0; right: 0; bottom: 0; max-height: 40%; overflow-y: auto; \
+    margin: 0; padding: 4px; z-index: 2147483647; pointer-events: none; \
+    background: rgba( 0, 0, 0, 0.7 ); color: white; font: 11px monospace; white-space: pre-wrap;";
+
+  #[ derive( Debug ) ]
+  struct State
+  {
+    level : Level,
+    capacity : usize,
+    lines : VecDeque< ( Level, String ) >,
+  }
+
+  #[ derive( Debug ) ]
+  struct Inner
+  {
+    element_id : String,
+    state : Mutex< State >,
+  }
+
+  /// Sink rendering records into a DOM panel.
+  ///
+  /// Clones share the same panel and ring buffer, so keep a clone to change the level or clear the panel after setup.
+  #[ derive( Debug, Clone ) ]
+  pub struct OverlaySink
+  {
+    inner : Arc< Inner >,
+  }
+
+  impl OverlaySink
+  {
+    /// Overlay in the element with the given id. The element is created and appended to `body` if it does not exist.
+    /// By default the last 100 records of any level are shown.
+    pub fn new( element_id : &str ) -> Self
+    {
+      let state = State { level : Level::Trace, capacity : 100, lines : VecDeque::new() };
+      let inner = Inner { element_id : element_id.to_string(), state : Mutex::new( state ) };
+      Self { inner : Arc::new( inner ) }
+    }
+
+    /// Number of the latest records kept in the panel.
+    /// Changes the capacity of the panel shared by all clones, the oldest records beyond it are dropped.
+    pub fn capacity( self, capacity : usize ) -> Self
+    {
+      {
+        let mut state = self.state();
+        state.capacity = capacity;
+        let excess = state.lines.len().saturating_sub( capacity );
+        state.lines.drain( ..excess );
+      }
+      self.render();
+      self
+    }
+
+    /// Most verbose level shown in the panel.
+    pub fn level( &self ) -> Level
+    {
+      self.state().level
+    }
+
+    /// Change the most verbose level shown in the panel. Records which are already in the panel are filtered as well.
+    pub fn set_level( &self, level : Level )
+    {
+      self.state().level = level;
+      self.render();
+    }
+
+    /// Remove all records from the panel.
+    pub fn clear( &self )
+    {
+      self.state().lines.clear();
+      self.render();
+    }
+
+    /// Records kept in the panel with their levels, the oldest first, including the ones hidden by the level filter.
+    pub fn lines( &self ) -> Vec< ( Level, String ) >
+    {
+      self.state().lines.iter().cloned().collect()
+    }
+
+    fn state( &self ) -> std::sync::MutexGuard< '_, State >
+    {
+      // Poisoning is not a reason to stop logging.
+      self.inner.state.lock().unwrap_or_else( | e | e.into_inner() )
+    }
+
+    /// The panel and whether it was just created.
+    #[ cfg( target_arch = "wasm32" ) ]
+    fn panel( &self ) -> Option< ( web_sys::Element, bool ) >
+    {
+      let document = web_sys::window().and_then( | w | w.document() )?;
+      if let Some( panel ) = document.get_element_by_id( &self.inner.element_id )
+      {
+        return Some( ( panel, false ) );
+      }
+
+      let panel = document.create_element( "pre" ).ok()?;
+      panel.set_id( &self.inner.element_id );
+      let _ = panel.set_attribute( "style", PANEL_STYLE );
+      document.body()?.append_child( &panel ).ok()?;
+      Some( ( panel, true ) )
+    }
+
+    /// There is no DOM outside of the browser, only the ring buffer is kept.
+    #[ cfg( not( target_arch = "wasm32" ) ) ]
+    fn panel( &self ) -> Option< ( web_sys::Element, bool ) >
+    {
+      None
+    }
+
+    /// Rebuild the panel from the ring buffer.
+    fn render( &self )
+    {
+      let Some( ( panel, _ ) ) = self.panel() else { return };
+
+      let state = self.state();
+      let mut html = String::new();
+      for ( level, line ) in state.lines.iter().filter( | ( level, _ ) | *level <= state.level )
+      {
+        html.push_str( &format!( "<div style=\"color: {};\">{}</div>", color( *level ), escape( line ) ) );
+      }
+      drop( state );
+
+      panel.set_inner_html( &html );
+      panel.set_scroll_top( panel.scroll_height() );
+    }
+  }
+
+  impl Sink for OverlaySink
+  {
+    fn write( &self, record : &Record< '_ > )
+    {
+      let ( level, line, dropped ) =
+      {
+        let mut state = self.state();
+        if state.capacity == 0
+        {
+          return;
+        }
+        let dropped = if state.lines.len() >= state.capacity { state.lines.pop_front() } else { None };
+        let line = format!( "{:<5} {}: {}", record.level(), record.target(), record.args() );
+        state.lines.push_back( ( record.level(), line.clone() ) );
+        // Only records passing the filter have a node in the panel
+        let dropped = dropped.is_some_and( | ( level, _ ) | level <= state.level );
+        ( ( record.level() <= state.level ).then_some( record.level() ), line, dropped )
+      };
+
+      let Some( ( panel, created ) ) = self.panel() else { return };
+      if created
+      {
+        self.render();
+        return;
+      }
+
+      if dropped
+      {
+        if let Some( oldest ) = panel.first_element_child()
+        {
+          oldest.remove();
+        }
+      }
+      let Some( level ) = level else { return };
+      let Some( document ) = panel.owner_document() else { return };
+      let Ok( node ) = document.create_element( "div" ) else { return };
+      let _ = node.set_attribute( "style", &format!( "color: {};", color( level ) ) );
+      node.set_text_content( Some( &line ) );
+      let _ = panel.append_child( &node );
+      panel.set_scroll_top( panel.scroll_height() );
+    }
+  }
+
+  fn color( level : Level ) -> &'static str
+  {
+    match level
+    {
+      Level::Trace => "gray",
+      Level::Debug => "lightskyblue",
+      Level::Info => "lightgreen",
+      Level::Warn => "orange",
+      Level::Error => "tomato",
+    }
+  }
+
+  fn escape( src : &str ) -> String
+  {
+    let mut result = String::with_capacity( src.len() );
+    for c in src.chars()
+    {
+      match c
+      {
+        '&' => result.push_str( "&amp;" ),
+        '<' => result.push_str( "&lt;" ),
+        '>' => result.push_str( "&gt;" ),
+        '"' => result.push_str( "&quot;" ),
+        c => result.push( c ),
+      }
+    }
+    result
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  orphan use
+  {
+    OverlaySink,
+  };
+
+}
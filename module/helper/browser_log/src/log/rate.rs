@@ -0,0 +1,192 @@
+//! Rate-limited and deduplicated logging.
+//!
+//! Per-frame warnings, for example about a missing uniform, flood the console and tank the frame rate.
+//! Macros of this module keep a state per callsite and drop repeated records:
+//!
+//! - `log_once!`, `error_once!`, `warn_once!`, `info_once!`, `debug_once!`, `trace_once!` log only the first time the callsite is reached.
+//! - `log_limited!`, `error_limited!`, `warn_limited!`, `info_limited!`, `debug_limited!`, `trace_limited!` log at most once per interval in milliseconds
+//!   and report how many records were suppressed in between.
+//!
+//! Records of disabled levels don't count, so they don't use up the first record or the interval.
+//!
+//! ```rust
+//! for frame in 0..1000
+//! {
+//!   browser_log::warn_once!( "Uniform `u_time` is missing" );
+//!   browser_log::warn_limited!( 1000, "Frame {frame} took too long" );
+//! }
+//! ```
+
+/// Internal namespace.
+mod private
+{
+  use core::sync::atomic::{ AtomicBool, AtomicU64, Ordering };
+
+  /// Per-callsite state of `*_once!` macros.
+  #[ derive( Debug ) ]
+  pub struct Once( AtomicBool );
+
+  impl Once
+  {
+    /// Not yet triggered.
+    pub const fn new() -> Self
+    {
+      Self( AtomicBool::new( false ) )
+    }
+
+    /// `true` only on the first call.
+    pub fn first( &self ) -> bool
+    {
+      !self.0.swap( true, Ordering::Relaxed )
+    }
+  }
+
+  impl Default for Once
+  {
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+  /// Per-callsite state of `*_limited!` macros.
+  #[ derive( Debug ) ]
+  pub struct RateLimit
+  {
+    interval_ms : u64,
+    /// Time of the last passed record, `u64::MAX` if there was none.
+    last_ms : AtomicU64,
+    suppressed : AtomicU64,
+  }
+
+  impl RateLimit
+  {
+    /// Pass at most one record per `interval_ms` milliseconds.
+    pub const fn new( interval_ms : u64 ) -> Self
+    {
+      Self
+      {
+        interval_ms,
+        last_ms : AtomicU64::new( u64::MAX ),
+        suppressed : AtomicU64::new( 0 ),
+      }
+    }
+
+    /// Check the limit at the given time. Returns number of records suppressed since the last passed one
+    /// if a record should be logged now, or `None` if it should be suppressed.
+    pub fn check_at( &self, now_ms : u64 ) -> Option< u64 >
+    {
+      let last_ms = self.last_ms.load( Ordering::Relaxed );
+      if last_ms != u64::MAX && now_ms.saturating_sub( last_ms ) < self.interval_ms
+      {
+        self.suppressed.fetch_add( 1, Ordering::Relaxed );
+        return None;
+      }
+      self.last_ms.store( now_ms, Ordering::Relaxed );
+      Some( self.suppressed.swap( 0, Ordering::Relaxed ) )
+    }
+
+    /// Check the limit now. See `check_at`.
+    pub fn check( &self ) -> Option< u64 >
+    {
+      self.check_at( now_ms() )
+    }
+  }
+
+  /// Milliseconds since the unix epoch. `std::time::Instant` is not available in the browser.
+  #[ cfg( target_arch = "wasm32" ) ]
+  fn now_ms() -> u64
+  {
+    js_sys::Date::now() as u64
+  }
+
+  /// Milliseconds since the unix epoch.
+  #[ cfg( not( target_arch = "wasm32" ) ) ]
+  fn now_ms() -> u64
+  {
+    std::time::SystemTime::now()
+    .duration_since( std::time::UNIX_EPOCH )
+    .map_or( 0, | d | d.as_millis() as u64 )
+  }
+
+}
+
+/// Log only the first time the callsite is reached. Syntax is the same as `log!` without `target:`.
+#[ macro_export ]
+macro_rules! log_once
+{
+  ( $lvl : expr, $( $arg : tt )+ ) =>
+  {{
+    static ONCE : $crate::log::Once = $crate::log::Once::new();
+    if $crate::log::log_enabled!( $lvl ) && ONCE.first()
+    {
+      $crate::log::log!( $lvl, $( $arg )+ );
+    }
+  }};
+}
+
+/// Log error only the first time the callsite is reached.
+#[ macro_export ]
+macro_rules! error_once { ( $( $arg : tt )+ ) => { $crate::log_once!( $crate::log::Level::Error, $( $arg )+ ) }; }
+/// Log warning only the first time the callsite is reached.
+#[ macro_export ]
+macro_rules! warn_once { ( $( $arg : tt )+ ) => { $crate::log_once!( $crate::log::Level::Warn, $( $arg )+ ) }; }
+/// Log info only the first time the callsite is reached.
+#[ macro_export ]
+macro_rules! info_once { ( $( $arg : tt )+ ) => { $crate::log_once!( $crate::log::Level::Info, $( $arg )+ ) }; }
+/// Log debug only the first time the callsite is reached.
+#[ macro_export ]
+macro_rules! debug_once { ( $( $arg : tt )+ ) => { $crate::log_once!( $crate::log::Level::Debug, $( $arg )+ ) }; }
+/// Log trace only the first time the callsite is reached.
+#[ macro_export ]
+macro_rules! trace_once { ( $( $arg : tt )+ ) => { $crate::log_once!( $crate::log::Level::Trace, $( $arg )+ ) }; }
+
+/// Log at most once per interval in milliseconds, which must be a constant expression.
+/// A record passed after suppressed ones mentions how many were suppressed.
+#[ macro_export ]
+macro_rules! log_limited
+{
+  ( $interval_ms : expr, $lvl : expr, $( $arg : tt )+ ) =>
+  {{
+    static LIMIT : $crate::log::RateLimit = $crate::log::RateLimit::new( $interval_ms );
+    if $crate::log::log_enabled!( $lvl )
+    {
+      match LIMIT.check()
+      {
+        Some( 0 ) => $crate::log::log!( $lvl, $( $arg )+ ),
+        Some( suppressed ) =>
+        {
+          $crate::log::log!( $lvl, "{} ( {} similar records suppressed )", format_args!( $( $arg )+ ), suppressed )
+        }
+        None => {}
+      }
+    }
+  }};
+}
+
+/// Log error at most once per interval in milliseconds.
+#[ macro_export ]
+macro_rules! error_limited { ( $interval_ms : expr, $( $arg : tt )+ ) => { $crate::log_limited!( $interval_ms, $crate::log::Level::Error, $( $arg )+ ) }; }
+/// Log warning at most once per interval in milliseconds.
+#[ macro_export ]
+macro_rules! warn_limited { ( $interval_ms : expr, $( $arg : tt )+ ) => { $crate::log_limited!( $interval_ms, $crate::log::Level::Warn, $( $arg )+ ) }; }
+/// Log info at most once per interval in milliseconds.
+#[ macro_export ]
+macro_rules! info_limited { ( $interval_ms : expr, $( $arg : tt )+ ) => { $crate::log_limited!( $interval_ms, $crate::log::Level::Info, $( $arg )+ ) }; }
+/// Log debug at most once per interval in milliseconds.
+#[ macro_export ]
+macro_rules! debug_limited { ( $interval_ms : expr, $( $arg : tt )+ ) => { $crate::log_limited!( $interval_ms, $crate::log::Level::Debug, $( $arg )+ ) }; }
+/// Log trace at most once per interval in milliseconds.
+#[ macro_export ]
+macro_rules! trace_limited { ( $interval_ms : expr, $( $arg : tt )+ ) => { $crate::log_limited!( $interval_ms, $crate::log::Level::Trace, $( $arg )+ ) }; }
+
+crate::mod_interface!
+{
+
+  orphan use
+  {
+    Once,
+    RateLimit,
+  };
+
+}
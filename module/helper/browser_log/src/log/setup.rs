@@ -3,29 +3,25 @@
 /// Internal namespace.
 mod private
 {
-  // use crate::*;
-
+  use crate::log::{ Sink, ConsoleSink };
   use ::log::{ Level, Log, Metadata, Record };
   use wasm_bindgen::prelude::*;
   pub use web_sys::console;
 
-  /// Specify what to be logged
+  /// Specify what to be logged and where
   #[ derive( Debug ) ]
   pub struct Config
   {
     level : Level,
     target_filter : Option< String >,
+    sinks : Vec< Box< dyn Sink > >,
   }
 
   impl Default for Config
   {
     fn default() -> Self
     {
-      Self
-      {
-        level : Level::Debug,
-        target_filter : None,
-      }
+      Self::new( Level::Debug )
     }
   }
 
@@ -38,6 +34,7 @@ mod private
       {
         level,
         target_filter : None,
+        sinks : vec![ Box::new( ConsoleSink::default() ) ],
       }
     }
 
@@ -51,43 +48,26 @@ mod private
       self
     }
 
-  }
-
-  /// The log styles
-  struct Predefined
-  {
-    lvl_trace : String,
-    lvl_debug : String,
-    lvl_info : String,
-    lvl_warn : String,
-    lvl_error : String,
-    tgt : String,
-    args : String,
-  }
+    /// Add one more destination of records. Records go to the browser console by default.
+    pub fn sink( mut self, sink : impl Sink + 'static ) -> Self
+    {
+      self.sinks.push( Box::new( sink ) );
+      self
+    }
 
-  impl Predefined
-  {
-    fn new() -> Predefined
+    /// Replace all destinations of records, including the default console sink.
+    pub fn sinks( mut self, sinks : Vec< Box< dyn Sink > > ) -> Self
     {
-      let base = String::from( "color: white; padding: 0 3px; background:" );
-      Predefined
-      {
-        lvl_trace : format!( "{} gray;", base ),
-        lvl_debug : format!( "{} blue;", base ),
-        lvl_info : format!( "{} green;", base ),
-        lvl_warn : format!( "{} orange;", base ),
-        lvl_error : format!( "{} darkred;", base ),
-        tgt : String::from( "font-weight: bold; color: inherit" ),
-        args : String::from( "background: inherit; color: inherit" ),
-      }
+      self.sinks = sinks;
+      self
     }
+
   }
 
   /// The logger
   struct BrowserLogger
   {
     config : Config,
-    style : Predefined,
   }
 
   impl Log for BrowserLogger
@@ -108,55 +88,20 @@ mod private
     {
       if self.enabled( record.metadata() )
       {
-        let style = &self.style;
-        let s = format!
-        (
-          "%c{}%c {}:{}%c\n{}",
-          record.level(),
-          record.file().unwrap_or_else( || record.target() ),
-          record
-          .line()
-          .map_or_else( || "[ Unknown ]".to_string(), |line| line.to_string() ),
-          record.args(),
-        );
-        let s = JsValue::from_str( &s );
-        let tgt_style = JsValue::from_str( &style.tgt );
-        let args_style = JsValue::from_str( &style.args );
-        match record.level()
+        for sink in &self.config.sinks
         {
-          Level::Trace => console::debug_4
-          (
-            &s,
-            &JsValue::from( &style.lvl_trace ),
-            &tgt_style,
-            &args_style,
-          ),
-          Level::Debug => console::log_4
-          (
-            &s,
-            &JsValue::from( &style.lvl_debug ),
-            &tgt_style,
-            &args_style,
-          ),
-          Level::Info =>
-          {
-            console::info_4( &s, &JsValue::from( &style.lvl_info ), &tgt_style, &args_style )
-          }
-          Level::Warn =>
-          {
-            console::warn_4( &s, &JsValue::from( &style.lvl_warn ), &tgt_style, &args_style )
-          }
-          Level::Error => console::error_4(
-            &s,
-            &JsValue::from( &style.lvl_error ),
-            &tgt_style,
-            &args_style,
-          ),
+          sink.write( record );
         }
       }
     }
 
-    fn flush( &self ) {}
+    fn flush( &self )
+    {
+      for sink in &self.config.sinks
+      {
+        sink.flush();
+      }
+    }
   }
 
   /// Initialize the logger which the given config. If failed, it will log a message to the the browser console.
@@ -175,7 +120,6 @@ mod private
     let wl = BrowserLogger
     {
       config,
-      style : Predefined::new(),
     };
     match ::log::set_boxed_logger( Box::new( wl ) )
     {
@@ -0,0 +1,136 @@
+//! Destinations of log records.
+
+/// Internal namespace.
+mod private
+{
+  // use crate::*;
+
+  use ::log::{ Level, Record };
+  use core::fmt;
+  use wasm_bindgen::prelude::*;
+  use web_sys::console;
+
+  /// Destination of log records.
+  ///
+  /// The logger passes every record which survived level and target filtering to each of its sinks,
+  /// so the same record may end up in the console, in the on-screen overlay and on a remote endpoint simultaneously.
+  /// Implement this trait to forward records anywhere else.
+  pub trait Sink : fmt::Debug + Send + Sync
+  {
+    /// Write a single record.
+    fn write( &self, record : &Record< '_ > );
+
+    /// Flush buffered records, if the sink buffers anything.
+    fn flush( &self ) {}
+  }
+
+  /// The log styles
+  #[ derive( Debug ) ]
+  struct Predefined
+  {
+    lvl_trace : String,
+    lvl_debug : String,
+    lvl_info : String,
+    lvl_warn : String,
+    lvl_error : String,
+    tgt : String,
+    args : String,
+  }
+
+  impl Predefined
+  {
+    fn new() -> Predefined
+    {
+      let base = String::from( "color: white; padding: 0 3px; background:" );
+      Predefined
+      {
+        lvl_trace : format!( "{} gray;", base ),
+        lvl_debug : format!( "{} blue;", base ),
+        lvl_info : format!( "{} green;", base ),
+        lvl_warn : format!( "{} orange;", base ),
+        lvl_error : format!( "{} darkred;", base ),
+        tgt : String::from( "font-weight: bold; color: inherit" ),
+        args : String::from( "background: inherit; color: inherit" ),
+      }
+    }
+  }
+
+  /// Sink writing styled records into the browser console.
+  #[ derive( Debug ) ]
+  pub struct ConsoleSink
+  {
+    style : Predefined,
+  }
+
+  impl Default for ConsoleSink
+  {
+    fn default() -> Self
+    {
+      Self { style : Predefined::new() }
+    }
+  }
+
+  impl Sink for ConsoleSink
+  {
+    fn write( &self, record : &Record< '_ > )
+    {
+      let style = &self.style;
+      let s = format!
+      (
+        "%c{}%c {}:{}%c\n{}",
+        record.level(),
+        record.file().unwrap_or_else( || record.target() ),
+        record
+        .line()
+        .map_or_else( || "[ Unknown ]".to_string(), |line| line.to_string() ),
+        record.args(),
+      );
+      let s = JsValue::from_str( &s );
+      let tgt_style = JsValue::from_str( &style.tgt );
+      let args_style = JsValue::from_str( &style.args );
+      match record.level()
+      {
+        Level::Trace => console::debug_4
+        (
+          &s,
+          &JsValue::from( &style.lvl_trace ),
+          &tgt_style,
+          &args_style,
+        ),
+        Level::Debug => console::log_4
+        (
+          &s,
+          &JsValue::from( &style.lvl_debug ),
+          &tgt_style,
+          &args_style,
+        ),
+        Level::Info =>
+        {
+          console::info_4( &s, &JsValue::from( &style.lvl_info ), &tgt_style, &args_style )
+        }
+        Level::Warn =>
+        {
+          console::warn_4( &s, &JsValue::from( &style.lvl_warn ), &tgt_style, &args_style )
+        }
+        Level::Error => console::error_4(
+          &s,
+          &JsValue::from( &style.lvl_error ),
+          &tgt_style,
+          &args_style,
+        ),
+      }
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  orphan use
+  {
+    Sink,
+    ConsoleSink,
+  };
+
+}
@@ -35,6 +35,26 @@
 //! }
 //! ```
 //!
+//! ## Panic Reports
+//!
+//! Set `on_report` to receive a structured `Report` with the panic message, location, symbolicated stack trace,
+//! user agent, page url and the latest log lines, for example to upload it to a server:
+//!
+//! ```rust, no_run
+//! use browser_log::{ log::HistorySink, panic };
+//!
+//! let history = HistorySink::new( 20 );
+//! let config = panic::Config
+//! {
+//!   history : Some( history.clone() ),
+//!   on_report : Some( Box::new( | report : &panic::Report | { let _ = &report.message; /* upload */ } ) ),
+//!   ..Default::default()
+//! };
+//! browser_log::setup( browser_log::Config { panic : config, ..Default::default() } );
+//! ```
+//!
+//! The top-level `browser_log::setup` registers `history` as a log sink, so log records end up in the report.
+//!
 //! ## Advanced Configuration
 //!
 //! ### Increasing Stack Trace Depth
@@ -46,14 +66,24 @@ mod private
   // use crate::*;
 
   use std::panic;
+  use core::fmt;
+
+  /// Callback receiving panic reports.
+  pub type ReportCallback = Box< dyn Fn( &Report ) + Send + Sync >;
 
   /// A panic hook designed for use with
   /// [`std::panic::set_hook`](https://doc.rust-lang.org/nightly/std/panic/fn.set_hook.html).
   /// It logs panic messages to `console.error` in WebAssembly environments.
   /// For non-WASM targets, it outputs the panic to standard error.
+  /// If `on_report` is configured, it is called with the report afterwards.
   pub fn hook( info : &panic::PanicInfo< '_ >, config : &Config )
   {
-    hook_impl( info, config );
+    let report = Report::capture( info, config );
+    imp::print( &report.to_string() );
+    if let Some( on_report ) = &config.on_report
+    {
+      on_report( &report );
+    }
   }
 
   /// Configures the panic hook to use `console.error` for logging. This function
@@ -69,7 +99,6 @@ mod private
   }
 
   /// Specify how to handle panic.
-  #[ derive( Debug ) ]
   pub struct Config
   {
     // qqq : cover by test
@@ -78,6 +107,10 @@ mod private
     // qqq : cover by test
     /// Print stack trace.
     pub with_stack_trace : bool,
+    /// Source of the latest log lines attached to the report.
+    pub history : Option< crate::log::HistorySink >,
+    /// Called with the report of every panic, after it is printed.
+    pub on_report : Option< ReportCallback >,
   }
 
   impl Default for Config
@@ -88,15 +121,181 @@ mod private
       {
         with_location : true,
         with_stack_trace : true,
+        history : None,
+        on_report : None,
       }
     }
   }
 
+  impl fmt::Debug for Config
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      f.debug_struct( "Config" )
+      .field( "with_location", &self.with_location )
+      .field( "with_stack_trace", &self.with_stack_trace )
+      .field( "history", &self.history )
+      .field( "on_report", &self.on_report.as_ref().map( | _ | "Fn( &Report )" ) )
+      .finish()
+    }
+  }
+
+  /// Everything known about a panic.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct Report
+  {
+    /// Panic as std formats it: `panicked at file:line:column:` followed by the payload.
+    pub summary : String,
+    /// Panic payload if it is a string, which is almost always the case.
+    pub message : Option< String >,
+    /// `file:line:column` of the panic.
+    pub location : Option< String >,
+    /// Stack trace with mangled Rust symbols demangled, if `with_stack_trace` is enabled and the platform provides it.
+    pub stack_trace : Option< String >,
+    /// `navigator.userAgent` of the browser.
+    pub user_agent : Option< String >,
+    /// Url of the page.
+    pub url : Option< String >,
+    /// Latest log lines, the oldest first. Empty if `history` is not configured.
+    pub log : Vec< String >,
+  }
+
+  impl Report
+  {
+    /// Gather report about the panic.
+    pub fn capture( info : &panic::PanicInfo< '_ >, config : &Config ) -> Self
+    {
+      let payload = info.payload();
+      let message = payload.downcast_ref::< &str >().map( | s | s.to_string() )
+      .or_else( || payload.downcast_ref::< String >().cloned() );
+      let location = info.location()
+      .filter( | _ | config.with_location )
+      .map( | l | format!( "{}:{}:{}", l.file(), l.line(), l.column() ) );
+      let stack_trace = if config.with_stack_trace
+      {
+        imp::stack_trace().map( | stack | demangle( &stack ) )
+      }
+      else
+      {
+        None
+      };
+      let log = config.history.as_ref().map( | h | h.lines() ).unwrap_or_default();
+
+      Self
+      {
+        summary : info.to_string(),
+        message,
+        location,
+        stack_trace,
+        user_agent : imp::user_agent(),
+        url : imp::url(),
+        log,
+      }
+    }
+  }
+
+  impl fmt::Display for Report
+  {
+    fn fmt( &self, f : &mut fmt::Formatter< '_ > ) -> fmt::Result
+    {
+      write!( f, "=== Error\n\n{}", self.summary )?;
+      if let Some( location ) = &self.location
+      {
+        write!( f, "\n\n = Location:\n\n {location}" )?;
+      }
+      if let Some( stack_trace ) = &self.stack_trace
+      {
+        write!( f, "\n\n = Stack Trace:\n\n{stack_trace}\n\n" )?;
+      }
+      Ok( () )
+    }
+  }
+
+  /// Demangle legacy Rust symbols ( `_ZN4core9panicking5panic17h0123456789abcdefE` ) found in the text,
+  /// turning wasm stack traces of browsers which do not demangle them into readable paths ( `core::panicking::panic` ).
+  /// Anything which does not look like a mangled symbol is left intact.
+  pub fn demangle( src : &str ) -> String
+  {
+    let mut result = String::with_capacity( src.len() );
+    let mut rest = src;
+    while let Some( start ) = rest.find( "_ZN" )
+    {
+      result.push_str( &rest[ .. start ] );
+      let symbol = &rest[ start + 3 .. ];
+      match demangle_symbol( symbol )
+      {
+        Some( ( path, consumed ) ) =>
+        {
+          result.push_str( &path );
+          rest = &symbol[ consumed .. ];
+        }
+        None =>
+        {
+          result.push_str( "_ZN" );
+          rest = symbol;
+        }
+      }
+    }
+    result.push_str( rest );
+    result
+  }
+
+  /// Parse `<len><ident>...E`, returning the path and number of consumed bytes.
+  fn demangle_symbol( src : &str ) -> Option< ( String, usize ) >
+  {
+    let bytes = src.as_bytes();
+    let mut i = 0;
+    let mut segments = Vec::new();
+    loop
+    {
+      match bytes.get( i )
+      {
+        Some( b'E' ) if !segments.is_empty() => break,
+        Some( b ) if b.is_ascii_digit() => {},
+        _ => return None,
+      }
+      let digits = bytes[ i .. ].iter().take_while( | b | b.is_ascii_digit() ).count();
+      let len : usize = src[ i .. i + digits ].parse().ok()?;
+      i += digits;
+      let end = i.checked_add( len )?;
+      let segment = src.get( i .. end )?;
+      segments.push( segment );
+      i = end;
+    }
+
+    // Drop the trailing hash segment.
+    if let Some( last ) = segments.last()
+    {
+      if last.len() == 17 && last.starts_with( 'h' ) && last[ 1 .. ].bytes().all( | b | b.is_ascii_hexdigit() )
+      {
+        segments.pop();
+      }
+    }
+
+    let path = segments.iter().map( | s | unescape( s ) ).collect::< Vec< _ > >().join( "::" );
+    Some( ( path, i.checked_add( 1 )? ) )
+  }
+
+  fn unescape( segment : &str ) -> String
+  {
+    const ESCAPES : &[ ( &str, &str ) ] =
+    &[
+      ( "$SP$", "@" ), ( "$BP$", "*" ), ( "$RF$", "&" ), ( "$LT$", "<" ), ( "$GT$", ">" ),
+      ( "$LP$", "(" ), ( "$RP$", ")" ), ( "$C$", "," ),
+      ( "$u20$", " " ), ( "$u22$", "\"" ), ( "$u27$", "'" ), ( "$u2b$", "+" ), ( "$u3b$", ";" ),
+      ( "$u5b$", "[" ), ( "$u5d$", "]" ), ( "$u7b$", "{" ), ( "$u7d$", "}" ), ( "$u7e$", "~" ),
+    ];
+    let mut result = segment.strip_prefix( "_$" ).map_or_else( || segment.to_string(), | s | format!( "${s}" ) );
+    for ( from, to ) in ESCAPES
+    {
+      result = result.replace( from, to );
+    }
+    result.replace( "..", "::" )
+  }
+
   #[ cfg( target_arch = "wasm32" ) ]
   mod imp
   {
-    use super::*;
-
     // extern crate wasm_bindgen;
     use wasm_bindgen::prelude::*;
 
@@ -115,52 +314,56 @@ mod private
       fn stack( error : &Error ) -> String;
     }
 
-    pub fn hook_impl( info : &panic::PanicInfo< '_ >, config : &Config )
+    /// Log the complete panic message using `console.error`.
+    pub fn print( message : &str )
     {
-      use std::fmt::Write;
-
-      let mut message = "=== Error\n\n".to_string();
-
-      message.push_str( &info.to_string() );
+      error( message.to_string() );
+    }
 
-      if config.with_location
-      {
-        let location = info.location();
-        if let Some( location ) = location
-        {
-          // message.push_str( "\n\n = Location:\n\n {}:{}", location.file(), location.line() );
-          let _ = write!( message, "\n\n = Location:\n\n {}:{}", location.file(), location.line() );
-        }
-      }
+    pub fn stack_trace() -> Option< String >
+    {
+      Some( Error::new().stack() )
+    }
 
-      if config.with_stack_trace
-      {
-        // Add the error stack to the message to ensure it is visible.
-        message.push_str( "\n\n = Stack Trace:\n\n" );
-        let error_instance = Error::new();
-        let stack_trace = error_instance.stack();
-        message.push_str( &stack_trace );
-        message.push_str( "\n\n" );
-      }
+    pub fn user_agent() -> Option< String >
+    {
+      web_sys::window()?.navigator().user_agent().ok()
+    }
 
-      // Log the complete panic message using `console.error`.
-      error( message );
+    pub fn url() -> Option< String >
+    {
+      web_sys::window()?.location().href().ok()
     }
   }
 
   #[ cfg( not( target_arch = "wasm32" ) ) ]
   mod imp
   {
-    use super::*;
     use std::io::{ self, Write };
 
-    pub fn hook_impl( info : &panic::PanicInfo< '_ >, _config : &Config )
+    pub fn print( message : &str )
     {
-      let _ = writeln!( io::stderr(), "{}", info );
+      let _ = writeln!( io::stderr(), "{}", message );
+    }
+
+    /// Captured only if enabled with `RUST_BACKTRACE`, as the default hook does.
+    pub fn stack_trace() -> Option< String >
+    {
+      use std::backtrace::{ Backtrace, BacktraceStatus };
+      let backtrace = Backtrace::capture();
+      ( backtrace.status() == BacktraceStatus::Captured ).then( || backtrace.to_string() )
     }
-  }
 
-  pub use imp::*;
+    pub fn user_agent() -> Option< String >
+    {
+      None
+    }
+
+    pub fn url() -> Option< String >
+    {
+      None
+    }
+  }
 
 }
 
@@ -170,6 +373,9 @@ crate::mod_interface!
   own use
   {
     Config,
+    Report,
+    ReportCallback,
+    demangle,
     hook,
     setup,
   };
@@ -0,0 +1,156 @@
+//! # `browser_log::trace`
+//!
+//! Performance tracing which shows up in the Performance panel of the browser's devtools as `performance.measure` entries.
+//!
+//! Everything here is active only with the `tracing` feature. Without it `measure` returns a zero-sized guard and compiles to nothing,
+//! so it can be left in hot paths of release builds.
+//!
+//! ## Scoped Measure
+//!
+//! ```rust, no_run
+//! fn render_frame()
+//! {
+//!   let _measure = browser_log::trace::measure( "render_frame" );
+//!   // Measured until the end of the scope.
+//! }
+//! ```
+//!
+//! ## `tracing` Spans
+//!
+//! With the `tracing` feature, `PerformanceLayer` forwards every entered span of the [`tracing`](https://docs.rs/tracing) ecosystem:
+//!
+//! ```rust, ignore
+//! use tracing_subscriber::prelude::*;
+//!
+//! tracing_subscriber::registry().with( browser_log::trace::PerformanceLayer::default() ).init();
+//!
+//! let span = tracing::info_span!( "physics" );
+//! let _entered = span.enter();
+//! ```
+
+/// Internal namespace.
+mod private
+{
+
+  /// Guard measuring time until it is dropped. Created by `measure`.
+  #[ cfg( feature = "tracing" ) ]
+  #[ derive( Debug ) ]
+  #[ must_use = "measurement ends when the guard is dropped" ]
+  pub struct Measure
+  {
+    name : String,
+    start : String,
+  }
+
+  /// Guard measuring time until it is dropped. Created by `measure`.
+  #[ cfg( not( feature = "tracing" ) ) ]
+  #[ derive( Debug ) ]
+  #[ must_use = "measurement ends when the guard is dropped" ]
+  pub struct Measure;
+
+  /// Start measuring time under the given name, the measurement ends when the returned guard is dropped.
+  #[ cfg( feature = "tracing" ) ]
+  pub fn measure( name : &str ) -> Measure
+  {
+    use core::sync::atomic::{ AtomicU64, Ordering };
+    static COUNTER : AtomicU64 = AtomicU64::new( 0 );
+    let start = format!( "{name}#{}", COUNTER.fetch_add( 1, Ordering::Relaxed ) );
+    imp::mark( &start );
+    Measure { name : name.to_string(), start }
+  }
+
+  /// Start measuring time under the given name, the measurement ends when the returned guard is dropped.
+  #[ cfg( not( feature = "tracing" ) ) ]
+  #[ inline( always ) ]
+  pub fn measure( _name : &str ) -> Measure
+  {
+    Measure
+  }
+
+  #[ cfg( feature = "tracing" ) ]
+  impl Drop for Measure
+  {
+    fn drop( &mut self )
+    {
+      imp::measure( &self.name, &self.start );
+    }
+  }
+
+  /// Layer of `tracing_subscriber` forwarding spans to `performance.measure`.
+  /// Every enter/exit pair of a span becomes a separate measure named after the span.
+  #[ cfg( feature = "tracing" ) ]
+  #[ derive( Debug, Default ) ]
+  pub struct PerformanceLayer;
+
+  #[ cfg( feature = "tracing" ) ]
+  impl< S > tracing_subscriber::Layer< S > for PerformanceLayer
+  where
+    S : tracing::Subscriber + for< 'a > tracing_subscriber::registry::LookupSpan< 'a >,
+  {
+    fn on_enter( &self, id : &tracing::span::Id, ctx : tracing_subscriber::layer::Context< '_, S > )
+    {
+      if let Some( metadata ) = ctx.metadata( id )
+      {
+        imp::mark( &imp::span_mark( metadata.name(), id ) );
+      }
+    }
+
+    fn on_exit( &self, id : &tracing::span::Id, ctx : tracing_subscriber::layer::Context< '_, S > )
+    {
+      if let Some( metadata ) = ctx.metadata( id )
+      {
+        imp::measure( metadata.name(), &imp::span_mark( metadata.name(), id ) );
+      }
+    }
+  }
+
+  #[ cfg( feature = "tracing" ) ]
+  mod imp
+  {
+
+    fn performance() -> Option< web_sys::Performance >
+    {
+      web_sys::window()?.performance()
+    }
+
+    /// Start mark of a span. Includes id, so nested spans with the same name do not clash.
+    pub fn span_mark( name : &str, id : &tracing::span::Id ) -> String
+    {
+      format!( "{name}#span{}", id.into_u64() )
+    }
+
+    pub fn mark( start : &str )
+    {
+      if let Some( performance ) = performance()
+      {
+        let _ = performance.mark( start );
+      }
+    }
+
+    /// Measure from the start mark till now and remove the mark, so marks do not pile up.
+    pub fn measure( name : &str, start : &str )
+    {
+      if let Some( performance ) = performance()
+      {
+        let _ = performance.measure_with_start_mark( name, start );
+        performance.clear_marks_with_mark_name( start );
+      }
+    }
+
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  own use
+  {
+    Measure,
+    measure,
+  };
+
+  #[ cfg( feature = "tracing" ) ]
+  own use PerformanceLayer;
+
+}
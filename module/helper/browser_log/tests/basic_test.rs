@@ -15,3 +15,143 @@ fn setpu_test()
   browser_log::panic::setup( Default::default() );
   browser_log::panic::setup( Default::default() );
 }
+
+#[ test ]
+fn demangle_test()
+{
+  let got = browser_log::panic::demangle( "at _ZN4core9panicking5panic17h0123456789abcdefE (wasm-function[12]:0x1a2b)" );
+  assert_eq!( got, "at core::panicking::panic (wasm-function[12]:0x1a2b)" );
+
+  let got = browser_log::panic::demangle( "_ZN45_$LT$T$u20$as$u20$alloc..string..ToString$GT$9to_string17h0123456789abcdefE" );
+  assert_eq!( got, "<T as alloc::string::ToString>::to_string" );
+
+  let got = browser_log::panic::demangle( "not _ZN mangled" );
+  assert_eq!( got, "not _ZN mangled" );
+
+  // Length near usize::MAX must not overflow
+  let got = browser_log::panic::demangle( "_ZN18446744073709551615abcE" );
+  assert_eq!( got, "_ZN18446744073709551615abcE" );
+}
+
+#[ test ]
+fn rate_limit_test()
+{
+  use browser_log::log::{ Once, RateLimit };
+
+  let once = Once::new();
+  assert!( once.first() );
+  assert!( !once.first() );
+
+  let limit = RateLimit::new( 100 );
+  assert_eq!( limit.check_at( 1000 ), Some( 0 ) );
+  assert_eq!( limit.check_at( 1050 ), None );
+  assert_eq!( limit.check_at( 1099 ), None );
+  assert_eq!( limit.check_at( 1100 ), Some( 2 ) );
+  assert_eq!( limit.check_at( 1300 ), Some( 0 ) );
+
+  // No logger is installed, so these only exercise expansion of macros.
+  for i in 0..3
+  {
+    browser_log::warn_once!( "once {}", i );
+    browser_log::error_limited!( 1000, "limited {i}" );
+  }
+}
+
+#[ test ]
+fn report_display_test()
+{
+  let report = browser_log::panic::Report
+  {
+    summary : "panicked at src/main.rs:10:5:\nboom".into(),
+    message : Some( "boom".into() ),
+    ..Default::default()
+  };
+  assert_eq!( report.to_string(), "=== Error\n\npanicked at src/main.rs:10:5:\nboom" );
+}
+
+#[ test ]
+fn rate_limit_filtered_test()
+{
+  use browser_log::log::{ Log, Metadata, Record, LevelFilter };
+  use std::sync::atomic::{ AtomicUsize, Ordering };
+
+  static COUNT : AtomicUsize = AtomicUsize::new( 0 );
+
+  struct Counter;
+  impl Log for Counter
+  {
+    fn enabled( &self, _ : &Metadata< '_ > ) -> bool { true }
+    fn log( &self, record : &Record< '_ > )
+    {
+      if record.args().to_string().starts_with( "filtered" )
+      {
+        COUNT.fetch_add( 1, Ordering::Relaxed );
+      }
+    }
+    fn flush( &self ) {}
+  }
+
+  fn once() { browser_log::warn_once!( "filtered once" ); }
+  fn limited() { browser_log::warn_limited!( 1_000_000, "filtered limited" ); }
+
+  browser_log::log::set_logger( &Counter ).expect( "No other logger is installed by tests" );
+
+  // Records of a disabled level must not use up the quota
+  browser_log::log::set_max_level( LevelFilter::Off );
+  once();
+  limited();
+  assert_eq!( COUNT.load( Ordering::Relaxed ), 0 );
+
+  browser_log::log::set_max_level( LevelFilter::Warn );
+  once();
+  once();
+  limited();
+  limited();
+  assert_eq!( COUNT.load( Ordering::Relaxed ), 2 );
+}
+
+#[ test ]
+fn overlay_buffer_test()
+{
+  use browser_log::log::{ Level, OverlaySink, Record, Sink };
+
+  fn write( sink : &OverlaySink, level : Level, i : usize )
+  {
+    sink.write( &Record::builder().level( level ).target( "t" ).args( format_args!( "{i}" ) ).build() );
+  }
+  fn texts( sink : &OverlaySink ) -> Vec< String >
+  {
+    sink.lines().into_iter().map( | ( _, line ) | line.rsplit( ' ' ).next().unwrap().to_string() ).collect()
+  }
+
+  // Appending past capacity evicts the oldest records first
+  let sink = OverlaySink::new( "overlay_buffer_test" ).capacity( 3 );
+  for i in 0..5
+  {
+    write( &sink, Level::Info, i );
+  }
+  assert_eq!( texts( &sink ), [ "2", "3", "4" ] );
+  assert_eq!( sink.lines()[ 0 ], ( Level::Info, "INFO  t: 2".to_string() ) );
+
+  // Shrinking drops the oldest records, clones share the buffer
+  let clone = sink.clone().capacity( 2 );
+  assert_eq!( texts( &sink ), [ "3", "4" ] );
+  write( &clone, Level::Warn, 5 );
+  assert_eq!( texts( &sink ), [ "4", "5" ] );
+
+  // Growing keeps the records and lets more in
+  let sink = sink.capacity( 4 );
+  write( &sink, Level::Debug, 6 );
+  write( &sink, Level::Error, 7 );
+  assert_eq!( texts( &sink ), [ "4", "5", "6", "7" ] );
+  write( &sink, Level::Info, 8 );
+  assert_eq!( texts( &sink ), [ "5", "6", "7", "8" ] );
+
+  // Records hidden by the level are kept, zero capacity keeps nothing
+  sink.set_level( Level::Warn );
+  write( &sink, Level::Trace, 9 );
+  assert_eq!( sink.lines().last().unwrap().0, Level::Trace );
+  let sink = sink.capacity( 0 );
+  write( &sink, Level::Error, 10 );
+  assert!( sink.lines().is_empty() );
+}
@@ -0,0 +1,50 @@
+//! Compares SIMD kernels with the generic implementations.
+//!
+//! Run with `cargo bench -p ndarray_cg --features simd`, add `--features nightly` on a nightly compiler for `std::simd`.
+
+use std::{ hint::black_box, time::Instant };
+use ndarray_cg::*;
+
+const ITERATIONS : u32 = 1_000_000;
+
+fn bench< R >( name : &str, mut f : impl FnMut() -> R )
+{
+  // Warm up
+  for _ in 0..ITERATIONS / 10
+  {
+    black_box( f() );
+  }
+
+  let start = Instant::now();
+  for _ in 0..ITERATIONS
+  {
+    black_box( f() );
+  }
+  let elapsed = start.elapsed();
+  println!( "{:<28} {:>8.2} ns/iter", name, elapsed.as_nanos() as f64 / f64::from( ITERATIONS ) );
+}
+
+fn main()
+{
+  let a = F32x4x4::from_column_major
+  ([
+    1.0, 2.0, 3.0, 4.0,
+    5.0, 6.0, 7.0, 8.0,
+    9.0, 10.0, 11.0, 12.0,
+    13.0, 14.0, 15.0, 16.0
+  ]);
+  let b = a.transpose();
+  let v4 = F32x4::from( [ 1.0, 2.0, 3.0, 4.0 ] );
+  let v3 = F32x3::from( [ 1.0, 2.0, 3.0 ] );
+
+  bench( "mat4 * mat4", || black_box( a ) * black_box( b ) );
+  bench( "mat4 * mat4 simd", || black_box( a ).mul_simd( &black_box( b ) ) );
+  bench( "mat4 * vec4", || black_box( a ) * black_box( v4 ) );
+  bench( "mat4 * vec4 simd", || black_box( a ).mul_vec_simd( black_box( v4 ) ) );
+  bench( "vec4 dot", || vector::arithmetics::inner_product::dot( &black_box( v4 ), &black_box( v4 ) ) );
+  bench( "vec4 dot simd", || black_box( v4 ).dot_simd( &black_box( v4 ) ) );
+  bench( "vec4 normalize", || black_box( v4 ).normalize() );
+  bench( "vec4 normalize simd", || black_box( v4 ).normalize_simd() );
+  bench( "vec3 normalize", || black_box( v3 ).normalize() );
+  bench( "vec3 normalize simd", || black_box( v3 ).normalize_simd() );
+}
@@ -0,0 +1,25 @@
+//! Color spaces and conversions between them.
+//!
+//! Colors are `Vector< E, 3 >` with components in `[ 0, 1 ]` unless stated otherwise.
+//! Hues are in degrees in `[ 0, 360 )`. Alpha is left to the caller, as no conversion touches it.
+
+/// Internal namespace.
+mod private
+{
+}
+
+crate::mod_interface!
+{
+  /// sRGB transfer function
+  layer srgb;
+  /// Hue, saturation, value
+  layer hsv;
+  /// Hue, saturation, lightness
+  layer hsl;
+  /// Perceptually uniform OKLab and its polar form OKLCH
+  layer oklab;
+  /// Color of a black body at the temperature
+  layer temperature;
+  /// Mapping of out-of-gamut colors into the sRGB gamut
+  layer gamut;
+}
@@ -0,0 +1,62 @@
+mod private
+{
+  use crate::*;
+
+  /// Whether every component is in `[ 0, 1 ]`, with a small tolerance for rounding
+  pub fn in_gamut< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> bool
+  {
+    let eps = E::from( 1e-5 ).unwrap();
+    rgb.0.iter().all( | c | *c >= -eps && *c <= E::one() + eps )
+  }
+
+  /// Clamps every component to `[ 0, 1 ]`. Cheap, but shifts hue of saturated colors
+  pub fn clamp< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    Vector( rgb.0.map( | c | c.max( E::zero() ).min( E::one() ) ) )
+  }
+
+  /// Maps an OKLCH color into the linear sRGB gamut by reducing its chroma while keeping lightness and hue.
+  /// Lightness is clamped to `[ 0, 1 ]` first
+  pub fn map_oklch< E : MatEl + nd::NdFloat >( lch : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let [ l, c, h ] = lch.0;
+    let l = l.max( E::zero() ).min( E::one() );
+    let rgb = | c : E | color::oklab::to_linear_rgb( color::oklab::from_oklch( Vector( [ l, c, h ] ) ) );
+
+    let full = rgb( c );
+    if in_gamut( full )
+    {
+      return full;
+    }
+
+    // Binary search of the largest chroma in the gamut
+    let ( mut low, mut high ) = ( E::zero(), c );
+    for _ in 0..24
+    {
+      let mid = ( low + high ) / E::from( 2.0 ).unwrap();
+      if in_gamut( rgb( mid ) ) { low = mid; } else { high = mid; }
+    }
+    clamp( rgb( low ) )
+  }
+
+  /// Maps a linear RGB color with out of range components into the gamut, preserving its hue and lightness
+  pub fn map_rgb< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    if in_gamut( rgb )
+    {
+      return clamp( rgb );
+    }
+    map_oklch( color::oklab::to_oklch( color::oklab::from_linear_rgb( rgb ) ) )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    in_gamut,
+    clamp,
+    map_oklch,
+    map_rgb
+  };
+}
@@ -0,0 +1,49 @@
+mod private
+{
+  use crate::*;
+  use color::hsv::wrap_hue;
+
+  /// Converts RGB to hue in degrees, saturation and lightness
+  pub fn from_rgb< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let hue = color::hsv::from_rgb( rgb ).0[ 0 ];
+    let [ r, g, b ] = rgb.0;
+    let max = r.max( g ).max( b );
+    let min = r.min( g ).min( b );
+    let two = E::from( 2.0 ).unwrap();
+    let lightness = ( max + min ) / two;
+    let delta = max - min;
+    let saturation = if delta <= E::zero()
+    {
+      E::zero()
+    }
+    else
+    {
+      delta / ( E::one() - ( two * lightness - E::one() ).abs() )
+    };
+    Vector( [ hue, saturation, lightness ] )
+  }
+
+  /// Converts hue in degrees, saturation and lightness to RGB
+  pub fn to_rgb< E : MatEl + nd::NdFloat >( hsl : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let [ h, s, l ] = hsl.0;
+    let a = s * l.min( E::one() - l );
+    let channel = | n : E |
+    {
+      let k = ( n + wrap_hue( h ) / E::from( 30.0 ).unwrap() ) % E::from( 12.0 ).unwrap();
+      let ramp = ( k - E::from( 3.0 ).unwrap() ).min( E::from( 9.0 ).unwrap() - k ).min( E::one() ).max( -E::one() );
+      l - a * ramp
+    };
+    Vector( [ channel( E::zero() ), channel( E::from( 8.0 ).unwrap() ), channel( E::from( 4.0 ).unwrap() ) ] )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    from_rgb,
+    to_rgb
+  };
+}
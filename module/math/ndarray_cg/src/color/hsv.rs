@@ -0,0 +1,73 @@
+mod private
+{
+  use crate::*;
+
+  /// Hue in degrees, minimum and maximum component of an RGB color
+  fn hue_min_max< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> ( E, E, E )
+  {
+    let [ r, g, b ] = rgb.0;
+    let max = r.max( g ).max( b );
+    let min = r.min( g ).min( b );
+    let delta = max - min;
+    let sixty = E::from( 60.0 ).unwrap();
+
+    let hue = if delta <= E::zero()
+    {
+      E::zero()
+    }
+    else if max == r
+    {
+      sixty * ( ( g - b ) / delta )
+    }
+    else if max == g
+    {
+      sixty * ( ( b - r ) / delta + E::from( 2.0 ).unwrap() )
+    }
+    else
+    {
+      sixty * ( ( r - g ) / delta + E::from( 4.0 ).unwrap() )
+    };
+
+    ( wrap_hue( hue ), min, max )
+  }
+
+  /// Wraps the hue in degrees into `[ 0, 360 )`
+  pub fn wrap_hue< E : nd::NdFloat >( hue : E ) -> E
+  {
+    let full = E::from( 360.0 ).unwrap();
+    let hue = hue % full;
+    if hue < E::zero() { hue + full } else { hue }
+  }
+
+  /// Converts RGB to hue in degrees, saturation and value
+  pub fn from_rgb< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let ( hue, min, max ) = hue_min_max( rgb );
+    let saturation = if max > E::zero() { ( max - min ) / max } else { E::zero() };
+    Vector( [ hue, saturation, max ] )
+  }
+
+  /// Converts hue in degrees, saturation and value to RGB
+  pub fn to_rgb< E : MatEl + nd::NdFloat >( hsv : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let [ h, s, v ] = hsv.0;
+    // Distance of each channel from its peak on the hue circle
+    let channel = | n : E |
+    {
+      let k = ( n + wrap_hue( h ) / E::from( 60.0 ).unwrap() ) % E::from( 6.0 ).unwrap();
+      let ramp = k.min( E::from( 4.0 ).unwrap() - k ).min( E::one() ).max( E::zero() );
+      v - v * s * ramp
+    };
+    Vector( [ channel( E::from( 5.0 ).unwrap() ), channel( E::from( 3.0 ).unwrap() ), channel( E::one() ) ] )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    from_rgb,
+    to_rgb,
+    wrap_hue
+  };
+}
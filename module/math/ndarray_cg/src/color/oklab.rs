@@ -0,0 +1,90 @@
+mod private
+{
+  use crate::*;
+
+  /// Multiplies a row-major 3x3 matrix by a vector
+  fn transform< E : MatEl + nd::NdFloat >( m : [ [ f64; 3 ]; 3 ], v : [ E; 3 ] ) -> [ E; 3 ]
+  {
+    m.map( | row | row.iter().zip( v ).fold( E::zero(), | acc, ( a, b ) | acc + E::from( *a ).unwrap() * b ) )
+  }
+
+  const RGB_TO_LMS : [ [ f64; 3 ]; 3 ] =
+  [
+    [ 0.412_221_470_8, 0.536_332_536_3, 0.051_445_992_9 ],
+    [ 0.211_903_498_2, 0.680_699_545_1, 0.107_396_956_6 ],
+    [ 0.088_302_461_9, 0.281_718_837_6, 0.629_978_700_5 ],
+  ];
+
+  const LMS_TO_LAB : [ [ f64; 3 ]; 3 ] =
+  [
+    [ 0.210_454_255_3, 0.793_617_785_0, -0.004_072_046_8 ],
+    [ 1.977_998_495_1, -2.428_592_205_0, 0.450_593_709_9 ],
+    [ 0.025_904_037_1, 0.782_771_766_2, -0.808_675_766_0 ],
+  ];
+
+  const LAB_TO_LMS : [ [ f64; 3 ]; 3 ] =
+  [
+    [ 1.0, 0.396_337_777_4, 0.215_803_757_3 ],
+    [ 1.0, -0.105_561_345_8, -0.063_854_172_8 ],
+    [ 1.0, -0.089_484_177_5, -1.291_485_548_0 ],
+  ];
+
+  const LMS_TO_RGB : [ [ f64; 3 ]; 3 ] =
+  [
+    [ 4.076_741_662_1, -3.307_711_591_3, 0.230_969_929_2 ],
+    [ -1.268_438_004_6, 2.609_757_401_1, -0.341_319_396_5 ],
+    [ -0.004_196_086_3, -0.703_418_614_7, 1.707_614_701_0 ],
+  ];
+
+  /// Converts linear RGB to OKLab: lightness and two opponent axes, green-red and blue-yellow
+  pub fn from_linear_rgb< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let lms = transform( RGB_TO_LMS, rgb.0 ).map( | c | c.cbrt() );
+    Vector( transform( LMS_TO_LAB, lms ) )
+  }
+
+  /// Converts OKLab to linear RGB. The result may be out of `[ 0, 1 ]`, see `gamut`
+  pub fn to_linear_rgb< E : MatEl + nd::NdFloat >( lab : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let lms = transform( LAB_TO_LMS, lab.0 ).map( | c | c * c * c );
+    Vector( transform( LMS_TO_RGB, lms ) )
+  }
+
+  /// Converts OKLab to OKLCH: lightness, chroma and hue in degrees
+  pub fn to_oklch< E : MatEl + nd::NdFloat >( lab : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let [ l, a, b ] = lab.0;
+    let chroma = ( a * a + b * b ).sqrt();
+    let hue = color::hsv::wrap_hue( b.atan2( a ).to_degrees() );
+    Vector( [ l, chroma, hue ] )
+  }
+
+  /// Converts OKLCH to OKLab
+  pub fn from_oklch< E : MatEl + nd::NdFloat >( lch : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let [ l, c, h ] = lch.0;
+    let h = h.to_radians();
+    Vector( [ l, c * h.cos(), c * h.sin() ] )
+  }
+
+  /// Interpolates two linear RGB colors in OKLab, which keeps perceived lightness and saturation
+  /// changing evenly, unlike interpolation in RGB
+  pub fn mix< E : MatEl + nd::NdFloat >( a : Vector< E, 3 >, b : Vector< E, 3 >, t : E ) -> Vector< E, 3 >
+  {
+    let a = from_linear_rgb( a );
+    let b = from_linear_rgb( b );
+    to_linear_rgb( a + ( b - a ) * t )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    from_linear_rgb,
+    to_linear_rgb,
+    to_oklch,
+    from_oklch,
+    mix
+  };
+}
@@ -0,0 +1,68 @@
+mod private
+{
+  use crate::*;
+
+  /// Decodes an sRGB encoded component into linear light
+  pub fn decode< E : nd::NdFloat >( c : E ) -> E
+  {
+    if c <= E::from( 0.04045 ).unwrap()
+    {
+      c / E::from( 12.92 ).unwrap()
+    }
+    else
+    {
+      ( ( c + E::from( 0.055 ).unwrap() ) / E::from( 1.055 ).unwrap() ).powf( E::from( 2.4 ).unwrap() )
+    }
+  }
+
+  /// Encodes a linear light component with the sRGB transfer function
+  pub fn encode< E : nd::NdFloat >( c : E ) -> E
+  {
+    if c <= E::from( 0.003_130_8 ).unwrap()
+    {
+      c * E::from( 12.92 ).unwrap()
+    }
+    else
+    {
+      E::from( 1.055 ).unwrap() * c.powf( E::one() / E::from( 2.4 ).unwrap() ) - E::from( 0.055 ).unwrap()
+    }
+  }
+
+  /// Converts an sRGB color, e.g. picked in an editor, to linear RGB used for shading
+  pub fn to_linear< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    Vector( rgb.0.map( decode ) )
+  }
+
+  /// Converts a linear RGB color to sRGB
+  pub fn from_linear< E : MatEl + nd::NdFloat >( rgb : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    Vector( rgb.0.map( encode ) )
+  }
+
+  /// Parses `#rgb` or `#rrggbb` with an optional `#` into an sRGB color
+  pub fn from_hex< E : MatEl + nd::NdFloat >( hex : &str ) -> Option< Vector< E, 3 > >
+  {
+    let hex = hex.strip_prefix( '#' ).unwrap_or( hex );
+    let digits : Vec< u32 > = hex.chars().map( | c | c.to_digit( 16 ) ).collect::< Option< _ > >()?;
+    let bytes = match digits.len()
+    {
+      3 => [ digits[ 0 ] * 17, digits[ 1 ] * 17, digits[ 2 ] * 17 ],
+      6 => [ digits[ 0 ] * 16 + digits[ 1 ], digits[ 2 ] * 16 + digits[ 3 ], digits[ 4 ] * 16 + digits[ 5 ] ],
+      _ => return None,
+    };
+    Some( Vector( bytes.map( | b | E::from( b ).unwrap() / E::from( 255 ).unwrap() ) ) )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    decode,
+    encode,
+    to_linear,
+    from_linear,
+    from_hex
+  };
+}
@@ -0,0 +1,44 @@
+mod private
+{
+  use crate::*;
+
+  /// Approximate sRGB color of a black body at the temperature in kelvins, normalized so the brightest channel is one.
+  /// Valid for `1000..40000` K, the temperature is clamped to this range. 6600 K is close to white
+  pub fn to_rgb< E : MatEl + nd::NdFloat >( kelvin : E ) -> Vector< E, 3 >
+  {
+    // Fit by Tanner Helland to the CIE 1964 10 degree color matching functions
+    let t = kelvin.to_f64().unwrap().clamp( 1000.0, 40000.0 ) / 100.0;
+
+    let r = if t <= 66.0 { 255.0 } else { 329.698_727_446 * ( t - 60.0 ).powf( -0.133_204_759_2 ) };
+    let g = if t <= 66.0
+    {
+      99.470_802_586_1 * t.ln() - 161.119_568_166_1
+    }
+    else
+    {
+      288.122_169_528_3 * ( t - 60.0 ).powf( -0.075_514_849_2 )
+    };
+    let b = if t >= 66.0
+    {
+      255.0
+    }
+    else if t <= 19.0
+    {
+      0.0
+    }
+    else
+    {
+      138.517_731_223_1 * ( t - 10.0 ).ln() - 305.044_792_730_7
+    };
+
+    Vector( [ r, g, b ].map( | c | E::from( c.clamp( 0.0, 255.0 ) / 255.0 ).unwrap() ) )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    to_rgb
+  };
+}
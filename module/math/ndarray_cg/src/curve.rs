@@ -0,0 +1,110 @@
+//! Parametric curves.
+
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Maximum depth of the recursive subdivision in `flatten`
+  const MAX_FLATTEN_DEPTH : u32 = 16;
+
+  /// A parametric curve defined on `t` in `[ 0, 1 ]`.
+  pub trait Curve< E, const N : usize >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    /// Point on the curve at the parameter `t`
+    fn point( &self, t : E ) -> Vector< E, N >;
+
+    /// First derivative of the curve with respect to `t`
+    fn derivative( &self, t : E ) -> Vector< E, N >;
+
+    /// Unit tangent at the parameter `t`
+    fn tangent( &self, t : E ) -> Vector< E, N >
+    {
+      self.derivative( t ).normalize()
+    }
+
+    /// Approximates the curve with a polyline, whose distance from the curve doesn't exceed `tolerance`.
+    /// Flat parts of the curve get few points, bends get more
+    fn flatten( &self, tolerance : E ) -> Vec< Vector< E, N > >
+    {
+      let mut points = vec![ self.point( E::zero() ) ];
+      flatten_range( self, E::zero(), E::one(), tolerance, 0, &mut points );
+      points
+    }
+
+    /// Samples the curve uniformly into a table for arc-length parameterization
+    fn arc_length_table( &self, samples : usize ) -> ArcLengthTable< E >
+    {
+      ArcLengthTable::new( | t | self.point( t ), samples )
+    }
+
+    /// Approximate length of the curve
+    fn length( &self, samples : usize ) -> E
+    {
+      self.arc_length_table( samples ).length()
+    }
+  }
+
+  fn flatten_range< E, C, const N : usize >( curve : &C, t0 : E, t1 : E, tolerance : E, depth : u32, points : &mut Vec< Vector< E, N > > )
+  where
+    E : MatEl + nd::NdFloat,
+    C : Curve< E, N > + ?Sized
+  {
+    let p0 = curve.point( t0 );
+    let p1 = curve.point( t1 );
+    let half = E::from( 0.5 ).unwrap();
+    let quarter = E::from( 0.25 ).unwrap();
+
+    // Checking quarters as well as the middle catches S-shaped segments which cross the chord in the middle
+    let flat = [ quarter, half, E::one() - quarter ].iter().all
+    (
+      | &k |
+      {
+        let on_curve = curve.point( t0 + ( t1 - t0 ) * k );
+        let on_chord = p0 + ( p1 - p0 ) * k;
+        on_curve.distance( &on_chord ) <= tolerance
+      }
+    );
+
+    if depth >= MAX_FLATTEN_DEPTH || ( depth > 1 && flat )
+    {
+      points.push( p1 );
+      return;
+    }
+
+    let tm = ( t0 + t1 ) * half;
+    flatten_range( curve, t0, tm, tolerance, depth + 1, points );
+    flatten_range( curve, tm, t1, tolerance, depth + 1, points );
+  }
+
+  /// Linear interpolation between two points
+  #[ inline ]
+  pub fn lerp< E, const N : usize >( a : Vector< E, N >, b : Vector< E, N >, t : E ) -> Vector< E, N >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    a + ( b - a ) * t
+  }
+
+}
+
+crate::mod_interface!
+{
+  /// Quadratic and cubic Bézier curves
+  layer bezier;
+  /// Catmull-Rom splines
+  layer catmull_rom;
+  /// B-splines
+  layer bspline;
+  /// Arc-length parameterization
+  layer arc_length;
+
+  own use lerp;
+
+  exposed use
+  {
+    Curve
+  };
+}
@@ -0,0 +1,83 @@
+mod private
+{
+  use crate::*;
+
+  /// Table of accumulated lengths at uniformly sampled parameters of a curve.
+  /// Maps distance along the curve to the curve parameter, so the curve can be traversed at constant speed.
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct ArcLengthTable< E >
+  {
+    /// Accumulated length at the parameter `i / ( lengths.len() - 1 )`
+    lengths : Vec< E >,
+  }
+
+  impl< E : MatEl + nd::NdFloat > ArcLengthTable< E >
+  {
+    /// Builds the table by sampling `point` at `samples + 1` uniformly distributed parameters
+    pub fn new< const N : usize >( point : impl Fn( E ) -> Vector< E, N >, samples : usize ) -> Self
+    {
+      let samples = samples.max( 1 );
+      let count = E::from( samples ).unwrap();
+      let mut lengths = Vec::with_capacity( samples + 1 );
+      let mut prev = point( E::zero() );
+      let mut length = E::zero();
+      lengths.push( length );
+      for i in 1..=samples
+      {
+        let p = point( E::from( i ).unwrap() / count );
+        length = length + prev.distance( &p );
+        lengths.push( length );
+        prev = p;
+      }
+      Self { lengths }
+    }
+
+    /// Total length of the curve
+    pub fn length( &self ) -> E
+    {
+      *self.lengths.last().unwrap()
+    }
+
+    /// Curve parameter at the distance `s` along the curve. `s` is clamped to the length of the curve
+    pub fn t_at_length( &self, s : E ) -> E
+    {
+      let length = self.length();
+      if length <= E::zero()
+      {
+        return E::zero();
+      }
+      let s = s.max( E::zero() ).min( length );
+
+      let i = self.lengths.partition_point( | l | *l < s ).max( 1 );
+      let ( l0, l1 ) = ( self.lengths[ i - 1 ], self.lengths[ i ] );
+      let k = if l1 > l0 { ( s - l0 ) / ( l1 - l0 ) } else { E::zero() };
+      let segments = E::from( self.lengths.len() - 1 ).unwrap();
+      ( E::from( i - 1 ).unwrap() + k ) / segments
+    }
+
+    /// Curve parameter at the fraction `u` of the length of the curve
+    pub fn t_at_fraction( &self, u : E ) -> E
+    {
+      self.t_at_length( u * self.length() )
+    }
+
+    /// Distance along the curve at the parameter `t`
+    pub fn length_at( &self, t : E ) -> E
+    {
+      let segments = self.lengths.len() - 1;
+      // `max` returns the other argument for NaN, so NaN maps to the start of the curve
+      let x = t.max( E::zero() ).min( E::one() ) * E::from( segments ).unwrap();
+      let i = x.floor().to_usize().unwrap().min( segments - 1 );
+      let k = x - E::from( i ).unwrap();
+      self.lengths[ i ] + ( self.lengths[ i + 1 ] - self.lengths[ i ] ) * k
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ArcLengthTable
+  };
+}
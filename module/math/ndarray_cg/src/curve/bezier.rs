@@ -0,0 +1,128 @@
+mod private
+{
+  use crate::*;
+  use curve::lerp;
+
+  /// Quadratic Bézier curve
+  #[ derive( Clone, Copy, Debug, PartialEq ) ]
+  pub struct QuadraticBezier< E, const N : usize >
+  where E : MatEl
+  {
+    pub p0 : Vector< E, N >,
+    pub p1 : Vector< E, N >,
+    pub p2 : Vector< E, N >,
+  }
+
+  /// Cubic Bézier curve
+  #[ derive( Clone, Copy, Debug, PartialEq ) ]
+  pub struct CubicBezier< E, const N : usize >
+  where E : MatEl
+  {
+    pub p0 : Vector< E, N >,
+    pub p1 : Vector< E, N >,
+    pub p2 : Vector< E, N >,
+    pub p3 : Vector< E, N >,
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > QuadraticBezier< E, N >
+  {
+    pub fn new( p0 : Vector< E, N >, p1 : Vector< E, N >, p2 : Vector< E, N > ) -> Self
+    {
+      Self { p0, p1, p2 }
+    }
+
+    /// Splits the curve at `t` into two curves, which together match the original one
+    pub fn split( &self, t : E ) -> ( Self, Self )
+    {
+      let a = lerp( self.p0, self.p1, t );
+      let b = lerp( self.p1, self.p2, t );
+      let p = lerp( a, b, t );
+      ( Self::new( self.p0, a, p ), Self::new( p, b, self.p2 ) )
+    }
+
+    /// Exact cubic representation of the curve
+    pub fn to_cubic( &self ) -> CubicBezier< E, N >
+    {
+      let k = E::from( 2.0 / 3.0 ).unwrap();
+      CubicBezier::new
+      (
+        self.p0,
+        lerp( self.p0, self.p1, k ),
+        lerp( self.p2, self.p1, k ),
+        self.p2
+      )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > Curve< E, N > for QuadraticBezier< E, N >
+  {
+    fn point( &self, t : E ) -> Vector< E, N >
+    {
+      let s = E::one() - t;
+      let two = E::from( 2.0 ).unwrap();
+      self.p0 * ( s * s ) + self.p1 * ( two * s * t ) + self.p2 * ( t * t )
+    }
+
+    fn derivative( &self, t : E ) -> Vector< E, N >
+    {
+      let two = E::from( 2.0 ).unwrap();
+      ( self.p1 - self.p0 ) * ( two * ( E::one() - t ) ) + ( self.p2 - self.p1 ) * ( two * t )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > CubicBezier< E, N >
+  {
+    pub fn new( p0 : Vector< E, N >, p1 : Vector< E, N >, p2 : Vector< E, N >, p3 : Vector< E, N > ) -> Self
+    {
+      Self { p0, p1, p2, p3 }
+    }
+
+    /// Second derivative of the curve with respect to `t`
+    pub fn second_derivative( &self, t : E ) -> Vector< E, N >
+    {
+      let six = E::from( 6.0 ).unwrap();
+      let a = self.p2 - self.p1 * E::from( 2.0 ).unwrap() + self.p0;
+      let b = self.p3 - self.p2 * E::from( 2.0 ).unwrap() + self.p1;
+      ( a * ( E::one() - t ) + b * t ) * six
+    }
+
+    /// Splits the curve at `t` into two curves, which together match the original one
+    pub fn split( &self, t : E ) -> ( Self, Self )
+    {
+      let a = lerp( self.p0, self.p1, t );
+      let b = lerp( self.p1, self.p2, t );
+      let c = lerp( self.p2, self.p3, t );
+      let ab = lerp( a, b, t );
+      let bc = lerp( b, c, t );
+      let p = lerp( ab, bc, t );
+      ( Self::new( self.p0, a, ab, p ), Self::new( p, bc, c, self.p3 ) )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > Curve< E, N > for CubicBezier< E, N >
+  {
+    fn point( &self, t : E ) -> Vector< E, N >
+    {
+      let s = E::one() - t;
+      let three = E::from( 3.0 ).unwrap();
+      self.p0 * ( s * s * s ) + self.p1 * ( three * s * s * t ) + self.p2 * ( three * s * t * t ) + self.p3 * ( t * t * t )
+    }
+
+    fn derivative( &self, t : E ) -> Vector< E, N >
+    {
+      let s = E::one() - t;
+      let three = E::from( 3.0 ).unwrap();
+      let six = E::from( 6.0 ).unwrap();
+      ( self.p1 - self.p0 ) * ( three * s * s ) + ( self.p2 - self.p1 ) * ( six * s * t ) + ( self.p3 - self.p2 ) * ( three * t * t )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    QuadraticBezier,
+    CubicBezier
+  };
+}
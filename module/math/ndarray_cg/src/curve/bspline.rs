@@ -0,0 +1,144 @@
+mod private
+{
+  use crate::*;
+
+  /// B-spline curve of arbitrary degree.
+  ///
+  /// The curve parameter `t` in `[ 0, 1 ]` is mapped onto the valid knot range `[ knots[ degree ], knots[ control.len() ] ]`
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct BSpline< E, const N : usize >
+  where E : MatEl
+  {
+    degree : usize,
+    control : Vec< Vector< E, N > >,
+    knots : Vec< E >,
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > BSpline< E, N >
+  {
+    /// Creates a B-spline with the knot vector.
+    /// Returns `None` if the number of knots isn't `control.len() + degree + 1`,
+    /// there are fewer control points than `degree + 1`, or knots are decreasing
+    pub fn new( degree : usize, control : Vec< Vector< E, N > >, knots : Vec< E > ) -> Option< Self >
+    {
+      if degree == 0 || control.len() <= degree || knots.len() != control.len() + degree + 1
+      {
+        return None;
+      }
+      if knots.windows( 2 ).any( | w | w[ 1 ] < w[ 0 ] )
+      {
+        return None;
+      }
+      Some( Self { degree, control, knots } )
+    }
+
+    /// Creates a clamped B-spline with uniform inner knots, so the curve starts at the first control point and ends at the last one
+    pub fn clamped( degree : usize, control : Vec< Vector< E, N > > ) -> Option< Self >
+    {
+      if degree == 0 || control.len() <= degree
+      {
+        return None;
+      }
+      let inner = control.len() - degree;
+      let knots = ( 0..control.len() + degree + 1 )
+      .map( | i | E::from( i.saturating_sub( degree ).min( inner ) ).unwrap() / E::from( inner ).unwrap() )
+      .collect();
+      Self::new( degree, control, knots )
+    }
+
+    pub fn degree( &self ) -> usize
+    {
+      self.degree
+    }
+
+    pub fn control( &self ) -> &[ Vector< E, N > ]
+    {
+      &self.control
+    }
+
+    pub fn knots( &self ) -> &[ E ]
+    {
+      &self.knots
+    }
+
+    /// Knot value for the curve parameter `t`
+    fn knot_at( &self, t : E ) -> E
+    {
+      let start = self.knots[ self.degree ];
+      let end = self.knots[ self.control.len() ];
+      // `max` returns the other argument for NaN, so NaN maps to the start of the curve
+      start + ( end - start ) * t.max( E::zero() ).min( E::one() )
+    }
+
+    /// Index of the knot span containing `u`
+    fn span( &self, u : E ) -> usize
+    {
+      let n = self.control.len();
+      if u >= self.knots[ n ]
+      {
+        // Last non-empty span
+        return ( self.degree..n ).rev().find( | &i | self.knots[ i ] < self.knots[ i + 1 ] ).unwrap_or( n - 1 );
+      }
+      ( self.degree..n ).find( | &i | u < self.knots[ i + 1 ] ).unwrap_or( n - 1 )
+    }
+
+    /// Evaluates the spline at the knot value `u` with de Boor's algorithm
+    fn de_boor( degree : usize, control : &[ Vector< E, N > ], knots : &[ E ], span : usize, u : E ) -> Vector< E, N >
+    {
+      let mut d : Vec< _ > = ( 0..=degree ).map( | j | control[ j + span - degree ] ).collect();
+      for r in 1..=degree
+      {
+        for j in ( r..=degree ).rev()
+        {
+          let i = j + span - degree;
+          let denominator = knots[ i + degree + 1 - r ] - knots[ i ];
+          let alpha = if denominator > E::zero() { ( u - knots[ i ] ) / denominator } else { E::zero() };
+          d[ j ] = d[ j - 1 ] * ( E::one() - alpha ) + d[ j ] * alpha;
+        }
+      }
+      d[ degree ]
+    }
+
+    /// Control points of the derivative, which is a B-spline of degree one less on the inner knots
+    fn derivative_control( &self ) -> Vec< Vector< E, N > >
+    {
+      let p = E::from( self.degree ).unwrap();
+      self.control.windows( 2 ).enumerate().map
+      (
+        | ( i, w ) |
+        {
+          let d = self.knots[ i + self.degree + 1 ] - self.knots[ i + 1 ];
+          if d > E::zero() { ( w[ 1 ] - w[ 0 ] ) * ( p / d ) } else { Vector::default() }
+        }
+      )
+      .collect()
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > Curve< E, N > for BSpline< E, N >
+  {
+    fn point( &self, t : E ) -> Vector< E, N >
+    {
+      let u = self.knot_at( t );
+      Self::de_boor( self.degree, &self.control, &self.knots, self.span( u ), u )
+    }
+
+    fn derivative( &self, t : E ) -> Vector< E, N >
+    {
+      let u = self.knot_at( t );
+      // Chain rule: from the knot value to `t`
+      let scale = self.knots[ self.control.len() ] - self.knots[ self.degree ];
+      // The derivative knot vector drops the first knot, so the span index is one less
+      let derivative = Self::de_boor( self.degree - 1, &self.derivative_control(), &self.knots[ 1..self.knots.len() - 1 ], self.span( u ) - 1, u );
+      derivative * scale
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BSpline
+  };
+}
@@ -0,0 +1,135 @@
+mod private
+{
+  use crate::*;
+
+  /// Catmull-Rom spline, passing through all of its points.
+  ///
+  /// `alpha` selects the parameterization: `0` is uniform, `0.5` is centripetal and `1` is chordal.
+  /// Centripetal splines have no cusps or self-intersections within a segment.
+  /// End segments use mirrored phantom points, so the spline spans from the first point to the last one.
+  /// The parameter `t` in `[ 0, 1 ]` is distributed evenly between segments
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct CatmullRom< E, const N : usize >
+  where E : MatEl
+  {
+    pub points : Vec< Vector< E, N > >,
+    pub alpha : E,
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > CatmullRom< E, N >
+  {
+    /// Centripetal Catmull-Rom spline
+    pub fn new( points : Vec< Vector< E, N > > ) -> Self
+    {
+      Self { points, alpha : E::from( 0.5 ).unwrap() }
+    }
+
+    /// Uniform Catmull-Rom spline
+    pub fn uniform( points : Vec< Vector< E, N > > ) -> Self
+    {
+      Self { points, alpha : E::zero() }
+    }
+
+    /// Sets the parameterization
+    pub fn alpha( mut self, alpha : E ) -> Self
+    {
+      self.alpha = alpha;
+      self
+    }
+
+    /// Number of segments between the points
+    pub fn segments( &self ) -> usize
+    {
+      self.points.len().saturating_sub( 1 )
+    }
+
+    /// Index of the segment and the local parameter within it
+    fn locate( &self, t : E ) -> ( usize, E )
+    {
+      let segments = self.segments();
+      // `max` returns the other argument for NaN, so NaN maps to the start of the curve
+      let x = t.max( E::zero() ).min( E::one() ) * E::from( segments ).unwrap();
+      let i = x.floor().to_usize().unwrap().min( segments - 1 );
+      ( i, x - E::from( i ).unwrap() )
+    }
+
+    /// Control points of the segment, including the phantom ones at the ends
+    fn segment_points( &self, i : usize ) -> [ Vector< E, N >; 4 ]
+    {
+      let p = &self.points;
+      let p1 = p[ i ];
+      let p2 = p[ i + 1 ];
+      let p0 = if i > 0 { p[ i - 1 ] } else { p1 + ( p1 - p2 ) };
+      let p3 = if i + 2 < p.len() { p[ i + 2 ] } else { p2 + ( p2 - p1 ) };
+      [ p0, p1, p2, p3 ]
+    }
+
+    /// Position and derivative with respect to the local parameter, using the Barry-Goldman pyramid
+    fn evaluate( &self, t : E ) -> ( Vector< E, N >, Vector< E, N > )
+    {
+      match self.points.len()
+      {
+        0 => return ( Vector::default(), Vector::default() ),
+        1 => return ( self.points[ 0 ], Vector::default() ),
+        _ => {}
+      }
+
+      let ( i, u ) = self.locate( t );
+      let p = self.segment_points( i );
+
+      // Knot intervals; coincident points would give zero intervals, fall back to uniform spacing for them
+      let interval = | a : Vector< E, N >, b : Vector< E, N > |
+      {
+        let d = a.distance( &b ).powf( self.alpha );
+        if d > E::epsilon() { d } else { E::one() }
+      };
+      let t0 = E::zero();
+      let t1 = t0 + interval( p[ 0 ], p[ 1 ] );
+      let t2 = t1 + interval( p[ 1 ], p[ 2 ] );
+      let t3 = t2 + interval( p[ 2 ], p[ 3 ] );
+      let t = t1 + ( t2 - t1 ) * u;
+
+      // Interpolates ( value, derivative ) pairs between knots ta and tb
+      let step = | a : ( Vector< E, N >, Vector< E, N > ), b : ( Vector< E, N >, Vector< E, N > ), ta : E, tb : E |
+      {
+        let d = tb - ta;
+        let ka = ( tb - t ) / d;
+        let kb = ( t - ta ) / d;
+        ( a.0 * ka + b.0 * kb, ( b.0 - a.0 ) / d + a.1 * ka + b.1 * kb )
+      };
+
+      let zero = Vector::default();
+      let a1 = step( ( p[ 0 ], zero ), ( p[ 1 ], zero ), t0, t1 );
+      let a2 = step( ( p[ 1 ], zero ), ( p[ 2 ], zero ), t1, t2 );
+      let a3 = step( ( p[ 2 ], zero ), ( p[ 3 ], zero ), t2, t3 );
+      let b1 = step( a1, a2, t0, t2 );
+      let b2 = step( a2, a3, t1, t3 );
+      let ( point, derivative ) = step( b1, b2, t1, t2 );
+
+      // Chain rule: from the knot parameter to the global `t`
+      let scale = ( t2 - t1 ) * E::from( self.segments() ).unwrap();
+      ( point, derivative * scale )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat, const N : usize > Curve< E, N > for CatmullRom< E, N >
+  {
+    fn point( &self, t : E ) -> Vector< E, N >
+    {
+      self.evaluate( t ).0
+    }
+
+    fn derivative( &self, t : E ) -> Vector< E, N >
+    {
+      self.evaluate( t ).1
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    CatmullRom
+  };
+}
@@ -39,6 +39,15 @@ crate::mod_interface!
   layer mat4x4;
   orphan use super::mat4x4;
 
+  /// General functions for 3x4 matrices.
+  /// Useful to pass affine transformations of 3D space compactly.
+  layer mat3x4;
+  orphan use super::mat3x4;
+
+  /// General functions for 4x3 matrices.
+  layer mat4x3;
+  orphan use super::mat4x3;
+
   /// Rotation.
   layer rotation;
   // orphan use super::rotation;
@@ -251,17 +251,45 @@ mod private
   }
 
 
+  impl< E, const N : usize, Descriptor : mat::Descriptor > Mat< N, N, E, Descriptor >
+  where
+    E : MatEl + nd::NdFloat,
+    Self : ScalarMut< Scalar = E, Index = Ix2 >,
+  {
+
+    /// Identity matrix.
+    #[ inline ]
+    pub fn identity() -> Self
+    {
+      let mut result = Self::default();
+      for i in 0..N
+      {
+        *result.scalar_mut( Ix2( i, i ) ) = E::one();
+      }
+      result
+    }
+
+  }
+
   pub type Mat2< E, Descriptor > = Mat< 2, 2, E, Descriptor >;
   pub type Mat3< E, Descriptor > = Mat< 3, 3, E, Descriptor >;
   pub type Mat4< E, Descriptor > = Mat< 4, 4, E, Descriptor >;
+  /// 3 rows and 4 columns, e.g. affine transformation of 3D space without the last row of homogenous matrix.
+  pub type Mat3x4< E, Descriptor > = Mat< 3, 4, E, Descriptor >;
+  /// 4 rows and 3 columns.
+  pub type Mat4x3< E, Descriptor > = Mat< 4, 3, E, Descriptor >;
 
   pub type F32x2x2 = Mat< 2, 2, f32, DescriptorOrderColumnMajor >;
   pub type F32x3x3 = Mat< 3, 3, f32, DescriptorOrderColumnMajor >;
   pub type F32x4x4 = Mat< 4, 4, f32, DescriptorOrderColumnMajor >;
+  pub type F32x3x4 = Mat< 3, 4, f32, DescriptorOrderColumnMajor >;
+  pub type F32x4x3 = Mat< 4, 3, f32, DescriptorOrderColumnMajor >;
 
   pub type F64x2x2 = Mat< 2, 2, f64, DescriptorOrderColumnMajor >;
   pub type F64x3x3 = Mat< 3, 3, f64, DescriptorOrderColumnMajor >;
   pub type F64x4x4 = Mat< 4, 4, f64, DescriptorOrderColumnMajor >;
+  pub type F64x3x4 = Mat< 3, 4, f64, DescriptorOrderColumnMajor >;
+  pub type F64x4x3 = Mat< 4, 3, f64, DescriptorOrderColumnMajor >;
 }
 
 mod access_common;
@@ -296,12 +324,18 @@ crate::mod_interface!
     Mat2,
     Mat3,
     Mat4,
+    Mat3x4,
+    Mat4x3,
     F32x2x2,
     F32x3x3,
     F32x4x4,
+    F32x3x4,
+    F32x4x3,
     F64x2x2,
     F64x3x3,
-    F64x4x4
+    F64x4x4,
+    F64x3x4,
+    F64x4x3
   };
 
 }
@@ -9,6 +9,29 @@ Self : RawSliceMut< Scalar = E > +
        ConstLayout< Index = Ix2 > + 
        IndexingMut< Scalar = E, Index = Ix2 >
 {
+  /// Construct a matrix from columns
+  pub fn from_cols
+  ( 
+    x : Vector< E, 2 >,
+    y : Vector< E, 2 >
+  ) -> Self
+  {
+    let x = x.vector_ref();
+    let y = y.vector_ref();
+
+    Self::from_column_major
+    ([
+      x[ 0 ], x[ 1 ],
+      y[ 0 ], y[ 1 ]
+    ])
+  }
+
+  /// Converts the matrix to an array
+  pub fn to_array( &self ) -> [ E; 4 ]
+  {
+    self.raw_slice().try_into().unwrap()
+  }
+
   /// Computes the determinant of the matrix
   pub fn determinant( &self ) -> E
   {
@@ -29,6 +29,29 @@ Self : RawSliceMut< Scalar = E > +
     ])
   }
 
+  /// Converts the matrix to an array
+  pub fn to_array( &self ) -> [ E; 9 ]
+  {
+    self.raw_slice().try_into().unwrap()
+  }
+
+  /// Homogenous matrix with the same linear transformation and no translation
+  pub fn to_mat4( &self ) -> Mat4< E, Descriptor >
+  where
+    Mat4< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+  {
+    let mut result = Mat4::< E, Descriptor >::default();
+    for r in 0..3
+    {
+      for c in 0..3
+      {
+        *result.scalar_mut( Ix2( r, c ) ) = *self.scalar_ref( Ix2( r, c ) );
+      }
+    }
+    *result.scalar_mut( Ix2( 3, 3 ) ) = E::one();
+    result
+  }
+
   /// Computes the determinant of the matrix
   pub fn determinant( &self ) -> E
   {
@@ -1,13 +1,13 @@
 use crate::*;
 use mdmath_core::vector::inner_product::*;
 
-pub fn from_angle_y< E >( angle : f32 ) -> Mat3< E, mat::DescriptorOrderColumnMajor >
+pub fn from_angle_y< E >( angle : E ) -> Mat3< E, mat::DescriptorOrderColumnMajor >
 where
   E : MatEl + nd::NdFloat,
   Mat3< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >
 {
-  let s = E::from( angle.sin() ).unwrap();
-  let c = E::from( angle.cos() ).unwrap();
+  let s = angle.sin();
+  let c = angle.cos();
   Mat3::from_row_major
   (
     [
@@ -18,14 +18,14 @@ where
   )
 }
 
-pub fn from_axis_angle< E, Vec3 >( axis : Vec3, angle : f32 ) -> Mat3< E, mat::DescriptorOrderColumnMajor >
+pub fn from_axis_angle< E, Vec3 >( axis : Vec3, angle : E ) -> Mat3< E, mat::DescriptorOrderColumnMajor >
 where
   E : MatEl + nd::NdFloat,
   Vec3 : VectorIter< E, 3 >,
   Mat3< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >
 {
-  let s = E::from( angle.sin() ).unwrap();
-  let c = E::from( angle.cos() ).unwrap();
+  let s = angle.sin();
+  let c = angle.cos();
   let _1subc = E::one() - c ;
 
   let mut iter = axis.vector_iter();
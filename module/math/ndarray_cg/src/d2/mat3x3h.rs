@@ -15,6 +15,16 @@ crate::mod_interface!
     // Decomposed,
     perspective_rh,
     perspective_rh_gl,
+    perspective_rh_reversed,
+    perspective_rh_infinite,
+    perspective_rh_gl_infinite,
+    perspective_rh_infinite_reversed,
+    frustum_rh,
+    frustum_rh_gl,
+    orthographic_rh,
+    orthographic_rh_gl,
+    oblique_clip,
+    oblique_clip_gl,
     loot_to_rh,
     loot_at_rh
   };
@@ -1,3 +1,12 @@
+//! Projection and view transformations.
+//!
+//! All of them are right-handed: in view space `+X` is right, `+Y` is up and the camera looks along `-Z`,
+//! so `z_near` and `z_far` are positive distances in front of the camera.
+//! Functions with the `_gl` suffix map depth into `[ -1.0, 1.0 ]` as OpenGL and WebGL do,
+//! others map it into `[ 0.0, 1.0 ]` as WebGPU, Vulkan and Direct3D do.
+//! Reversed variants map the near plane to `1.0` and the far plane to `0.0`, which distributes
+//! floating-point depth precision much more evenly, use them with the `Greater` depth test.
+
 use crate::*;
 use mdmath_core::vector::inner_product::*;
 
@@ -79,6 +88,371 @@ where
   )
 }
 
+/// Creates right-handed perspective transformation with reversed z: `z_near` maps to 1.0 and `z_far` to 0.0.
+///
+/// Similiar functions:
+/// perspective_rh - return the same matrix, but with z in range [ 0.0, 1.0 ]
+/// perspective_rh_infinite_reversed - the same, but without the far plane
+pub fn perspective_rh_reversed< E >
+(
+  fovy : E,
+  aspect : E,
+  z_near : E,
+  z_far : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let f = E::one() / ( fovy / two ).tan();
+  let dz = z_far - z_near;
+  let mz = z_near * z_far;
+
+  Mat4::from_row_major
+  (
+    [
+      f / aspect, E::zero(),  E::zero(),   E::zero(),
+      E::zero(),  f,          E::zero(),   E::zero(),
+      E::zero(),  E::zero(),  z_near / dz, mz / dz,
+      E::zero(),  E::zero(), -E::one(),    E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed perspective transformation with z in range [ -1.0, 1.0 ] and the far plane at infinity.
+///
+/// Similiar functions:
+/// perspective_rh_gl - return the same matrix, but with a finite far plane
+pub fn perspective_rh_gl_infinite< E >
+(
+  fovy : E,
+  aspect : E,
+  z_near : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let f = E::one() / ( fovy / two ).tan();
+
+  Mat4::from_row_major
+  (
+    [
+      f / aspect, E::zero(),  E::zero(), E::zero(),
+      E::zero(),  f,          E::zero(), E::zero(),
+      E::zero(),  E::zero(), -E::one(), -two * z_near,
+      E::zero(),  E::zero(), -E::one(),  E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed perspective transformation with z in range [ 0.0, 1.0 ] and the far plane at infinity.
+///
+/// Similiar functions:
+/// perspective_rh - return the same matrix, but with a finite far plane
+pub fn perspective_rh_infinite< E >
+(
+  fovy : E,
+  aspect : E,
+  z_near : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let f = E::one() / ( fovy / two ).tan();
+
+  Mat4::from_row_major
+  (
+    [
+      f / aspect, E::zero(),  E::zero(), E::zero(),
+      E::zero(),  f,          E::zero(), E::zero(),
+      E::zero(),  E::zero(), -E::one(), -z_near,
+      E::zero(),  E::zero(), -E::one(),  E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed perspective transformation with reversed z and the far plane at infinity:
+/// `z_near` maps to 1.0 and infinity to 0.0.
+/// It gives the best depth precision of all the perspective transformations.
+///
+/// Similiar functions:
+/// perspective_rh_reversed - return the same matrix, but with a finite far plane
+pub fn perspective_rh_infinite_reversed< E >
+(
+  fovy : E,
+  aspect : E,
+  z_near : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let f = E::one() / ( fovy / two ).tan();
+
+  Mat4::from_row_major
+  (
+    [
+      f / aspect, E::zero(),  E::zero(), E::zero(),
+      E::zero(),  f,          E::zero(), E::zero(),
+      E::zero(),  E::zero(),  E::zero(), z_near,
+      E::zero(),  E::zero(), -E::one(),  E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed off-center perspective transformation with z in range [ -1.0, 1.0 ].
+/// `left`, `right`, `bottom` and `top` are the edges of the view volume on the near plane.
+/// This transformation corresponds to the transformation used in OpenGL:
+/// https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glFrustum.xml
+///
+/// Similiar functions:
+/// frustum_rh - return the same matrix, but with z in range [ 0.0, 1.0 ]
+pub fn frustum_rh_gl< E >
+(
+  left : E,
+  right : E,
+  bottom : E,
+  top : E,
+  z_near : E,
+  z_far : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let w = right - left;
+  let h = top - bottom;
+  let dz = z_near - z_far;
+
+  Mat4::from_row_major
+  (
+    [
+      two * z_near / w, E::zero(),        ( right + left ) / w,    E::zero(),
+      E::zero(),        two * z_near / h, ( top + bottom ) / h,    E::zero(),
+      E::zero(),        E::zero(),        ( z_near + z_far ) / dz, two * z_near * z_far / dz,
+      E::zero(),        E::zero(),       -E::one(),                E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed off-center perspective transformation with z in range [ 0.0, 1.0 ].
+/// `left`, `right`, `bottom` and `top` are the edges of the view volume on the near plane.
+///
+/// Similiar functions:
+/// frustum_rh_gl - return the same matrix, but with z in range [ -1.0, 1.0 ]
+pub fn frustum_rh< E >
+(
+  left : E,
+  right : E,
+  bottom : E,
+  top : E,
+  z_near : E,
+  z_far : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let w = right - left;
+  let h = top - bottom;
+  let dz = z_near - z_far;
+
+  Mat4::from_row_major
+  (
+    [
+      two * z_near / w, E::zero(),        ( right + left ) / w, E::zero(),
+      E::zero(),        two * z_near / h, ( top + bottom ) / h, E::zero(),
+      E::zero(),        E::zero(),        z_far / dz,           z_near * z_far / dz,
+      E::zero(),        E::zero(),       -E::one(),             E::zero()
+    ]
+  )
+}
+
+/// Creates right-handed orthographic transformation with z in range [ -1.0, 1.0 ].
+/// This transformation corresponds to the transformation used in OpenGL:
+/// https://registry.khronos.org/OpenGL-Refpages/gl2.1/xhtml/glOrtho.xml
+///
+/// Similiar functions:
+/// orthographic_rh - return the same matrix, but with z in range [ 0.0, 1.0 ]
+pub fn orthographic_rh_gl< E >
+(
+  left : E,
+  right : E,
+  bottom : E,
+  top : E,
+  z_near : E,
+  z_far : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let w = right - left;
+  let h = top - bottom;
+  let dz = z_near - z_far;
+
+  Mat4::from_row_major
+  (
+    [
+      two / w,   E::zero(), E::zero(), -( right + left ) / w,
+      E::zero(), two / h,   E::zero(), -( top + bottom ) / h,
+      E::zero(), E::zero(), two / dz,  ( z_near + z_far ) / dz,
+      E::zero(), E::zero(), E::zero(), E::one()
+    ]
+  )
+}
+
+/// Creates right-handed orthographic transformation with z in range [ 0.0, 1.0 ].
+///
+/// Similiar functions:
+/// orthographic_rh_gl - return the same matrix, but with z in range [ -1.0, 1.0 ]
+pub fn orthographic_rh< E >
+(
+  left : E,
+  right : E,
+  bottom : E,
+  top : E,
+  z_near : E,
+  z_far : E
+)
+->  Mat4< E, mat::DescriptorOrderColumnMajor >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > : RawSliceMut< Scalar = E >,
+{
+  let two = E::from( 2.0 ).unwrap();
+  let w = right - left;
+  let h = top - bottom;
+  let dz = z_near - z_far;
+
+  Mat4::from_row_major
+  (
+    [
+      two / w,   E::zero(), E::zero(),      -( right + left ) / w,
+      E::zero(), two / h,   E::zero(),      -( top + bottom ) / h,
+      E::zero(), E::zero(), E::one() / dz,  z_near / dz,
+      E::zero(), E::zero(), E::zero(),      E::one()
+    ]
+  )
+}
+
+/// Replaces the near plane of a projection with z in range [ -1.0, 1.0 ] by an arbitrary `clip_plane`,
+/// given in view space. It's used to clip geometry behind the mirror when rendering planar reflections.
+/// The positive side of the plane is kept, so the camera must be on the negative side.
+/// The far plane becomes skewed, so depth precision is reduced.
+/// If the projection can't be inverted - return `None`
+///
+/// Method by Eric Lengyel: https://terathon.com/lengyel/Lengyel-Oblique.pdf
+///
+/// Similiar functions:
+/// oblique_clip - the same for projections with z in range [ 0.0, 1.0 ]
+pub fn oblique_clip_gl< E >
+(
+  projection : &Mat4< E, mat::DescriptorOrderColumnMajor >,
+  clip_plane : &geometry::Plane< E >
+)
+->  Option< Mat4< E, mat::DescriptorOrderColumnMajor > >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > :
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    RawSliceMut< Scalar = E > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+  Mat3< E, mat::DescriptorOrderColumnMajor > :
+    RawSliceMut< Scalar = E > +
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+{
+  oblique_clip_impl( projection, clip_plane, E::from( 2.0 ).unwrap(), true )
+}
+
+/// Replaces the near plane of a projection with z in range [ 0.0, 1.0 ] by an arbitrary `clip_plane`,
+/// given in view space. It's used to clip geometry behind the mirror when rendering planar reflections.
+/// The positive side of the plane is kept, so the camera must be on the negative side.
+/// Reversed projections are not supported.
+/// If the projection can't be inverted - return `None`
+///
+/// Similiar functions:
+/// oblique_clip_gl - the same for projections with z in range [ -1.0, 1.0 ]
+pub fn oblique_clip< E >
+(
+  projection : &Mat4< E, mat::DescriptorOrderColumnMajor >,
+  clip_plane : &geometry::Plane< E >
+)
+->  Option< Mat4< E, mat::DescriptorOrderColumnMajor > >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > :
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    RawSliceMut< Scalar = E > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+  Mat3< E, mat::DescriptorOrderColumnMajor > :
+    RawSliceMut< Scalar = E > +
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+{
+  oblique_clip_impl( projection, clip_plane, E::one(), false )
+}
+
+fn oblique_clip_impl< E >
+(
+  projection : &Mat4< E, mat::DescriptorOrderColumnMajor >,
+  clip_plane : &geometry::Plane< E >,
+  depth_range : E,
+  gl : bool
+)
+->  Option< Mat4< E, mat::DescriptorOrderColumnMajor > >
+where
+  E : MatEl + nd::NdFloat,
+  Mat4< E, mat::DescriptorOrderColumnMajor > :
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    RawSliceMut< Scalar = E > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+  Mat3< E, mat::DescriptorOrderColumnMajor > :
+    RawSliceMut< Scalar = E > +
+    ScalarMut< Scalar = E, Index = Ix2 > +
+    ConstLayout< Index = Ix2 > +
+    IndexingMut< Scalar = E, Index = Ix2 >,
+{
+  let n = clip_plane.normal.0;
+  let c = [ n[ 0 ], n[ 1 ], n[ 2 ], clip_plane.d ];
+
+  // The corner of the view volume opposite to the plane, on the far plane
+  let corner = Vector( [ c[ 0 ].signum(), c[ 1 ].signum(), E::one(), E::one() ] );
+  let q = projection.inverse()? * corner;
+  let scale = depth_range / ( 0..4 ).fold( E::zero(), | acc, i | acc + c[ i ] * q.0[ i ] );
+
+  // Near plane is `z = 0` or `z = -w`, so the new third row is the plane itself or the plane minus the fourth row
+  let mut result = *projection;
+  for i in 0..4
+  {
+    let w = if gl { *projection.scalar_ref( Ix2( 3, i ) ) } else { E::zero() };
+    *result.scalar_mut( Ix2( 2, i ) ) = c[ i ] * scale - w;
+  }
+  Some( result )
+}
+
 /// Make a right-handed view transformation from camera's position, camera's view directions, 
 /// and camera's "up" orientation.
 /// (+)X - right, (+)Y - up, (+)Z - back
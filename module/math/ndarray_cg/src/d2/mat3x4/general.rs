@@ -0,0 +1,35 @@
+use crate::*;
+
+impl< E, Descriptor > Mat3x4< E, Descriptor >
+where
+E : MatEl + nd::NdFloat,
+Descriptor : mat::Descriptor,
+Self : RawSliceMut< Scalar = E > +
+       ScalarMut< Scalar = E, Index = Ix2 > +
+       ConstLayout< Index = Ix2 > +
+       IndexingMut< Scalar = E, Index = Ix2 >
+{
+  /// Converts the matrix to an array.
+  /// With column-major ordering it's 4 columns of 3 scalars, as expected by `uniformMatrix4x3fv`
+  pub fn to_array( &self ) -> [ E; 12 ]
+  {
+    self.raw_slice().try_into().unwrap()
+  }
+
+  /// Homogenous matrix with `[ 0, 0, 0, 1 ]` appended as the last row
+  pub fn to_mat4( &self ) -> Mat4< E, Descriptor >
+  where
+    Mat4< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+  {
+    let mut result = Mat4::< E, Descriptor >::default();
+    for r in 0..3
+    {
+      for c in 0..4
+      {
+        *result.scalar_mut( Ix2( r, c ) ) = *self.scalar_ref( Ix2( r, c ) );
+      }
+    }
+    *result.scalar_mut( Ix2( 3, 3 ) ) = E::one();
+    result
+  }
+}
@@ -0,0 +1,11 @@
+mod private
+{
+
+}
+
+mod general;
+
+crate::mod_interface!
+{
+
+}
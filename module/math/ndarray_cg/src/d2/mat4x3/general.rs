@@ -0,0 +1,18 @@
+use crate::*;
+
+impl< E, Descriptor > Mat4x3< E, Descriptor >
+where
+E : MatEl + nd::NdFloat,
+Descriptor : mat::Descriptor,
+Self : RawSliceMut< Scalar = E > +
+       ScalarMut< Scalar = E, Index = Ix2 > +
+       ConstLayout< Index = Ix2 > +
+       IndexingMut< Scalar = E, Index = Ix2 >
+{
+  /// Converts the matrix to an array.
+  /// With column-major ordering it's 3 columns of 4 scalars, as expected by `uniformMatrix3x4fv`
+  pub fn to_array( &self ) -> [ E; 12 ]
+  {
+    self.raw_slice().try_into().unwrap()
+  }
+}
@@ -72,6 +72,102 @@ Self : ScalarMut< Scalar = E, Index = Ix2 > +
     self.raw_slice().try_into().unwrap()
   }
 
+  /// Upper-left 3x3 part of the matrix, which is its linear transformation without translation
+  pub fn truncate( &self ) -> Mat3< E, Descriptor >
+  where
+    Mat3< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+  {
+    let mut result = Mat3::< E, Descriptor >::default();
+    for r in 0..3
+    {
+      for c in 0..3
+      {
+        *result.scalar_mut( Ix2( r, c ) ) = *self.scalar_ref( Ix2( r, c ) );
+      }
+    }
+    result
+  }
+
+  /// Matrix to transform normals of a model transformed by this matrix.
+  /// It is the inverse transpose of the upper-left 3x3 part, so it keeps normals perpendicular to surfaces under non-uniform scaling.
+  /// If the 3x3 part is degenerate - return `None`
+  pub fn normal_matrix( &self ) -> Option< Mat3< E, Descriptor > >
+  where
+    Mat3< E, Descriptor > :
+      RawSliceMut< Scalar = E > +
+      ScalarMut< Scalar = E, Index = Ix2 > +
+      ConstLayout< Index = Ix2 > +
+      IndexingMut< Scalar = E, Index = Ix2 >
+  {
+    self.truncate().inverse().map( | m | m.transpose() )
+  }
+
+  /// First 3 rows of the matrix. For affine transformations the dropped row is always `[ 0, 0, 0, 1 ]`
+  pub fn to_mat3x4( &self ) -> Mat3x4< E, Descriptor >
+  where
+    Mat3x4< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+  {
+    let mut result = Mat3x4::< E, Descriptor >::default();
+    for r in 0..3
+    {
+      for c in 0..4
+      {
+        *result.scalar_mut( Ix2( r, c ) ) = *self.scalar_ref( Ix2( r, c ) );
+      }
+    }
+    result
+  }
+
+  /// Creates a transformation, which scales, then rotates and then translates
+  pub fn from_trs( translation : Vector< E, 3 >, rotation : Quat< E >, scale : Vector< E, 3 > ) -> Self
+  {
+    let rotation = rotation.to_mat4::< Descriptor >();
+    let mut result = Self::default();
+    for r in 0..3
+    {
+      for c in 0..3
+      {
+        *result.scalar_mut( Ix2( r, c ) ) = *rotation.scalar_ref( Ix2( r, c ) ) * scale.0[ c ];
+      }
+      *result.scalar_mut( Ix2( r, 3 ) ) = translation.0[ r ];
+    }
+    *result.scalar_mut( Ix2( 3, 3 ) ) = E::one();
+    result
+  }
+
+  /// Decomposes an affine transformation into translation, rotation and scale, the inverse of `from_trs`.
+  /// Negative determinant is represented by the negative `x` scale.
+  /// Shear can't be represented and is lost. If any scale is zero - return `None`
+  pub fn decompose( &self ) -> Option< ( Vector< E, 3 >, Quat< E >, Vector< E, 3 > ) >
+  where
+    Mat3< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+  {
+    let mut linear = self.truncate();
+    let column = | c : usize | Vector( [ 0, 1, 2 ].map( | r | *linear.scalar_ref( Ix2( r, c ) ) ) );
+    let mut scale = Vector( [ column( 0 ).mag(), column( 1 ).mag(), column( 2 ).mag() ] );
+    if scale.0.iter().any( | s | *s <= E::epsilon() )
+    {
+      return None;
+    }
+
+    let determinant = vector::arithmetics::inner_product::dot( &column( 0 ).cross( column( 1 ) ), &column( 2 ) );
+    if determinant < E::zero()
+    {
+      scale.0[ 0 ] = -scale.0[ 0 ];
+    }
+
+    for r in 0..3
+    {
+      for c in 0..3
+      {
+        *linear.scalar_mut( Ix2( r, c ) ) = *linear.scalar_ref( Ix2( r, c ) ) / scale.0[ c ];
+      }
+    }
+
+    let translation = Vector( [ 0, 1, 2 ].map( | r | *self.scalar_ref( Ix2( r, 3 ) ) ) );
+    Some( ( translation, Quat::from_rotation_matrix( &linear ), scale ) )
+  }
+
 
   /// Computes the determinant of the matrix
   pub fn determinant( &self ) -> E
@@ -0,0 +1,50 @@
+//! Geometric primitives and intersection tests between them.
+
+/// Internal namespace.
+mod private
+{
+  /// Tests whether two shapes overlap. Touching shapes intersect
+  pub trait Intersects< Rhs : ?Sized >
+  {
+    fn intersects( &self, rhs : &Rhs ) -> bool;
+  }
+
+  /// Tests whether a shape is fully inside another one
+  pub trait Contains< Rhs : ?Sized >
+  {
+    fn contains( &self, rhs : &Rhs ) -> bool;
+  }
+
+  /// Position of a shape relative to a plane
+  #[ derive( Clone, Copy, PartialEq, Eq, Hash, Debug ) ]
+  pub enum PlaneSide
+  {
+    /// Fully on the side the normal points to
+    Front,
+    /// Fully on the opposite side
+    Back,
+    /// Crosses the plane
+    Intersecting,
+  }
+}
+
+crate::mod_interface!
+{
+  /// Planes
+  layer plane;
+  /// Axis-aligned bounding boxes
+  layer aabb;
+  /// Spheres
+  layer sphere;
+  /// Oriented bounding boxes
+  layer obb;
+  /// Pairwise intersection and containment tests
+  layer intersection;
+
+  exposed use
+  {
+    Intersects,
+    Contains,
+    PlaneSide
+  };
+}
@@ -0,0 +1,91 @@
+mod private
+{
+  use crate::*;
+
+  /// Axis-aligned bounding box
+  #[ derive( Clone, Copy, PartialEq, Debug ) ]
+  pub struct Aabb< E >
+  where E : MatEl
+  {
+    pub min : Vector< E, 3 >,
+    pub max : Vector< E, 3 >,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Aabb< E >
+  {
+    pub fn new( min : Vector< E, 3 >, max : Vector< E, 3 > ) -> Self
+    {
+      Self { min, max }
+    }
+
+    /// Creates a box from its center and half extents
+    pub fn from_center_extents( center : Vector< E, 3 >, half_extents : Vector< E, 3 > ) -> Self
+    {
+      Self { min : center - half_extents, max : center + half_extents }
+    }
+
+    /// Smallest box containing all the points. If there are no points - return `None`
+    pub fn from_points( points : impl IntoIterator< Item = Vector< E, 3 > > ) -> Option< Self >
+    {
+      let mut points = points.into_iter();
+      let first = points.next()?;
+      Some( points.fold( Self::new( first, first ), | aabb, p | aabb.expand( p ) ) )
+    }
+
+    pub fn center( &self ) -> Vector< E, 3 >
+    {
+      ( self.min + self.max ) * E::from( 0.5 ).unwrap()
+    }
+
+    pub fn half_extents( &self ) -> Vector< E, 3 >
+    {
+      ( self.max - self.min ) * E::from( 0.5 ).unwrap()
+    }
+
+    pub fn size( &self ) -> Vector< E, 3 >
+    {
+      self.max - self.min
+    }
+
+    /// Box containing both boxes
+    pub fn merge( self, rhs : Self ) -> Self
+    {
+      Self { min : self.min.min( rhs.min ), max : self.max.max( rhs.max ) }
+    }
+
+    /// Box containing the box and the point
+    pub fn expand( self, point : Vector< E, 3 > ) -> Self
+    {
+      Self { min : self.min.min( point ), max : self.max.max( point ) }
+    }
+
+    /// Closest point inside the box
+    pub fn closest_point( &self, point : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      point.max( self.min ).min( self.max )
+    }
+
+    /// All 8 corners of the box
+    pub fn corners( &self ) -> [ Vector< E, 3 >; 8 ]
+    {
+      let ( a, b ) = ( self.min.0, self.max.0 );
+      core::array::from_fn
+      (
+        | i | Vector
+        ([
+          if i & 1 == 0 { a[ 0 ] } else { b[ 0 ] },
+          if i & 2 == 0 { a[ 1 ] } else { b[ 1 ] },
+          if i & 4 == 0 { a[ 2 ] } else { b[ 2 ] },
+        ])
+      )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Aabb
+  };
+}
@@ -0,0 +1,247 @@
+mod private
+{
+  use crate::*;
+  use vector::arithmetics::inner_product::dot;
+
+  /// Implements `Intersects< A > for B` through `Intersects< B > for A`
+  macro_rules! symmetric
+  {
+    ( $( $A : ident, $B : ident );* $(;)? ) =>
+    {
+      $(
+        impl< E : MatEl + nd::NdFloat > Intersects< $A< E > > for $B< E >
+        {
+          fn intersects( &self, rhs : &$A< E > ) -> bool
+          {
+            rhs.intersects( self )
+          }
+        }
+      )*
+    };
+  }
+
+  // Points
+
+  impl< E : MatEl + nd::NdFloat > Contains< Vector< E, 3 > > for Aabb< E >
+  {
+    fn contains( &self, rhs : &Vector< E, 3 > ) -> bool
+    {
+      ( 0..3 ).all( | i | self.min.0[ i ] <= rhs.0[ i ] && rhs.0[ i ] <= self.max.0[ i ] )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Vector< E, 3 > > for Sphere< E >
+  {
+    fn contains( &self, rhs : &Vector< E, 3 > ) -> bool
+    {
+      self.center.distance_squared( rhs ) <= self.radius * self.radius
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Vector< E, 3 > > for Obb< E >
+  {
+    fn contains( &self, rhs : &Vector< E, 3 > ) -> bool
+    {
+      let d = *rhs - self.center;
+      self.axes.iter().zip( self.half_extents.0 ).all( | ( axis, half ) | dot( &d, axis ).abs() <= half )
+    }
+  }
+
+  // Plane
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Plane< E > > for Plane< E >
+  {
+    fn intersects( &self, rhs : &Plane< E > ) -> bool
+    {
+      // Non-parallel planes always intersect, parallel ones only if they coincide
+      self.normal.cross( rhs.normal ).mag2() > E::epsilon()
+      || rhs.signed_distance( self.normal * -self.d ).abs() <= E::epsilon()
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Aabb< E > > for Plane< E >
+  {
+    fn intersects( &self, rhs : &Aabb< E > ) -> bool
+    {
+      let half = rhs.half_extents();
+      let radius = ( 0..3 ).fold( E::zero(), | r, i | r + self.normal.0[ i ].abs() * half.0[ i ] );
+      self.side( rhs.center(), radius ) == PlaneSide::Intersecting
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Sphere< E > > for Plane< E >
+  {
+    fn intersects( &self, rhs : &Sphere< E > ) -> bool
+    {
+      self.side( rhs.center, rhs.radius ) == PlaneSide::Intersecting
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Obb< E > > for Plane< E >
+  {
+    fn intersects( &self, rhs : &Obb< E > ) -> bool
+    {
+      self.side( rhs.center, rhs.projected_radius( self.normal ) ) == PlaneSide::Intersecting
+    }
+  }
+
+  // Aabb
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Aabb< E > > for Aabb< E >
+  {
+    fn intersects( &self, rhs : &Aabb< E > ) -> bool
+    {
+      ( 0..3 ).all( | i | self.min.0[ i ] <= rhs.max.0[ i ] && rhs.min.0[ i ] <= self.max.0[ i ] )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Sphere< E > > for Aabb< E >
+  {
+    fn intersects( &self, rhs : &Sphere< E > ) -> bool
+    {
+      self.closest_point( rhs.center ).distance_squared( &rhs.center ) <= rhs.radius * rhs.radius
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Obb< E > > for Aabb< E >
+  {
+    fn intersects( &self, rhs : &Obb< E > ) -> bool
+    {
+      Obb::from_aabb( self, Quat::identity() ).intersects( rhs )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Aabb< E > > for Aabb< E >
+  {
+    fn contains( &self, rhs : &Aabb< E > ) -> bool
+    {
+      self.contains( &rhs.min ) && self.contains( &rhs.max )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Sphere< E > > for Aabb< E >
+  {
+    fn contains( &self, rhs : &Sphere< E > ) -> bool
+    {
+      let r = Vector( [ rhs.radius; 3 ] );
+      self.contains( &( rhs.center - r ) ) && self.contains( &( rhs.center + r ) )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Obb< E > > for Aabb< E >
+  {
+    fn contains( &self, rhs : &Obb< E > ) -> bool
+    {
+      self.contains( &rhs.to_aabb() )
+    }
+  }
+
+  // Sphere
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Sphere< E > > for Sphere< E >
+  {
+    fn intersects( &self, rhs : &Sphere< E > ) -> bool
+    {
+      let r = self.radius + rhs.radius;
+      self.center.distance_squared( &rhs.center ) <= r * r
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Obb< E > > for Sphere< E >
+  {
+    fn intersects( &self, rhs : &Obb< E > ) -> bool
+    {
+      rhs.closest_point( self.center ).distance_squared( &self.center ) <= self.radius * self.radius
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Sphere< E > > for Sphere< E >
+  {
+    fn contains( &self, rhs : &Sphere< E > ) -> bool
+    {
+      self.center.distance( &rhs.center ) + rhs.radius <= self.radius
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Aabb< E > > for Sphere< E >
+  {
+    fn contains( &self, rhs : &Aabb< E > ) -> bool
+    {
+      rhs.corners().iter().all( | c | self.contains( c ) )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Obb< E > > for Sphere< E >
+  {
+    fn contains( &self, rhs : &Obb< E > ) -> bool
+    {
+      rhs.corners().iter().all( | c | self.contains( c ) )
+    }
+  }
+
+  // Obb
+
+  impl< E : MatEl + nd::NdFloat > Intersects< Obb< E > > for Obb< E >
+  {
+    /// Separating axis test over the face normals of both boxes and cross products of their edges
+    fn intersects( &self, rhs : &Obb< E > ) -> bool
+    {
+      let d = rhs.center - self.center;
+      let separated = | axis : Vector< E, 3 > |
+      {
+        let len2 = axis.mag2();
+        // Cross product of parallel edges, already covered by the face normals
+        if len2 <= E::epsilon()
+        {
+          return false;
+        }
+        let axis = axis / len2.sqrt();
+        dot( &d, &axis ).abs() > self.projected_radius( axis ) + rhs.projected_radius( axis )
+      };
+
+      let faces = self.axes.iter().chain( rhs.axes.iter() ).any( | a | separated( *a ) );
+      let edges = self.axes.iter().any( | a | rhs.axes.iter().any( | b | separated( a.cross( *b ) ) ) );
+      !faces && !edges
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Aabb< E > > for Obb< E >
+  {
+    fn contains( &self, rhs : &Aabb< E > ) -> bool
+    {
+      rhs.corners().iter().all( | c | self.contains( c ) )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Sphere< E > > for Obb< E >
+  {
+    fn contains( &self, rhs : &Sphere< E > ) -> bool
+    {
+      let d = rhs.center - self.center;
+      self.axes.iter().zip( self.half_extents.0 ).all( | ( axis, half ) | dot( &d, axis ).abs() + rhs.radius <= half )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Contains< Obb< E > > for Obb< E >
+  {
+    fn contains( &self, rhs : &Obb< E > ) -> bool
+    {
+      rhs.corners().iter().all( | c | self.contains( c ) )
+    }
+  }
+
+  symmetric!
+  {
+    Plane, Aabb;
+    Plane, Sphere;
+    Plane, Obb;
+    Aabb, Sphere;
+    Aabb, Obb;
+    Sphere, Obb;
+  }
+}
+
+crate::mod_interface!
+{
+
+}
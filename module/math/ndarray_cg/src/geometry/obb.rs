@@ -0,0 +1,97 @@
+mod private
+{
+  use crate::*;
+  use vector::arithmetics::inner_product::dot;
+
+  /// Oriented bounding box
+  #[ derive( Clone, Copy, PartialEq, Debug ) ]
+  pub struct Obb< E >
+  where E : MatEl
+  {
+    pub center : Vector< E, 3 >,
+    /// Unit axes of the box
+    pub axes : [ Vector< E, 3 >; 3 ],
+    /// Half size along each of the axes
+    pub half_extents : Vector< E, 3 >,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Obb< E >
+  {
+    pub fn new( center : Vector< E, 3 >, rotation : Quat< E >, half_extents : Vector< E, 3 > ) -> Self
+    {
+      let ( zero, one ) = ( E::zero(), E::one() );
+      let axes =
+      [
+        rotation.rotate( Vector( [ one, zero, zero ] ) ),
+        rotation.rotate( Vector( [ zero, one, zero ] ) ),
+        rotation.rotate( Vector( [ zero, zero, one ] ) ),
+      ];
+      Self { center, axes, half_extents }
+    }
+
+    /// Box rotated by the `rotation` around its center
+    pub fn from_aabb( aabb : &Aabb< E >, rotation : Quat< E > ) -> Self
+    {
+      Self::new( aabb.center(), rotation, aabb.half_extents() )
+    }
+
+    /// Axis-aligned box containing the oriented one
+    pub fn to_aabb( &self ) -> Aabb< E >
+    {
+      let mut extents = Vector::< E, 3 >::default();
+      for ( axis, half ) in self.axes.iter().zip( self.half_extents.0 )
+      {
+        for i in 0..3
+        {
+          extents.0[ i ] = extents.0[ i ] + axis.0[ i ].abs() * half;
+        }
+      }
+      Aabb::from_center_extents( self.center, extents )
+    }
+
+    /// Radius of the projection of the box onto the unit `axis`
+    pub fn projected_radius( &self, axis : Vector< E, 3 > ) -> E
+    {
+      self.axes.iter().zip( self.half_extents.0 ).fold( E::zero(), | r, ( a, h ) | r + dot( a, &axis ).abs() * h )
+    }
+
+    /// Closest point inside the box
+    pub fn closest_point( &self, point : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      let d = point - self.center;
+      self.axes.iter().zip( self.half_extents.0 ).fold
+      (
+        self.center,
+        | p, ( axis, half ) | p + *axis * dot( &d, axis ).max( -half ).min( half )
+      )
+    }
+
+    /// All 8 corners of the box
+    pub fn corners( &self ) -> [ Vector< E, 3 >; 8 ]
+    {
+      core::array::from_fn
+      (
+        | i |
+        {
+          ( 0..3 ).fold
+          (
+            self.center,
+            | p, k |
+            {
+              let sign = if i & ( 1 << k ) == 0 { -E::one() } else { E::one() };
+              p + self.axes[ k ] * ( self.half_extents.0[ k ] * sign )
+            }
+          )
+        }
+      )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Obb
+  };
+}
@@ -0,0 +1,103 @@
+mod private
+{
+  use crate::*;
+  use vector::arithmetics::inner_product::dot;
+
+  /// Plane of points `p` satisfying `dot( normal, p ) + d = 0`
+  #[ derive( Clone, Copy, PartialEq, Debug ) ]
+  pub struct Plane< E >
+  where E : MatEl
+  {
+    /// Unit normal of the plane
+    pub normal : Vector< E, 3 >,
+    /// Negated distance from the origin along the normal
+    pub d : E,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Plane< E >
+  {
+    /// Creates a plane from the normal and a point on the plane. The normal doesn't have to be normalized
+    pub fn from_normal_point( normal : Vector< E, 3 >, point : Vector< E, 3 > ) -> Self
+    {
+      let normal = normal.normalize();
+      Self { normal, d : -dot( &normal, &point ) }
+    }
+
+    /// Creates a plane through three points, with the normal facing the side from which they are counter-clockwise.
+    /// If the points are collinear - return `None`
+    pub fn from_points( a : Vector< E, 3 >, b : Vector< E, 3 >, c : Vector< E, 3 > ) -> Option< Self >
+    {
+      let normal = ( b - a ).cross( c - a );
+      if normal.mag2() <= E::epsilon()
+      {
+        return None;
+      }
+      Some( Self::from_normal_point( normal, a ) )
+    }
+
+    /// Creates a plane from the coefficients `a * x + b * y + c * z + d = 0`, normalizing them.
+    /// Useful for extracting frustum planes from a view-projection matrix
+    pub fn from_coefficients( a : E, b : E, c : E, d : E ) -> Self
+    {
+      let normal = Vector( [ a, b, c ] );
+      let mag = normal.mag();
+      Self { normal : normal / mag, d : d / mag }
+    }
+
+    /// Signed distance from the plane, positive on the side the normal points to
+    pub fn signed_distance( &self, point : Vector< E, 3 > ) -> E
+    {
+      dot( &self.normal, &point ) + self.d
+    }
+
+    /// Closest point on the plane
+    pub fn project( &self, point : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      point - self.normal * self.signed_distance( point )
+    }
+
+    /// Plane with the opposite orientation
+    pub fn flip( self ) -> Self
+    {
+      Self { normal : self.normal * -E::one(), d : -self.d }
+    }
+
+    /// Position of the shape relative to the plane, given its projected radius onto the plane normal
+    pub fn side( &self, center : Vector< E, 3 >, radius : E ) -> PlaneSide
+    {
+      let distance = self.signed_distance( center );
+      if distance > radius
+      {
+        PlaneSide::Front
+      }
+      else if distance < -radius
+      {
+        PlaneSide::Back
+      }
+      else
+      {
+        PlaneSide::Intersecting
+      }
+    }
+
+    /// Point where the ray `origin + t * direction` hits the plane, as the parameter `t`.
+    /// If the ray is parallel to the plane - return `None`
+    pub fn ray_intersection( &self, origin : Vector< E, 3 >, direction : Vector< E, 3 > ) -> Option< E >
+    {
+      let denominator = dot( &self.normal, &direction );
+      if denominator.abs() <= E::epsilon()
+      {
+        return None;
+      }
+      Some( -self.signed_distance( origin ) / denominator )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Plane
+  };
+}
@@ -0,0 +1,56 @@
+mod private
+{
+  use crate::*;
+
+  /// Bounding sphere
+  #[ derive( Clone, Copy, PartialEq, Debug ) ]
+  pub struct Sphere< E >
+  where E : MatEl
+  {
+    pub center : Vector< E, 3 >,
+    pub radius : E,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Sphere< E >
+  {
+    pub fn new( center : Vector< E, 3 >, radius : E ) -> Self
+    {
+      Self { center, radius }
+    }
+
+    /// Sphere containing all the points, centered at the center of their bounding box.
+    /// It isn't the minimal one, but is cheap and close to it for typical meshes.
+    /// If there are no points - return `None`
+    pub fn from_points( points : impl IntoIterator< Item = Vector< E, 3 > > + Clone ) -> Option< Self >
+    {
+      let center = Aabb::from_points( points.clone() )?.center();
+      let radius = points.into_iter().map( | p | p.distance( &center ) ).fold( E::zero(), E::max );
+      Some( Self { center, radius } )
+    }
+
+    /// Sphere containing both spheres
+    pub fn merge( self, rhs : Self ) -> Self
+    {
+      let distance = self.center.distance( &rhs.center );
+      if distance + rhs.radius <= self.radius
+      {
+        return self;
+      }
+      if distance + self.radius <= rhs.radius
+      {
+        return rhs;
+      }
+      let radius = ( distance + self.radius + rhs.radius ) * E::from( 0.5 ).unwrap();
+      let center = self.center + ( rhs.center - self.center ) * ( ( radius - self.radius ) / distance );
+      Self { center, radius }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Sphere
+  };
+}
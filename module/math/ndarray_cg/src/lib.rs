@@ -1,4 +1,5 @@
 #![ doc = include_str!( "../readme.md" ) ]
+#![ cfg_attr( all( feature = "nightly", not( target_arch = "wasm32" ) ), feature( portable_simd ) ) ]
 
 use ::mod_interface::mod_interface;
 
@@ -48,4 +49,38 @@ crate::mod_interface!
   layer vector;
   own use super::vector;
 
+  /// Quaternions.
+  layer quaternion;
+  own use super::quaternion;
+
+  /// Translation, rotation and scale, and transform hierarchies.
+  layer transform;
+  own use super::transform;
+
+  /// Parametric curves: Bézier, Catmull-Rom and B-splines.
+  layer curve;
+  own use super::curve;
+
+  /// Geometric primitives: planes, boxes and spheres, and intersections between them.
+  layer geometry;
+  own use super::geometry;
+
+  /// Seedable coherent noise: Perlin, Simplex, OpenSimplex2 and their fractal sums.
+  layer noise;
+  own use super::noise;
+
+  /// Seeded random numbers, sampling distributions and low-discrepancy sequences.
+  layer random;
+  own use super::random;
+
+  /// Color spaces: sRGB, HSV, HSL, OKLab and OKLCH.
+  layer color;
+  own use super::color;
+
+  /// SIMD kernels for `f32` vectors and matrices.
+  #[ cfg( feature = "simd" ) ]
+  layer simd;
+  #[ cfg( feature = "simd" ) ]
+  own use super::simd;
+
 }
@@ -0,0 +1,95 @@
+//! Deterministic, seedable coherent noise.
+
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Coherent noise sampled at points of `N`-dimensional space.
+  /// Gradient noises return values roughly in `[ -1, 1 ]`
+  pub trait Noise< E, const N : usize >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    fn sample( &self, point : Vector< E, N > ) -> E;
+  }
+
+  impl< E, T, const N : usize > Noise< E, N > for &T
+  where
+    E : MatEl + nd::NdFloat,
+    T : Noise< E, N > + ?Sized
+  {
+    fn sample( &self, point : Vector< E, N > ) -> E
+    {
+      ( **self ).sample( point )
+    }
+  }
+
+  /// Permutation of `0..256`, repeated twice so lattice hashes can be chained without wrapping
+  #[ derive( Clone, Debug, PartialEq, Eq ) ]
+  pub struct Permutation( [ u8; 512 ] );
+
+  impl Permutation
+  {
+    /// Shuffles the table with a generator seeded by `seed`. The same seed always gives the same table
+    pub fn new( seed : u64 ) -> Self
+    {
+      let mut table : [ u8; 256 ] = core::array::from_fn( | i | i as u8 );
+      let mut state = seed;
+      for i in ( 1..256 ).rev()
+      {
+        let j = ( splitmix64( &mut state ) % ( i as u64 + 1 ) ) as usize;
+        table.swap( i, j );
+      }
+      Self( core::array::from_fn( | i | table[ i & 255 ] ) )
+    }
+
+    /// Hash of the lattice coordinate, wrapping it to the table
+    #[ inline ]
+    pub fn hash( &self, i : usize ) -> usize
+    {
+      self.0[ i & 511 ] as usize
+    }
+  }
+
+  /// Step of the SplitMix64 generator
+  pub fn splitmix64( state : &mut u64 ) -> u64
+  {
+    *state = state.wrapping_add( 0x9E37_79B9_7F4A_7C15 );
+    let mut z = *state;
+    z = ( z ^ ( z >> 30 ) ).wrapping_mul( 0xBF58_476D_1CE4_E5B9 );
+    z = ( z ^ ( z >> 27 ) ).wrapping_mul( 0x94D0_49BB_1331_11EB );
+    z ^ ( z >> 31 )
+  }
+
+  /// Integer part of the lattice coordinate, wrapped into the permutation table
+  #[ inline ]
+  pub fn lattice< E : nd::NdFloat >( x : E ) -> usize
+  {
+    ( x.floor().to_i64().unwrap_or( 0 ) & 255 ) as usize
+  }
+}
+
+crate::mod_interface!
+{
+  /// Perlin gradient noise
+  layer perlin;
+  /// Classic Simplex noise
+  layer simplex;
+  /// OpenSimplex2 noise
+  layer open_simplex;
+  /// Fractal sums and domain warping of other noises
+  layer fractal;
+
+  own use
+  {
+    Permutation,
+    splitmix64,
+    lattice
+  };
+
+  exposed use
+  {
+    Noise
+  };
+}
@@ -0,0 +1,179 @@
+mod private
+{
+  use crate::*;
+
+  /// Fractional Brownian motion: sum of octaves of a noise with rising frequency and falling amplitude.
+  /// The sum is normalized by the total amplitude, so the range matches the base noise
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct Fbm< Nz >
+  {
+    pub noise : Nz,
+    pub octaves : u32,
+    /// Frequency multiplier between octaves
+    pub lacunarity : f64,
+    /// Amplitude multiplier between octaves
+    pub gain : f64,
+  }
+
+  impl< Nz > Fbm< Nz >
+  {
+    /// Six octaves with lacunarity of 2 and gain of 0.5
+    pub fn new( noise : Nz ) -> Self
+    {
+      Self { noise, octaves : 6, lacunarity : 2.0, gain : 0.5 }
+    }
+
+    pub fn octaves( mut self, octaves : u32 ) -> Self
+    {
+      self.octaves = octaves;
+      self
+    }
+
+    pub fn lacunarity( mut self, lacunarity : f64 ) -> Self
+    {
+      self.lacunarity = lacunarity;
+      self
+    }
+
+    pub fn gain( mut self, gain : f64 ) -> Self
+    {
+      self.gain = gain;
+      self
+    }
+  }
+
+  impl< E, Nz, const N : usize > Noise< E, N > for Fbm< Nz >
+  where
+    E : MatEl + nd::NdFloat,
+    Nz : Noise< E, N >
+  {
+    fn sample( &self, point : Vector< E, N > ) -> E
+    {
+      let ( lacunarity, gain ) = ( E::from( self.lacunarity ).unwrap(), E::from( self.gain ).unwrap() );
+      let ( mut sum, mut total ) = ( E::zero(), E::zero() );
+      let ( mut frequency, mut amplitude ) = ( E::one(), E::one() );
+      for _ in 0..self.octaves
+      {
+        sum = sum + self.noise.sample( point * frequency ) * amplitude;
+        total = total + amplitude;
+        frequency = frequency * lacunarity;
+        amplitude = amplitude * gain;
+      }
+      if total > E::zero() { sum / total } else { E::zero() }
+    }
+  }
+
+  /// Ridged multifractal: octaves of `( 1 - |noise| )^2`, where each octave is weighted by the previous one.
+  /// Gives sharp crests, typical for mountain ranges. Values are in `[ 0, 1 ]`
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct Ridged< Nz >
+  {
+    pub noise : Nz,
+    pub octaves : u32,
+    /// Frequency multiplier between octaves
+    pub lacunarity : f64,
+    /// Amplitude multiplier between octaves
+    pub gain : f64,
+  }
+
+  impl< Nz > Ridged< Nz >
+  {
+    /// Six octaves with lacunarity of 2 and gain of 0.5
+    pub fn new( noise : Nz ) -> Self
+    {
+      Self { noise, octaves : 6, lacunarity : 2.0, gain : 0.5 }
+    }
+
+    pub fn octaves( mut self, octaves : u32 ) -> Self
+    {
+      self.octaves = octaves;
+      self
+    }
+
+    pub fn lacunarity( mut self, lacunarity : f64 ) -> Self
+    {
+      self.lacunarity = lacunarity;
+      self
+    }
+
+    pub fn gain( mut self, gain : f64 ) -> Self
+    {
+      self.gain = gain;
+      self
+    }
+  }
+
+  impl< E, Nz, const N : usize > Noise< E, N > for Ridged< Nz >
+  where
+    E : MatEl + nd::NdFloat,
+    Nz : Noise< E, N >
+  {
+    fn sample( &self, point : Vector< E, N > ) -> E
+    {
+      let ( lacunarity, gain ) = ( E::from( self.lacunarity ).unwrap(), E::from( self.gain ).unwrap() );
+      let ( mut sum, mut total ) = ( E::zero(), E::zero() );
+      let ( mut frequency, mut amplitude ) = ( E::one(), E::one() );
+      let mut weight = E::one();
+      for _ in 0..self.octaves
+      {
+        let ridge = E::one() - self.noise.sample( point * frequency ).abs().min( E::one() );
+        let ridge = ridge * ridge * weight;
+        weight = ridge;
+        sum = sum + ridge * amplitude;
+        total = total + amplitude;
+        frequency = frequency * lacunarity;
+        amplitude = amplitude * gain;
+      }
+      if total > E::zero() { sum / total } else { E::zero() }
+    }
+  }
+
+  /// Domain warping: samples `noise` at the point displaced by `warp`, which gives swirly, organic patterns.
+  /// Each coordinate of the displacement samples `warp` at a different offset, so they are uncorrelated
+  #[ derive( Clone, Debug, PartialEq ) ]
+  pub struct DomainWarp< Nz, W >
+  {
+    pub noise : Nz,
+    pub warp : W,
+    /// Scale of the displacement
+    pub strength : f64,
+  }
+
+  impl< Nz, W > DomainWarp< Nz, W >
+  {
+    pub fn new( noise : Nz, warp : W, strength : f64 ) -> Self
+    {
+      Self { noise, warp, strength }
+    }
+  }
+
+  impl< E, Nz, W, const N : usize > Noise< E, N > for DomainWarp< Nz, W >
+  where
+    E : MatEl + nd::NdFloat,
+    Nz : Noise< E, N >,
+    W : Noise< E, N >
+  {
+    fn sample( &self, point : Vector< E, N > ) -> E
+    {
+      let strength = E::from( self.strength ).unwrap();
+      let mut warped = point;
+      for ( i, w ) in warped.0.iter_mut().enumerate()
+      {
+        // Arbitrary offsets far enough apart to decorrelate the coordinates
+        let offset = Vector( [ E::from( 17.31 * ( i + 1 ) as f64 ).unwrap(); N ] );
+        *w = *w + self.warp.sample( point + offset ) * strength;
+      }
+      self.noise.sample( warped )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Fbm,
+    Ridged,
+    DomainWarp
+  };
+}
@@ -0,0 +1,168 @@
+mod private
+{
+  use crate::*;
+  use noise::{ Permutation, lattice };
+
+  /// Unit gradients of 2D noise: 8 directions at `22.5 + 45k` degrees and 16 at `±7.5` and `±37.5` degrees from the axes
+  const GRADIENTS_2D : [ [ f64; 2 ]; 24 ] =
+  [
+    [ 0.923879532511287, 0.382683432365090 ], [ 0.382683432365090, 0.923879532511287 ], [ -0.382683432365090, 0.923879532511287 ], [ -0.923879532511287, 0.382683432365090 ],
+    [ -0.923879532511287, -0.382683432365090 ], [ -0.382683432365090, -0.923879532511287 ], [ 0.382683432365090, -0.923879532511287 ], [ 0.923879532511287, -0.382683432365090 ],
+    [ 0.991444861373810, 0.130526192220052 ], [ -0.130526192220052, 0.991444861373810 ], [ -0.991444861373810, -0.130526192220052 ], [ 0.130526192220052, -0.991444861373810 ],
+    [ 0.793353340291235, 0.608761429008721 ], [ -0.608761429008721, 0.793353340291235 ], [ -0.793353340291235, -0.608761429008721 ], [ 0.608761429008721, -0.793353340291235 ],
+    [ 0.608761429008721, 0.793353340291235 ], [ -0.793353340291235, 0.608761429008721 ], [ -0.608761429008721, -0.793353340291235 ], [ 0.793353340291235, -0.608761429008721 ],
+    [ 0.130526192220052, 0.991444861373810 ], [ -0.991444861373810, 0.130526192220052 ], [ -0.130526192220052, -0.991444861373810 ], [ 0.991444861373810, -0.130526192220052 ],
+  ];
+
+  /// Gradients of 3D noise: all permutations and signs of `( 1, a, a )` and `( 0, b, c )`, which have the same length
+  const GRADIENTS_3D : [ [ f64; 3 ]; 48 ] =
+  [
+    [ 1.0, 2.224744871391589, 2.224744871391589 ], [ 1.0, 2.224744871391589, -2.224744871391589 ], [ 1.0, -2.224744871391589, 2.224744871391589 ],
+    [ 1.0, -2.224744871391589, -2.224744871391589 ], [ -1.0, 2.224744871391589, 2.224744871391589 ], [ -1.0, 2.224744871391589, -2.224744871391589 ],
+    [ -1.0, -2.224744871391589, 2.224744871391589 ], [ -1.0, -2.224744871391589, -2.224744871391589 ], [ 2.224744871391589, 1.0, 2.224744871391589 ],
+    [ 2.224744871391589, 1.0, -2.224744871391589 ], [ 2.224744871391589, -1.0, 2.224744871391589 ], [ 2.224744871391589, -1.0, -2.224744871391589 ],
+    [ -2.224744871391589, 1.0, 2.224744871391589 ], [ -2.224744871391589, 1.0, -2.224744871391589 ], [ -2.224744871391589, -1.0, 2.224744871391589 ],
+    [ -2.224744871391589, -1.0, -2.224744871391589 ], [ 2.224744871391589, 2.224744871391589, 1.0 ], [ 2.224744871391589, 2.224744871391589, -1.0 ],
+    [ 2.224744871391589, -2.224744871391589, 1.0 ], [ 2.224744871391589, -2.224744871391589, -1.0 ], [ -2.224744871391589, 2.224744871391589, 1.0 ],
+    [ -2.224744871391589, 2.224744871391589, -1.0 ], [ -2.224744871391589, -2.224744871391589, 1.0 ], [ -2.224744871391589, -2.224744871391589, -1.0 ],
+    [ 0.0, 1.172151342246498, 3.086266468797202 ], [ 0.0, 1.172151342246498, -3.086266468797202 ], [ 0.0, -1.172151342246498, 3.086266468797202 ],
+    [ 0.0, -1.172151342246498, -3.086266468797202 ], [ 0.0, 3.086266468797202, 1.172151342246498 ], [ 0.0, 3.086266468797202, -1.172151342246498 ],
+    [ 0.0, -3.086266468797202, 1.172151342246498 ], [ 0.0, -3.086266468797202, -1.172151342246498 ], [ 1.172151342246498, 0.0, 3.086266468797202 ],
+    [ 1.172151342246498, 0.0, -3.086266468797202 ], [ -1.172151342246498, 0.0, 3.086266468797202 ], [ -1.172151342246498, 0.0, -3.086266468797202 ],
+    [ 1.172151342246498, 3.086266468797202, 0.0 ], [ 1.172151342246498, -3.086266468797202, 0.0 ], [ -1.172151342246498, 3.086266468797202, 0.0 ],
+    [ -1.172151342246498, -3.086266468797202, 0.0 ], [ 3.086266468797202, 0.0, 1.172151342246498 ], [ 3.086266468797202, 0.0, -1.172151342246498 ],
+    [ -3.086266468797202, 0.0, 1.172151342246498 ], [ -3.086266468797202, 0.0, -1.172151342246498 ], [ 3.086266468797202, 1.172151342246498, 0.0 ],
+    [ 3.086266468797202, -1.172151342246498, 0.0 ], [ -3.086266468797202, 1.172151342246498, 0.0 ], [ -3.086266468797202, -1.172151342246498, 0.0 ],
+  ];
+
+  /// Largest magnitude of the sum of 2D contributions, maps the noise to `[ -1, 1 ]`
+  const NORMALIZER_2D : f64 = 0.01001634121365712;
+  /// Largest magnitude of the sum of 3D contributions
+  const NORMALIZER_3D : f64 = 0.07969837668935331;
+
+  /// OpenSimplex2 noise in 2D and 3D.
+  ///
+  /// Sums contributions of the nearest points of a lattice like Simplex noise, but the 3D lattice is the body centered cubic one,
+  /// made of two cubic lattices offset by half a cell, which has fewer directional artifacts than the skewed cubic lattice.
+  /// The 3D noise is rotated so that slices along `z` look best, which suits `x`, `y` in the plane and `z` as time or height
+  #[ derive( Clone, Debug, PartialEq, Eq ) ]
+  pub struct OpenSimplex2
+  {
+    permutation : Permutation,
+  }
+
+  impl OpenSimplex2
+  {
+    pub fn new( seed : u64 ) -> Self
+    {
+      Self { permutation : Permutation::new( seed ) }
+    }
+  }
+
+  /// Contribution of a lattice point with the offset `d` from the sample point
+  #[ inline ]
+  fn contribution< E : nd::NdFloat, const N : usize >( gradient : [ f64; N ], d : [ E; N ], radius2 : E ) -> E
+  {
+    let a = d.iter().fold( radius2, | a, x | a - *x * *x );
+    if a <= E::zero()
+    {
+      return E::zero();
+    }
+    let dot = d.iter().zip( gradient ).fold( E::zero(), | acc, ( x, g ) | acc + *x * E::from( g ).unwrap() );
+    let a2 = a * a;
+    a2 * a2 * dot
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 2 > for OpenSimplex2
+  {
+    fn sample( &self, point : Vector< E, 2 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y ] = point.0;
+      let sqrt3 = E::from( 3.0 ).unwrap().sqrt();
+      let half = E::from( 0.5 ).unwrap();
+      let skew = half * ( sqrt3 - E::one() );
+      let unskew = half * ( E::one() / sqrt3 - E::one() );
+
+      // Skew to the lattice of squares, each of which holds two triangles
+      let s = ( x + y ) * skew;
+      let ( xs, ys ) = ( x + s, y + s );
+      let ( xb, yb ) = ( xs.floor(), ys.floor() );
+      let t = ( xs - xb + ys - yb ) * unskew;
+      let x0 = xs - xb + t;
+      let y0 = ys - yb + t;
+
+      let ( i, j ) = ( lattice( xb ), lattice( yb ) );
+      let gradient = | di : usize, dj : usize | GRADIENTS_2D[ p.hash( i + di + p.hash( j + dj ) ) % 24 ];
+      let radius2 = half;
+
+      // Corners of the square on its diagonal, then the corner of the triangle containing the point
+      let d = E::one() + unskew + unskew;
+      let n = contribution( gradient( 0, 0 ), [ x0, y0 ], radius2 )
+      + contribution( gradient( 1, 1 ), [ x0 - d, y0 - d ], radius2 )
+      + if y0 > x0
+      {
+        contribution( gradient( 0, 1 ), [ x0 - unskew, y0 - unskew - E::one() ], radius2 )
+      }
+      else
+      {
+        contribution( gradient( 1, 0 ), [ x0 - unskew - E::one(), y0 - unskew ], radius2 )
+      };
+
+      n / E::from( NORMALIZER_2D ).unwrap()
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 3 > for OpenSimplex2
+  {
+    fn sample( &self, point : Vector< E, 3 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y, z ] = point.0;
+      let half = E::from( 0.5 ).unwrap();
+
+      // Rotate the lattice so that its main diagonal points along `z`
+      let xy = x + y;
+      let s = xy * E::from( -0.211324865405187 ).unwrap();
+      let zz = z * E::from( 0.577350269189626 ).unwrap();
+      let r = [ x + s + zz, y + s + zz, xy * E::from( -0.577350269189626 ).unwrap() + zz ];
+
+      let radius2 = E::from( 0.6 ).unwrap();
+      let mut n = E::zero();
+      for ( copy, offset ) in [ ( 0, E::zero() ), ( 1, half ) ]
+      {
+        // The closest point of the cubic lattice and the offset from it
+        let base : [ E; 3 ] = core::array::from_fn( | a | ( r[ a ] - offset + half ).floor() );
+        let d : [ E; 3 ] = core::array::from_fn( | a | r[ a ] - offset - base[ a ] );
+        let base = base.map( | b | b.to_i64().unwrap_or( 0 ) );
+
+        // Each copy of the lattice hashes its points differently
+        let gradient = | c : [ i64; 3 ] |
+        {
+          let w = | a : usize | ( c[ a ] & 255 ) as usize;
+          GRADIENTS_3D[ p.hash( w( 0 ) + p.hash( w( 1 ) + p.hash( w( 2 ) + 128 * copy ) ) ) % 48 ]
+        };
+        n = n + contribution( gradient( base ), d, radius2 );
+
+        // The second closest point is the neighbour along the axis of the largest offset
+        let k = ( 1..3 ).fold( 0, | k, a | if d[ a ].abs() > d[ k ].abs() { a } else { k } );
+        let step = if d[ k ] > E::zero() { 1 } else { -1 };
+        let mut c = base;
+        c[ k ] += step;
+        let mut d = d;
+        d[ k ] = d[ k ] - E::from( step ).unwrap();
+        n = n + contribution( gradient( c ), d, radius2 );
+      }
+
+      n / E::from( NORMALIZER_3D ).unwrap()
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    OpenSimplex2
+  };
+}
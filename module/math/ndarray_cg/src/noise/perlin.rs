@@ -0,0 +1,119 @@
+mod private
+{
+  use crate::*;
+  use noise::{ Permutation, lattice };
+
+  /// Improved Perlin noise in 2D and 3D
+  #[ derive( Clone, Debug, PartialEq, Eq ) ]
+  pub struct Perlin
+  {
+    permutation : Permutation,
+  }
+
+  impl Perlin
+  {
+    pub fn new( seed : u64 ) -> Self
+    {
+      Self { permutation : Permutation::new( seed ) }
+    }
+  }
+
+  #[ inline ]
+  fn fade< E : nd::NdFloat >( t : E ) -> E
+  {
+    // 6t^5 - 15t^4 + 10t^3
+    t * t * t * ( t * ( t * E::from( 6.0 ).unwrap() - E::from( 15.0 ).unwrap() ) + E::from( 10.0 ).unwrap() )
+  }
+
+  #[ inline ]
+  fn lerp< E : nd::NdFloat >( t : E, a : E, b : E ) -> E
+  {
+    a + t * ( b - a )
+  }
+
+  /// Dot product with one of the 4 diagonal gradients
+  #[ inline ]
+  fn grad2< E : nd::NdFloat >( hash : usize, x : E, y : E ) -> E
+  {
+    let x = if hash & 1 == 0 { x } else { -x };
+    let y = if hash & 2 == 0 { y } else { -y };
+    x + y
+  }
+
+  /// Dot product with one of the 12 gradients pointing to the edges of a cube
+  #[ inline ]
+  fn grad3< E : nd::NdFloat >( hash : usize, x : E, y : E, z : E ) -> E
+  {
+    let h = hash & 15;
+    let u = if h < 8 { x } else { y };
+    let v = if h < 4 { y } else if h == 12 || h == 14 { x } else { z };
+    ( if h & 1 == 0 { u } else { -u } ) + ( if h & 2 == 0 { v } else { -v } )
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 2 > for Perlin
+  {
+    fn sample( &self, point : Vector< E, 2 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y ] = point.0;
+      let ( xi, yi ) = ( lattice( x ), lattice( y ) );
+      let ( x, y ) = ( x - x.floor(), y - y.floor() );
+      let ( u, v ) = ( fade( x ), fade( y ) );
+      let one = E::one();
+
+      let a = p.hash( xi ) + yi;
+      let b = p.hash( xi + 1 ) + yi;
+      lerp
+      (
+        v,
+        lerp( u, grad2( p.hash( a ), x, y ), grad2( p.hash( b ), x - one, y ) ),
+        lerp( u, grad2( p.hash( a + 1 ), x, y - one ), grad2( p.hash( b + 1 ), x - one, y - one ) )
+      )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 3 > for Perlin
+  {
+    fn sample( &self, point : Vector< E, 3 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y, z ] = point.0;
+      let ( xi, yi, zi ) = ( lattice( x ), lattice( y ), lattice( z ) );
+      let ( x, y, z ) = ( x - x.floor(), y - y.floor(), z - z.floor() );
+      let ( u, v, w ) = ( fade( x ), fade( y ), fade( z ) );
+      let one = E::one();
+
+      let a = p.hash( xi ) + yi;
+      let aa = p.hash( a ) + zi;
+      let ab = p.hash( a + 1 ) + zi;
+      let b = p.hash( xi + 1 ) + yi;
+      let ba = p.hash( b ) + zi;
+      let bb = p.hash( b + 1 ) + zi;
+
+      lerp
+      (
+        w,
+        lerp
+        (
+          v,
+          lerp( u, grad3( p.hash( aa ), x, y, z ), grad3( p.hash( ba ), x - one, y, z ) ),
+          lerp( u, grad3( p.hash( ab ), x, y - one, z ), grad3( p.hash( bb ), x - one, y - one, z ) )
+        ),
+        lerp
+        (
+          v,
+          lerp( u, grad3( p.hash( aa + 1 ), x, y, z - one ), grad3( p.hash( ba + 1 ), x - one, y, z - one ) ),
+          lerp( u, grad3( p.hash( ab + 1 ), x, y - one, z - one ), grad3( p.hash( bb + 1 ), x - one, y - one, z - one ) )
+        )
+      )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Perlin
+  };
+}
@@ -0,0 +1,133 @@
+mod private
+{
+  use crate::*;
+  use noise::{ Permutation, lattice };
+
+  /// Gradients pointing to the edges of a cube. In 2D only `x` and `y` are used
+  const GRADIENTS : [ [ f64; 3 ]; 12 ] =
+  [
+    [ 1.0, 1.0, 0.0 ], [ -1.0, 1.0, 0.0 ], [ 1.0, -1.0, 0.0 ], [ -1.0, -1.0, 0.0 ],
+    [ 1.0, 0.0, 1.0 ], [ -1.0, 0.0, 1.0 ], [ 1.0, 0.0, -1.0 ], [ -1.0, 0.0, -1.0 ],
+    [ 0.0, 1.0, 1.0 ], [ 0.0, -1.0, 1.0 ], [ 0.0, 1.0, -1.0 ], [ 0.0, -1.0, -1.0 ],
+  ];
+
+  /// Classic Simplex noise in 2D and 3D, on the skewed cubic lattice. See [`OpenSimplex2`] for the variant on the body centered cubic lattice.
+  /// Has fewer directional artifacts than Perlin noise and is cheaper in higher dimensions
+  #[ derive( Clone, Debug, PartialEq, Eq ) ]
+  pub struct Simplex
+  {
+    permutation : Permutation,
+  }
+
+  impl Simplex
+  {
+    pub fn new( seed : u64 ) -> Self
+    {
+      Self { permutation : Permutation::new( seed ) }
+    }
+  }
+
+  /// Contribution of a simplex corner with the offset `d` from the sample point
+  #[ inline ]
+  fn corner< E : nd::NdFloat, const N : usize >( hash : usize, d : [ E; N ], falloff : E ) -> E
+  {
+    let t = d.iter().fold( falloff, | t, x | t - *x * *x );
+    if t <= E::zero()
+    {
+      return E::zero();
+    }
+    let g = GRADIENTS[ hash % 12 ];
+    let dot = d.iter().zip( g ).fold( E::zero(), | acc, ( x, g ) | acc + *x * E::from( g ).unwrap() );
+    let t2 = t * t;
+    t2 * t2 * dot
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 2 > for Simplex
+  {
+    fn sample( &self, point : Vector< E, 2 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y ] = point.0;
+      let sqrt3 = E::from( 3.0 ).unwrap().sqrt();
+      let f2 = E::from( 0.5 ).unwrap() * ( sqrt3 - E::one() );
+      let g2 = ( E::from( 3.0 ).unwrap() - sqrt3 ) / E::from( 6.0 ).unwrap();
+      let ( one, two ) = ( E::one(), E::from( 2.0 ).unwrap() );
+
+      // Skew to the lattice of squares, each of which holds two triangles
+      let s = ( x + y ) * f2;
+      let ( i, j ) = ( ( x + s ).floor(), ( y + s ).floor() );
+      let t = ( i + j ) * g2;
+      let x0 = x - ( i - t );
+      let y0 = y - ( j - t );
+
+      let ( i1, j1 ) = if x0 > y0 { ( 1, 0 ) } else { ( 0, 1 ) };
+      let x1 = x0 - E::from( i1 ).unwrap() + g2;
+      let y1 = y0 - E::from( j1 ).unwrap() + g2;
+      let x2 = x0 - one + two * g2;
+      let y2 = y0 - one + two * g2;
+
+      let ( ii, jj ) = ( lattice( i ), lattice( j ) );
+      let falloff = E::from( 0.5 ).unwrap();
+      let n0 = corner( p.hash( ii + p.hash( jj ) ), [ x0, y0 ], falloff );
+      let n1 = corner( p.hash( ii + i1 + p.hash( jj + j1 ) ), [ x1, y1 ], falloff );
+      let n2 = corner( p.hash( ii + 1 + p.hash( jj + 1 ) ), [ x2, y2 ], falloff );
+
+      E::from( 70.0 ).unwrap() * ( n0 + n1 + n2 )
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Noise< E, 3 > for Simplex
+  {
+    fn sample( &self, point : Vector< E, 3 > ) -> E
+    {
+      let p = &self.permutation;
+      let [ x, y, z ] = point.0;
+      let f3 = E::one() / E::from( 3.0 ).unwrap();
+      let g3 = E::one() / E::from( 6.0 ).unwrap();
+
+      // Skew to the lattice of cubes, each of which holds six tetrahedra
+      let s = ( x + y + z ) * f3;
+      let ( i, j, k ) = ( ( x + s ).floor(), ( y + s ).floor(), ( z + s ).floor() );
+      let t = ( i + j + k ) * g3;
+      let d0 = [ x - ( i - t ), y - ( j - t ), z - ( k - t ) ];
+      let [ x0, y0, z0 ] = d0;
+
+      // Offsets of the second and third corners of the tetrahedron containing the point
+      let ( c1, c2 ) = if x0 >= y0
+      {
+        if y0 >= z0 { ( [ 1, 0, 0 ], [ 1, 1, 0 ] ) }
+        else if x0 >= z0 { ( [ 1, 0, 0 ], [ 1, 0, 1 ] ) }
+        else { ( [ 0, 0, 1 ], [ 1, 0, 1 ] ) }
+      }
+      else if y0 < z0 { ( [ 0, 0, 1 ], [ 0, 1, 1 ] ) }
+      else if x0 < z0 { ( [ 0, 1, 0 ], [ 0, 1, 1 ] ) }
+      else { ( [ 0, 1, 0 ], [ 1, 1, 0 ] ) };
+
+      let offset = | c : [ usize; 3 ], g : E | -> [ E; 3 ]
+      {
+        core::array::from_fn( | n | d0[ n ] - E::from( c[ n ] ).unwrap() + g )
+      };
+      let d1 = offset( c1, g3 );
+      let d2 = offset( c2, g3 + g3 );
+      let d3 = offset( [ 1, 1, 1 ], g3 + g3 + g3 );
+
+      let ( ii, jj, kk ) = ( lattice( i ), lattice( j ), lattice( k ) );
+      let hash = | c : [ usize; 3 ] | p.hash( ii + c[ 0 ] + p.hash( jj + c[ 1 ] + p.hash( kk + c[ 2 ] ) ) );
+      let falloff = E::from( 0.6 ).unwrap();
+      let n = corner( hash( [ 0, 0, 0 ] ), d0, falloff )
+      + corner( hash( c1 ), d1, falloff )
+      + corner( hash( c2 ), d2, falloff )
+      + corner( hash( [ 1, 1, 1 ] ), d3, falloff );
+
+      E::from( 32.0 ).unwrap() * n
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Simplex
+  };
+}
@@ -0,0 +1,85 @@
+//! Quaternions.
+
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// A rotation quaternion, stored as `[ x, y, z, w ]` where `w` is the scalar part.
+  #[ derive( Clone, Copy, PartialEq, PartialOrd, Debug ) ]
+  pub struct Quat< E >( pub Vector< E, 4 > )
+  where E : MatEl;
+  pub type QuatF32 = Quat< f32 >;
+  pub type QuatF64 = Quat< f64 >;
+
+  /// A dual quaternion, representing rotation followed by translation.
+  /// Unlike matrices, dual quaternions can be blended without introducing scale or shear,
+  /// which makes them the preferred representation for skinning.
+  #[ derive( Clone, Copy, PartialEq, PartialOrd, Debug ) ]
+  pub struct DualQuat< E >
+  where E : MatEl
+  {
+    /// Rotation part
+    pub real : Quat< E >,
+    /// Translation part, `0.5 * t * real`
+    pub dual : Quat< E >,
+  }
+  pub type DualQuatF32 = DualQuat< f32 >;
+  pub type DualQuatF64 = DualQuat< f64 >;
+
+  /// Order in which Euler angles are applied.
+  ///
+  /// The name lists the axes in the order the rotation matrices are multiplied,
+  /// so `Xyz` is `Rx * Ry * Rz`: `z` is applied to a vector first and `x` last.
+  #[ derive( Clone, Copy, PartialEq, Eq, Hash, Debug, Default ) ]
+  pub enum EulerOrder
+  {
+    #[ default ]
+    Xyz,
+    Xzy,
+    Yxz,
+    Yzx,
+    Zxy,
+    Zyx,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Default for Quat< E >
+  {
+    #[ inline( always ) ]
+    fn default() -> Self
+    {
+      Self::identity()
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Default for DualQuat< E >
+  {
+    #[ inline( always ) ]
+    fn default() -> Self
+    {
+      Self::identity()
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+  /// General for the type implementations
+  layer general;
+  /// Conversions from and to matrices, Euler angles and axis-angle
+  layer conversion;
+  /// Dual quaternion operations
+  layer dual;
+
+  exposed use
+  {
+    Quat,
+    QuatF32,
+    QuatF64,
+    DualQuat,
+    DualQuatF32,
+    DualQuatF64,
+    EulerOrder
+  };
+}
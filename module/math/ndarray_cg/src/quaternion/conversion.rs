@@ -0,0 +1,279 @@
+mod private
+{
+  use crate::*;
+
+  impl< E : MatEl + nd::NdFloat > Quat< E >
+  {
+    /// Creates a rotation of `angle` radians around the `axis`.
+    /// The axis doesn't have to be normalized
+    pub fn from_axis_angle( axis : Vector< E, 3 >, angle : E ) -> Self
+    {
+      let half = angle / E::from( 2.0 ).unwrap();
+      let axis = axis.normalize() * half.sin();
+      Self::new( axis.x(), axis.y(), axis.z(), half.cos() )
+    }
+
+    /// Returns the normalized axis and the angle in radians of the rotation.
+    /// The axis of the identity rotation is `+X`
+    pub fn to_axis_angle( &self ) -> ( Vector< E, 3 >, E )
+    {
+      let q = if self.w() < E::zero() { Self( self.0 * -E::one() ) } else { *self };
+      let angle = E::from( 2.0 ).unwrap() * q.w().min( E::one() ).acos();
+      let axis = q.xyz();
+      if axis.mag2() <= E::epsilon()
+      {
+        return ( Vector( [ E::one(), E::zero(), E::zero() ] ), angle );
+      }
+      ( axis.normalize(), angle )
+    }
+
+    /// Creates a rotation from Euler angles in radians, applied as `Rx * Ry * Rz`
+    pub fn from_euler_xyz( angles : Vector< E, 3 > ) -> Self
+    {
+      Self::from_euler( angles, EulerOrder::Xyz )
+    }
+
+    /// Creates a rotation from Euler angles in radians.
+    /// `angles` are rotations around `x`, `y` and `z` regardless of the `order`
+    pub fn from_euler( angles : Vector< E, 3 >, order : EulerOrder ) -> Self
+    {
+      let x = Self::from_axis_angle( Vector( [ E::one(), E::zero(), E::zero() ] ), angles.x() );
+      let y = Self::from_axis_angle( Vector( [ E::zero(), E::one(), E::zero() ] ), angles.y() );
+      let z = Self::from_axis_angle( Vector( [ E::zero(), E::zero(), E::one() ] ), angles.z() );
+
+      match order
+      {
+        EulerOrder::Xyz => x * y * z,
+        EulerOrder::Xzy => x * z * y,
+        EulerOrder::Yxz => y * x * z,
+        EulerOrder::Yzx => y * z * x,
+        EulerOrder::Zxy => z * x * y,
+        EulerOrder::Zyx => z * y * x,
+      }
+    }
+
+    /// Converts the rotation to Euler angles in radians, such that `Quat::from_euler( angles, order )`
+    /// gives the same rotation. In gimbal lock the last angle in the `order` is zero
+    pub fn to_euler( &self, order : EulerOrder ) -> Vector< E, 3 >
+    {
+      let m = self.rotation_rows();
+      let m = | r : usize, c : usize | m[ r - 1 ][ c - 1 ];
+      let one = E::one();
+      let clamp = | v : E | v.max( -one ).min( one );
+      let locked = | v : E | v.abs() >= E::from( 0.9999999 ).unwrap();
+      let zero = E::zero();
+
+      let ( x, y, z ) = match order
+      {
+        EulerOrder::Xyz =>
+        {
+          let y = clamp( m( 1, 3 ) ).asin();
+          if !locked( m( 1, 3 ) ) { ( ( -m( 2, 3 ) ).atan2( m( 3, 3 ) ), y, ( -m( 1, 2 ) ).atan2( m( 1, 1 ) ) ) }
+          else { ( m( 3, 2 ).atan2( m( 2, 2 ) ), y, zero ) }
+        }
+        EulerOrder::Xzy =>
+        {
+          let z = ( -clamp( m( 1, 2 ) ) ).asin();
+          if !locked( m( 1, 2 ) ) { ( m( 3, 2 ).atan2( m( 2, 2 ) ), m( 1, 3 ).atan2( m( 1, 1 ) ), z ) }
+          else { ( ( -m( 2, 3 ) ).atan2( m( 3, 3 ) ), zero, z ) }
+        }
+        EulerOrder::Yxz =>
+        {
+          let x = ( -clamp( m( 2, 3 ) ) ).asin();
+          if !locked( m( 2, 3 ) ) { ( x, m( 1, 3 ).atan2( m( 3, 3 ) ), m( 2, 1 ).atan2( m( 2, 2 ) ) ) }
+          else { ( x, ( -m( 3, 1 ) ).atan2( m( 1, 1 ) ), zero ) }
+        }
+        EulerOrder::Yzx =>
+        {
+          let z = clamp( m( 2, 1 ) ).asin();
+          if !locked( m( 2, 1 ) ) { ( ( -m( 2, 3 ) ).atan2( m( 2, 2 ) ), ( -m( 3, 1 ) ).atan2( m( 1, 1 ) ), z ) }
+          else { ( zero, m( 1, 3 ).atan2( m( 3, 3 ) ), z ) }
+        }
+        EulerOrder::Zxy =>
+        {
+          let x = clamp( m( 3, 2 ) ).asin();
+          if !locked( m( 3, 2 ) ) { ( x, ( -m( 3, 1 ) ).atan2( m( 3, 3 ) ), ( -m( 1, 2 ) ).atan2( m( 2, 2 ) ) ) }
+          else { ( x, zero, m( 2, 1 ).atan2( m( 1, 1 ) ) ) }
+        }
+        EulerOrder::Zyx =>
+        {
+          let y = ( -clamp( m( 3, 1 ) ) ).asin();
+          if !locked( m( 3, 1 ) ) { ( m( 3, 2 ).atan2( m( 3, 3 ) ), y, m( 2, 1 ).atan2( m( 1, 1 ) ) ) }
+          else { ( zero, y, ( -m( 1, 2 ) ).atan2( m( 2, 2 ) ) ) }
+        }
+      };
+
+      Vector( [ x, y, z ] )
+    }
+
+    /// Creates a quaternion from the rotation part of a matrix.
+    /// The matrix must not contain scale
+    pub fn from_rotation_matrix< Descriptor >( mat : &Mat3< E, Descriptor > ) -> Self
+    where
+      Descriptor : mat::Descriptor,
+      Mat3< E, Descriptor > : ScalarRef< Scalar = E, Index = Ix2 >
+    {
+      let mut rows = [ [ E::zero(); 3 ]; 3 ];
+      for ( r, row ) in rows.iter_mut().enumerate()
+      {
+        for ( c, e ) in row.iter_mut().enumerate()
+        {
+          *e = *mat.scalar_ref( Ix2( r, c ) );
+        }
+      }
+      Self::from_rotation_rows( rows )
+    }
+
+    /// Creates a quaternion from rows of a rotation matrix
+    fn from_rotation_rows( m : [ [ E; 3 ]; 3 ] ) -> Self
+    {
+      let m = | r : usize, c : usize | m[ r - 1 ][ c - 1 ];
+      let two = E::from( 2.0 ).unwrap();
+      let quarter = E::from( 0.25 ).unwrap();
+      let trace = m( 1, 1 ) + m( 2, 2 ) + m( 3, 3 );
+
+      let q = if trace > E::zero()
+      {
+        let s = E::from( 0.5 ).unwrap() / ( trace + E::one() ).sqrt();
+        Self::new
+        (
+          ( m( 3, 2 ) - m( 2, 3 ) ) * s,
+          ( m( 1, 3 ) - m( 3, 1 ) ) * s,
+          ( m( 2, 1 ) - m( 1, 2 ) ) * s,
+          quarter / s
+        )
+      }
+      else if m( 1, 1 ) > m( 2, 2 ) && m( 1, 1 ) > m( 3, 3 )
+      {
+        let s = two * ( E::one() + m( 1, 1 ) - m( 2, 2 ) - m( 3, 3 ) ).sqrt();
+        Self::new
+        (
+          quarter * s,
+          ( m( 1, 2 ) + m( 2, 1 ) ) / s,
+          ( m( 1, 3 ) + m( 3, 1 ) ) / s,
+          ( m( 3, 2 ) - m( 2, 3 ) ) / s
+        )
+      }
+      else if m( 2, 2 ) > m( 3, 3 )
+      {
+        let s = two * ( E::one() + m( 2, 2 ) - m( 1, 1 ) - m( 3, 3 ) ).sqrt();
+        Self::new
+        (
+          ( m( 1, 2 ) + m( 2, 1 ) ) / s,
+          quarter * s,
+          ( m( 2, 3 ) + m( 3, 2 ) ) / s,
+          ( m( 1, 3 ) - m( 3, 1 ) ) / s
+        )
+      }
+      else
+      {
+        let s = two * ( E::one() + m( 3, 3 ) - m( 1, 1 ) - m( 2, 2 ) ).sqrt();
+        Self::new
+        (
+          ( m( 1, 3 ) + m( 3, 1 ) ) / s,
+          ( m( 2, 3 ) + m( 3, 2 ) ) / s,
+          quarter * s,
+          ( m( 2, 1 ) - m( 1, 2 ) ) / s
+        )
+      };
+
+      q.normalize()
+    }
+
+    /// Converts the quaternion to a rotation matrix
+    pub fn to_mat3< Descriptor >( &self ) -> Mat3< E, Descriptor >
+    where
+      Descriptor : mat::Descriptor,
+      Mat3< E, Descriptor > : RawSliceMut< Scalar = E >
+    {
+      let m = self.rotation_rows();
+      Mat3::from_row_major
+      (
+        [
+          m[ 0 ][ 0 ], m[ 0 ][ 1 ], m[ 0 ][ 2 ],
+          m[ 1 ][ 0 ], m[ 1 ][ 1 ], m[ 1 ][ 2 ],
+          m[ 2 ][ 0 ], m[ 2 ][ 1 ], m[ 2 ][ 2 ]
+        ]
+      )
+    }
+
+    /// Converts the quaternion to a homogenous rotation matrix
+    pub fn to_mat4< Descriptor >( &self ) -> Mat4< E, Descriptor >
+    where
+      Descriptor : mat::Descriptor,
+      Mat4< E, Descriptor > : RawSliceMut< Scalar = E >
+    {
+      let m = self.rotation_rows();
+      let ( zero, one ) = ( E::zero(), E::one() );
+      Mat4::from_row_major
+      (
+        [
+          m[ 0 ][ 0 ], m[ 0 ][ 1 ], m[ 0 ][ 2 ], zero,
+          m[ 1 ][ 0 ], m[ 1 ][ 1 ], m[ 1 ][ 2 ], zero,
+          m[ 2 ][ 0 ], m[ 2 ][ 1 ], m[ 2 ][ 2 ], zero,
+          zero,        zero,        zero,        one
+        ]
+      )
+    }
+
+    /// Rotation that turns `+Z` into `forward` and keeps `+Y` as close to `up` as possible.
+    /// If `forward` is parallel to `up`, an arbitrary perpendicular up vector is used
+    pub fn look_rotation( forward : Vector< E, 3 >, up : Vector< E, 3 > ) -> Self
+    {
+      let z = forward.normalize();
+      let mut x = up.cross( z );
+      if x.mag2() <= E::epsilon()
+      {
+        let fallback = if z.x().abs() < E::from( 0.9 ).unwrap()
+        {
+          Vector( [ E::one(), E::zero(), E::zero() ] )
+        }
+        else
+        {
+          Vector( [ E::zero(), E::zero(), E::one() ] )
+        };
+        x = fallback.cross( z );
+      }
+      let x = x.normalize();
+      let y = z.cross( x );
+
+      // Basis vectors are the columns of the rotation matrix
+      Self::from_rotation_rows
+      ([
+        [ x.x(), y.x(), z.x() ],
+        [ x.y(), y.y(), z.y() ],
+        [ x.z(), y.z(), z.z() ],
+      ])
+    }
+
+    /// Rotates `self` towards `target` by at most `max_angle` radians.
+    /// Returns `target` if it is closer than `max_angle`
+    pub fn rotate_towards( self, target : Self, max_angle : E ) -> Self
+    {
+      let angle = self.angle_to( &target );
+      if angle <= max_angle || angle == E::zero()
+      {
+        return target;
+      }
+      self.slerp( target, max_angle.max( E::zero() ) / angle )
+    }
+
+    /// Rows of the rotation matrix of the quaternion
+    fn rotation_rows( &self ) -> [ [ E; 3 ]; 3 ]
+    {
+      let ( x, y, z, w ) = ( self.x(), self.y(), self.z(), self.w() );
+      let one = E::one();
+      let two = E::from( 2.0 ).unwrap();
+      [
+        [ one - two * ( y * y + z * z ), two * ( x * y - z * w ), two * ( x * z + y * w ) ],
+        [ two * ( x * y + z * w ), one - two * ( x * x + z * z ), two * ( y * z - x * w ) ],
+        [ two * ( x * z - y * w ), two * ( y * z + x * w ), one - two * ( x * x + y * y ) ],
+      ]
+    }
+  }
+}
+
+crate::mod_interface!
+{
+
+}
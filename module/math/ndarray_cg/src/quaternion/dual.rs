@@ -0,0 +1,148 @@
+mod private
+{
+  use crate::*;
+
+  impl< E : MatEl + nd::NdFloat > DualQuat< E >
+  {
+    /// Dual quaternion that doesn't transform
+    pub fn identity() -> Self
+    {
+      Self { real : Quat::identity(), dual : Quat( Vector::default() ) }
+    }
+
+    /// Creates a transformation that rotates by `rotation` and then translates by `translation`
+    pub fn from_rotation_translation( rotation : Quat< E >, translation : Vector< E, 3 > ) -> Self
+    {
+      let half = E::from( 0.5 ).unwrap();
+      let t = Quat::new( translation.x(), translation.y(), translation.z(), E::zero() );
+      Self { real : rotation, dual : Quat( ( t * rotation ).0 * half ) }
+    }
+
+    /// Creates a dual quaternion from the rotation and translation of a matrix, scale is dropped.
+    /// If the matrix is degenerate - return `None`
+    pub fn from_mat4< Descriptor >( mat : &Mat4< E, Descriptor > ) -> Option< Self >
+    where
+      Descriptor : mat::Descriptor,
+      Mat4< E, Descriptor > :
+        ScalarMut< Scalar = E, Index = Ix2 > +
+        RawSliceMut< Scalar = E > +
+        ConstLayout< Index = Ix2 > +
+        IndexingMut< Scalar = E, Index = Ix2 >,
+      Mat3< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+    {
+      let ( translation, rotation, _ ) = mat.decompose()?;
+      Some( Self::from_rotation_translation( rotation, translation ) )
+    }
+
+    /// Rotation part of the transformation
+    pub fn rotation( &self ) -> Quat< E >
+    {
+      self.real
+    }
+
+    /// Translation part of the transformation
+    pub fn translation( &self ) -> Vector< E, 3 >
+    {
+      ( self.dual * self.real.conjugate() ).xyz() * E::from( 2.0 ).unwrap()
+    }
+
+    /// Normalizes the dual quaternion, so it represents a rigid transformation
+    pub fn normalize( self ) -> Self
+    {
+      let mag = self.real.mag();
+      let real = Quat( self.real.0 / mag );
+      let dual = Quat( self.dual.0 / mag );
+      // Remove the component of the dual part parallel to the real part
+      let dual = Quat( dual.0 - real.0 * real.dot( &dual ) );
+      Self { real, dual }
+    }
+
+    /// Transforms a point
+    pub fn transform_point( &self, p : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      self.real.rotate( p ) + self.translation()
+    }
+
+    /// Transforms a direction, ignoring the translation
+    pub fn transform_vector( &self, v : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      self.real.rotate( v )
+    }
+
+    /// Converts the dual quaternion to a homogenous transformation matrix
+    pub fn to_mat4< Descriptor >( &self ) -> Mat4< E, Descriptor >
+    where
+      Descriptor : mat::Descriptor,
+      Mat4< E, Descriptor > : RawSliceMut< Scalar = E > + ScalarMut< Scalar = E, Index = Ix2 >
+    {
+      let mut result = self.real.to_mat4::< Descriptor >();
+      let t = self.translation();
+      for r in 0..3
+      {
+        *result.scalar_mut( Ix2( r, 3 ) ) = t.0[ r ];
+      }
+      result
+    }
+
+    /// Dual quaternion linear blending.
+    /// Weights don't have to sum up to one, the result is normalized.
+    /// Quaternions are flipped into the hemisphere of the first one, so the blend takes the shortest path.
+    /// If all weights are zero - return `None`
+    pub fn blend( items : &[ ( Self, E ) ] ) -> Option< Self >
+    {
+      let pivot = items.first()?.0.real;
+      let mut real = Vector::< E, 4 >::default();
+      let mut dual = Vector::< E, 4 >::default();
+      for ( dq, weight ) in items
+      {
+        let weight = if pivot.dot( &dq.real ) < E::zero() { -*weight } else { *weight };
+        real += dq.real.0 * weight;
+        dual += dq.dual.0 * weight;
+      }
+
+      if real.mag2() <= E::epsilon()
+      {
+        return None;
+      }
+      Some( Self { real : Quat( real ), dual : Quat( dual ) }.normalize() )
+    }
+
+    /// Interpolates between two transformations. Equals to `blend` with weights `1 - t` and `t`
+    pub fn lerp( self, rhs : Self, t : E ) -> Self
+    {
+      Self::blend( &[ ( self, E::one() - t ), ( rhs, t ) ] ).unwrap_or( self )
+    }
+  }
+
+  impl< E > Mul for DualQuat< E >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    type Output = Self;
+
+    /// Composes two transformations, `rhs` is applied first
+    fn mul( self, rhs : Self ) -> Self::Output
+    {
+      Self
+      {
+        real : self.real * rhs.real,
+        dual : Quat( ( self.real * rhs.dual ).0 + ( self.dual * rhs.real ).0 ),
+      }
+    }
+  }
+
+  impl< E > MulAssign for DualQuat< E >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    fn mul_assign( &mut self, rhs : Self )
+    {
+      *self = *self * rhs;
+    }
+  }
+}
+
+crate::mod_interface!
+{
+
+}
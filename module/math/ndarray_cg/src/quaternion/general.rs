@@ -0,0 +1,194 @@
+mod private
+{
+  use crate::*;
+  use vector::arithmetics::inner_product::*;
+
+  impl< E : MatEl + nd::NdFloat > Quat< E >
+  {
+    /// Creates a quaternion from its components
+    #[ inline ]
+    pub fn new( x : E, y : E, z : E, w : E ) -> Self
+    {
+      Self( Vector( [ x, y, z, w ] ) )
+    }
+
+    /// Quaternion that doesn't rotate
+    #[ inline ]
+    pub fn identity() -> Self
+    {
+      Self::new( E::zero(), E::zero(), E::zero(), E::one() )
+    }
+
+    #[ inline ]
+    pub fn x( &self ) -> E
+    {
+      self.0.0[ 0 ]
+    }
+
+    #[ inline ]
+    pub fn y( &self ) -> E
+    {
+      self.0.0[ 1 ]
+    }
+
+    #[ inline ]
+    pub fn z( &self ) -> E
+    {
+      self.0.0[ 2 ]
+    }
+
+    #[ inline ]
+    pub fn w( &self ) -> E
+    {
+      self.0.0[ 3 ]
+    }
+
+    /// Vector part of the quaternion
+    #[ inline ]
+    pub fn xyz( &self ) -> Vector< E, 3 >
+    {
+      Vector( [ self.x(), self.y(), self.z() ] )
+    }
+
+    /// Computes the dot product of two quaternions
+    pub fn dot( &self, rhs : &Self ) -> E
+    {
+      dot( &self.0, &rhs.0 )
+    }
+
+    /// Compute the length of the quaternion
+    pub fn mag( &self ) -> E
+    {
+      self.0.mag()
+    }
+
+    /// Normalizes the quaternion
+    pub fn normalize( self ) -> Self
+    {
+      Self( self.0.normalize() )
+    }
+
+    /// Quaternion with the negated vector part.
+    /// For unit quaternions it is the inverse rotation
+    pub fn conjugate( self ) -> Self
+    {
+      Self::new( -self.x(), -self.y(), -self.z(), self.w() )
+    }
+
+    /// Computes the inverse of the quaternion.
+    /// If the quaternion has zero length - return `None`
+    pub fn inverse( self ) -> Option< Self >
+    {
+      let mag2 = self.0.mag2();
+      if mag2 == E::zero()
+      {
+        return None;
+      }
+      Some( Self( self.conjugate().0 / mag2 ) )
+    }
+
+    /// Angle in radians between two rotations
+    pub fn angle_to( &self, rhs : &Self ) -> E
+    {
+      let d = self.dot( rhs ).abs().min( E::one() );
+      E::from( 2.0 ).unwrap() * d.acos()
+    }
+
+    /// Spherical linear interpolation between two rotations along the shortest path
+    pub fn slerp( self, rhs : Self, t : E ) -> Self
+    {
+      let mut cos = self.dot( &rhs );
+      let mut rhs = rhs.0;
+      if cos < E::zero()
+      {
+        cos = -cos;
+        rhs = rhs * -E::one();
+      }
+
+      // Rotations are almost the same, fall back to linear interpolation
+      if cos > E::one() - E::epsilon()
+      {
+        return Self( self.0 * ( E::one() - t ) + rhs * t ).normalize();
+      }
+
+      let theta = cos.acos();
+      let sin = theta.sin();
+      let a = ( ( E::one() - t ) * theta ).sin() / sin;
+      let b = ( t * theta ).sin() / sin;
+      Self( self.0 * a + rhs * b )
+    }
+
+    /// Rotates the vector by the quaternion
+    pub fn rotate( &self, v : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      // v' = v + 2w( u x v ) + 2u x ( u x v )
+      let u = self.xyz();
+      let t = u.cross( v ) * E::from( 2.0 ).unwrap();
+      v + t * self.w() + u.cross( t )
+    }
+  }
+
+  impl< E > Mul for Quat< E >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    type Output = Self;
+
+    /// Composes two rotations, `rhs` is applied first
+    fn mul( self, rhs : Self ) -> Self::Output
+    {
+      let ( x1, y1, z1, w1 ) = ( self.x(), self.y(), self.z(), self.w() );
+      let ( x2, y2, z2, w2 ) = ( rhs.x(), rhs.y(), rhs.z(), rhs.w() );
+      Self::new
+      (
+        w1 * x2 + x1 * w2 + y1 * z2 - z1 * y2,
+        w1 * y2 - x1 * z2 + y1 * w2 + z1 * x2,
+        w1 * z2 + x1 * y2 - y1 * x2 + z1 * w2,
+        w1 * w2 - x1 * x2 - y1 * y2 - z1 * z2
+      )
+    }
+  }
+
+  impl< E > MulAssign for Quat< E >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    fn mul_assign( &mut self, rhs : Self )
+    {
+      *self = *self * rhs;
+    }
+  }
+
+  impl< E > Mul< Vector< E, 3 > > for Quat< E >
+  where
+    E : MatEl + nd::NdFloat
+  {
+    type Output = Vector< E, 3 >;
+
+    fn mul( self, rhs : Vector< E, 3 > ) -> Self::Output
+    {
+      self.rotate( rhs )
+    }
+  }
+
+  impl< E : MatEl > From< [ E; 4 ] > for Quat< E >
+  {
+    fn from( value : [ E; 4 ] ) -> Self
+    {
+      Self( Vector( value ) )
+    }
+  }
+
+  impl< E : MatEl > From< Quat< E > > for [ E; 4 ]
+  {
+    fn from( value : Quat< E > ) -> Self
+    {
+      value.0.0
+    }
+  }
+}
+
+crate::mod_interface!
+{
+
+}
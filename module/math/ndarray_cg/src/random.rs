@@ -0,0 +1,19 @@
+//! Seeded random numbers, sampling distributions and low-discrepancy sequences.
+//!
+//! Distributions map uniform numbers in `[ 0, 1 )` to the target domain, so they can be fed
+//! either with a random generator or with a low-discrepancy sequence.
+
+/// Internal namespace.
+mod private
+{
+}
+
+crate::mod_interface!
+{
+  /// Permuted congruential generator
+  layer pcg;
+  /// Mapping of uniform numbers to disks, spheres and hemispheres
+  layer distribution;
+  /// Halton, Hammersley and Sobol sequences
+  layer sequence;
+}
@@ -0,0 +1,84 @@
+mod private
+{
+  use crate::*;
+
+  fn tau< E : nd::NdFloat >() -> E
+  {
+    E::from( core::f64::consts::TAU ).unwrap()
+  }
+
+  /// Uniformly distributed point in the unit disk
+  pub fn uniform_disk< E : MatEl + nd::NdFloat >( u : Vector< E, 2 > ) -> Vector< E, 2 >
+  {
+    let r = u.0[ 0 ].sqrt();
+    let phi = tau::< E >() * u.0[ 1 ];
+    Vector( [ r * phi.cos(), r * phi.sin() ] )
+  }
+
+  /// Uniformly distributed point on the unit sphere
+  pub fn uniform_sphere< E : MatEl + nd::NdFloat >( u : Vector< E, 2 > ) -> Vector< E, 3 >
+  {
+    let z = E::one() - E::from( 2.0 ).unwrap() * u.0[ 0 ];
+    let r = ( E::one() - z * z ).max( E::zero() ).sqrt();
+    let phi = tau::< E >() * u.0[ 1 ];
+    Vector( [ r * phi.cos(), r * phi.sin(), z ] )
+  }
+
+  /// Uniformly distributed point in the unit ball
+  pub fn uniform_ball< E : MatEl + nd::NdFloat >( u : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    let direction = uniform_sphere( Vector( [ u.0[ 0 ], u.0[ 1 ] ] ) );
+    direction * u.0[ 2 ].cbrt()
+  }
+
+  /// Uniformly distributed point on the unit hemisphere around `+Z`
+  pub fn uniform_hemisphere< E : MatEl + nd::NdFloat >( u : Vector< E, 2 > ) -> Vector< E, 3 >
+  {
+    let z = u.0[ 0 ];
+    let r = ( E::one() - z * z ).max( E::zero() ).sqrt();
+    let phi = tau::< E >() * u.0[ 1 ];
+    Vector( [ r * phi.cos(), r * phi.sin(), z ] )
+  }
+
+  /// Point on the unit hemisphere around `+Z`, with density proportional to the cosine of the angle with `+Z`.
+  /// Importance sampling of diffuse lighting
+  pub fn cosine_hemisphere< E : MatEl + nd::NdFloat >( u : Vector< E, 2 > ) -> Vector< E, 3 >
+  {
+    let d = uniform_disk( u );
+    let z = ( E::one() - u.0[ 0 ] ).max( E::zero() ).sqrt();
+    Vector( [ d.0[ 0 ], d.0[ 1 ], z ] )
+  }
+
+  /// Probability density of `cosine_hemisphere` for the direction with the `cos_theta` to `+Z`
+  pub fn cosine_hemisphere_pdf< E : nd::NdFloat >( cos_theta : E ) -> E
+  {
+    cos_theta.max( E::zero() ) / E::from( core::f64::consts::PI ).unwrap()
+  }
+
+  /// Transforms a sample around `+Z` to be around the unit `normal`
+  pub fn orient< E : MatEl + nd::NdFloat >( sample : Vector< E, 3 >, normal : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    // Orthonormal basis without branches on the normal direction, Duff et al. 2017
+    let [ nx, ny, nz ] = normal.0;
+    let sign = E::one().copysign( nz );
+    let a = -E::one() / ( sign + nz );
+    let b = nx * ny * a;
+    let tangent = Vector( [ E::one() + sign * nx * nx * a, sign * b, -sign * nx ] );
+    let bitangent = Vector( [ b, sign + ny * ny * a, -ny ] );
+    tangent * sample.0[ 0 ] + bitangent * sample.0[ 1 ] + normal * sample.0[ 2 ]
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use
+  {
+    uniform_disk,
+    uniform_sphere,
+    uniform_ball,
+    uniform_hemisphere,
+    cosine_hemisphere,
+    cosine_hemisphere_pdf,
+    orient
+  };
+}
@@ -0,0 +1,101 @@
+mod private
+{
+  use crate::*;
+
+  const MULTIPLIER : u64 = 6_364_136_223_846_793_005;
+
+  /// PCG32 random number generator: small, fast and statistically good, with the same output on every platform.
+  /// Not suitable for cryptography
+  #[ derive( Clone, Debug, PartialEq, Eq, Hash ) ]
+  pub struct Pcg32
+  {
+    state : u64,
+    increment : u64,
+  }
+
+  impl Pcg32
+  {
+    /// Creates a generator from the seed
+    pub fn new( seed : u64 ) -> Self
+    {
+      Self::with_stream( seed, 0xDA3E_39CB_94B9_5BDB )
+    }
+
+    /// Creates a generator from the seed and the stream.
+    /// Generators with the same seed but different streams produce independent sequences
+    pub fn with_stream( seed : u64, stream : u64 ) -> Self
+    {
+      let mut rng = Self { state : 0, increment : ( stream << 1 ) | 1 };
+      rng.next_u32();
+      rng.state = rng.state.wrapping_add( seed );
+      rng.next_u32();
+      rng
+    }
+
+    pub fn next_u32( &mut self ) -> u32
+    {
+      let old = self.state;
+      self.state = old.wrapping_mul( MULTIPLIER ).wrapping_add( self.increment );
+      let xorshifted = ( ( ( old >> 18 ) ^ old ) >> 27 ) as u32;
+      let rotation = ( old >> 59 ) as u32;
+      xorshifted.rotate_right( rotation )
+    }
+
+    pub fn next_u64( &mut self ) -> u64
+    {
+      ( u64::from( self.next_u32() ) << 32 ) | u64::from( self.next_u32() )
+    }
+
+    /// Uniform integer in `[ 0, bound )`, without modulo bias. `bound` must not be zero
+    pub fn next_below( &mut self, bound : u32 ) -> u32
+    {
+      debug_assert!( bound > 0, "Bound must not be zero" );
+      let threshold = bound.wrapping_neg() % bound;
+      loop
+      {
+        let r = self.next_u32();
+        if r >= threshold
+        {
+          return r % bound;
+        }
+      }
+    }
+
+    /// Uniform float in `[ 0, 1 )`
+    pub fn next_float< E : nd::NdFloat >( &mut self ) -> E
+    {
+      // 24 bits fit the f32 mantissa exactly, so the result never rounds up to 1
+      E::from( f64::from( self.next_u32() >> 8 ) / f64::from( 1u32 << 24 ) ).unwrap()
+    }
+
+    /// Uniform float in `[ min, max )`
+    pub fn range< E : nd::NdFloat >( &mut self, min : E, max : E ) -> E
+    {
+      min + ( max - min ) * self.next_float::< E >()
+    }
+
+    /// Vector with elements uniform in `[ 0, 1 )`, for feeding the distributions
+    pub fn next_vector< E : MatEl + nd::NdFloat, const N : usize >( &mut self ) -> Vector< E, N >
+    {
+      Vector( core::array::from_fn( | _ | self.next_float() ) )
+    }
+
+    /// Shuffles the slice in place
+    pub fn shuffle< T >( &mut self, slice : &mut [ T ] )
+    {
+      for i in ( 1..slice.len() ).rev()
+      {
+        let j = self.next_below( i as u32 + 1 ) as usize;
+        slice.swap( i, j );
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Pcg32
+  };
+}
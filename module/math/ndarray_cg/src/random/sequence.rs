@@ -0,0 +1,75 @@
+mod private
+{
+  use crate::*;
+
+  /// Bases of the first dimensions of the Halton sequence
+  const PRIMES : [ u32; 8 ] = [ 2, 3, 5, 7, 11, 13, 17, 19 ];
+
+  /// Mirrors the digits of `index` in `base` around the decimal point.
+  /// Panics if `base` is less than 2
+  pub fn radical_inverse< E : nd::NdFloat >( mut index : u32, base : u32 ) -> E
+  {
+    assert!( base >= 2, "Base of the radical inverse must be at least 2, got {}", base );
+    let inv_base = 1.0 / f64::from( base );
+    let mut factor = inv_base;
+    let mut result = 0.0;
+    while index > 0
+    {
+      result += f64::from( index % base ) * factor;
+      index /= base;
+      factor *= inv_base;
+    }
+    E::from( result ).unwrap()
+  }
+
+  /// Point of the Halton sequence in up to 8 dimensions
+  pub fn halton< E : MatEl + nd::NdFloat, const N : usize >( index : u32 ) -> Vector< E, N >
+  {
+    assert!( N <= PRIMES.len(), "Halton sequence supports up to {} dimensions", PRIMES.len() );
+    Vector( core::array::from_fn( | i | radical_inverse( index, PRIMES[ i ] ) ) )
+  }
+
+  /// Point `index` of `count` points of the Hammersley set, evenly covering the unit square.
+  /// Unlike sequences, the number of points has to be known in advance. Panics if `count` is 0
+  pub fn hammersley< E : MatEl + nd::NdFloat >( index : u32, count : u32 ) -> Vector< E, 2 >
+  {
+    assert!( count > 0, "Hammersley set must have at least one point" );
+    let x = E::from( f64::from( index ) / f64::from( count ) ).unwrap();
+    Vector( [ x, sobol_dimension( index.reverse_bits() ) ] )
+  }
+
+  /// Point of the first two dimensions of the Sobol sequence
+  pub fn sobol2< E : MatEl + nd::NdFloat >( index : u32 ) -> Vector< E, 2 >
+  {
+    let mut v = 1u32 << 31;
+    let mut y = 0u32;
+    let mut i = index;
+    while i > 0
+    {
+      if i & 1 == 1
+      {
+        y ^= v;
+      }
+      v ^= v >> 1;
+      i >>= 1;
+    }
+    Vector( [ sobol_dimension( index.reverse_bits() ), sobol_dimension( y ) ] )
+  }
+
+  /// Maps 32 bits of a sequence to `[ 0, 1 )`
+  fn sobol_dimension< E : nd::NdFloat >( bits : u32 ) -> E
+  {
+    E::from( f64::from( bits ) / 4_294_967_296.0 ).unwrap()
+  }
+}
+
+crate::mod_interface!
+{
+  orphan use
+  {
+    radical_inverse,
+    halton,
+    hammersley,
+    sobol2
+  };
+}
@@ -0,0 +1,285 @@
+//! SIMD kernels for the hot `f32` operations.
+//!
+//! Uses `simd128` on wasm when the target feature is enabled and `std::simd` on other targets
+//! when the `nightly` feature is enabled, which requires a nightly compiler. Otherwise kernels fall back to scalar code.
+//! Matrices are passed as column-major arrays.
+
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  #[ cfg( all( target_arch = "wasm32", target_feature = "simd128" ) ) ]
+  mod backend
+  {
+    use core::arch::wasm32::*;
+
+    pub type Lane = v128;
+
+    #[ inline( always ) ]
+    pub fn load( a : [ f32; 4 ] ) -> Lane
+    {
+      f32x4( a[ 0 ], a[ 1 ], a[ 2 ], a[ 3 ] )
+    }
+
+    #[ inline( always ) ]
+    pub fn store( a : Lane ) -> [ f32; 4 ]
+    {
+      [
+        f32x4_extract_lane::< 0 >( a ),
+        f32x4_extract_lane::< 1 >( a ),
+        f32x4_extract_lane::< 2 >( a ),
+        f32x4_extract_lane::< 3 >( a ),
+      ]
+    }
+
+    #[ inline( always ) ]
+    pub fn splat( a : f32 ) -> Lane
+    {
+      f32x4_splat( a )
+    }
+
+    #[ inline( always ) ]
+    pub fn add( a : Lane, b : Lane ) -> Lane
+    {
+      f32x4_add( a, b )
+    }
+
+    #[ inline( always ) ]
+    pub fn mul( a : Lane, b : Lane ) -> Lane
+    {
+      f32x4_mul( a, b )
+    }
+
+    #[ inline( always ) ]
+    pub fn sum( a : Lane ) -> f32
+    {
+      // Pairwise: [ a0 + a2, a1 + a3, .. ], then add the two halves
+      let a = f32x4_add( a, i32x4_shuffle::< 2, 3, 0, 1 >( a, a ) );
+      let a = f32x4_add( a, i32x4_shuffle::< 1, 0, 3, 2 >( a, a ) );
+      f32x4_extract_lane::< 0 >( a )
+    }
+  }
+
+  #[ cfg( all( not( target_arch = "wasm32" ), feature = "nightly" ) ) ]
+  mod backend
+  {
+    use std::simd::{ f32x4, num::SimdFloat };
+
+    pub type Lane = f32x4;
+
+    #[ inline( always ) ]
+    pub fn load( a : [ f32; 4 ] ) -> Lane
+    {
+      f32x4::from_array( a )
+    }
+
+    #[ inline( always ) ]
+    pub fn store( a : Lane ) -> [ f32; 4 ]
+    {
+      a.to_array()
+    }
+
+    #[ inline( always ) ]
+    pub fn splat( a : f32 ) -> Lane
+    {
+      f32x4::splat( a )
+    }
+
+    #[ inline( always ) ]
+    pub fn add( a : Lane, b : Lane ) -> Lane
+    {
+      a + b
+    }
+
+    #[ inline( always ) ]
+    pub fn mul( a : Lane, b : Lane ) -> Lane
+    {
+      a * b
+    }
+
+    #[ inline( always ) ]
+    pub fn sum( a : Lane ) -> f32
+    {
+      a.reduce_sum()
+    }
+  }
+
+  #[ cfg( not( any( all( target_arch = "wasm32", target_feature = "simd128" ), all( not( target_arch = "wasm32" ), feature = "nightly" ) ) ) ) ]
+  mod backend
+  {
+    pub type Lane = [ f32; 4 ];
+
+    #[ inline( always ) ]
+    pub fn load( a : [ f32; 4 ] ) -> Lane
+    {
+      a
+    }
+
+    #[ inline( always ) ]
+    pub fn store( a : Lane ) -> [ f32; 4 ]
+    {
+      a
+    }
+
+    #[ inline( always ) ]
+    pub fn splat( a : f32 ) -> Lane
+    {
+      [ a; 4 ]
+    }
+
+    #[ inline( always ) ]
+    pub fn add( a : Lane, b : Lane ) -> Lane
+    {
+      [ a[ 0 ] + b[ 0 ], a[ 1 ] + b[ 1 ], a[ 2 ] + b[ 2 ], a[ 3 ] + b[ 3 ] ]
+    }
+
+    #[ inline( always ) ]
+    pub fn mul( a : Lane, b : Lane ) -> Lane
+    {
+      [ a[ 0 ] * b[ 0 ], a[ 1 ] * b[ 1 ], a[ 2 ] * b[ 2 ], a[ 3 ] * b[ 3 ] ]
+    }
+
+    #[ inline( always ) ]
+    pub fn sum( a : Lane ) -> f32
+    {
+      ( a[ 0 ] + a[ 2 ] ) + ( a[ 1 ] + a[ 3 ] )
+    }
+  }
+
+  use backend::*;
+
+  #[ inline( always ) ]
+  fn column( m : &[ f32; 16 ], c : usize ) -> Lane
+  {
+    load( [ m[ c * 4 ], m[ c * 4 + 1 ], m[ c * 4 + 2 ], m[ c * 4 + 3 ] ] )
+  }
+
+  #[ inline( always ) ]
+  fn combine( m : &[ Lane; 4 ], v : [ f32; 4 ] ) -> Lane
+  {
+    let r = mul( m[ 0 ], splat( v[ 0 ] ) );
+    let r = add( r, mul( m[ 1 ], splat( v[ 1 ] ) ) );
+    let r = add( r, mul( m[ 2 ], splat( v[ 2 ] ) ) );
+    add( r, mul( m[ 3 ], splat( v[ 3 ] ) ) )
+  }
+
+  /// Computes the dot product of two 4D vectors
+  #[ inline ]
+  pub fn dot4( a : [ f32; 4 ], b : [ f32; 4 ] ) -> f32
+  {
+    sum( mul( load( a ), load( b ) ) )
+  }
+
+  /// Normalizes a 4D vector. Zero vector gives NaNs, same as the scalar `normalize`
+  #[ inline ]
+  pub fn normalize4( a : [ f32; 4 ] ) -> [ f32; 4 ]
+  {
+    let a = load( a );
+    let inv = 1.0 / sum( mul( a, a ) ).sqrt();
+    store( mul( a, splat( inv ) ) )
+  }
+
+  /// Normalizes a 3D vector
+  #[ inline ]
+  pub fn normalize3( a : [ f32; 3 ] ) -> [ f32; 3 ]
+  {
+    let r = normalize4( [ a[ 0 ], a[ 1 ], a[ 2 ], 0.0 ] );
+    [ r[ 0 ], r[ 1 ], r[ 2 ] ]
+  }
+
+  /// Multiplies two column-major 4x4 matrices
+  #[ inline ]
+  pub fn mat4_mul( a : &[ f32; 16 ], b : &[ f32; 16 ] ) -> [ f32; 16 ]
+  {
+    let a = [ column( a, 0 ), column( a, 1 ), column( a, 2 ), column( a, 3 ) ];
+    let mut result = [ 0.0; 16 ];
+    for c in 0..4
+    {
+      let v = [ b[ c * 4 ], b[ c * 4 + 1 ], b[ c * 4 + 2 ], b[ c * 4 + 3 ] ];
+      result[ c * 4..c * 4 + 4 ].copy_from_slice( &store( combine( &a, v ) ) );
+    }
+    result
+  }
+
+  /// Multiplies a column-major 4x4 matrix by a column vector
+  #[ inline ]
+  pub fn mat4_mul_vec4( a : &[ f32; 16 ], v : [ f32; 4 ] ) -> [ f32; 4 ]
+  {
+    let a = [ column( a, 0 ), column( a, 1 ), column( a, 2 ), column( a, 3 ) ];
+    store( combine( &a, v ) )
+  }
+
+  impl Vector< f32, 4 >
+  {
+    /// SIMD version of the dot product
+    #[ inline ]
+    pub fn dot_simd( &self, rhs : &Self ) -> f32
+    {
+      dot4( self.0, rhs.0 )
+    }
+
+    /// SIMD version of `normalize`
+    #[ inline ]
+    pub fn normalize_simd( self ) -> Self
+    {
+      Vector( normalize4( self.0 ) )
+    }
+  }
+
+  impl Vector< f32, 3 >
+  {
+    /// SIMD version of `normalize`
+    #[ inline ]
+    pub fn normalize_simd( self ) -> Self
+    {
+      Vector( normalize3( self.0 ) )
+    }
+  }
+
+  impl< Descriptor > Mat4< f32, Descriptor >
+  where
+    Descriptor : mat::Descriptor,
+    Self : RawSliceMut< Scalar = f32 >
+  {
+    /// SIMD version of the matrix multiplication
+    pub fn mul_simd( &self, rhs : &Self ) -> Self
+    {
+      let a : &[ f32; 16 ] = self.raw_slice().try_into().unwrap();
+      let b : &[ f32; 16 ] = rhs.raw_slice().try_into().unwrap();
+      // Row-major buffer holds the transposed matrix, so ( A * B )^T = B^T * A^T
+      let raw = if < Descriptor as mat::Descriptor >::IS_ROW_MAJOR { mat4_mul( b, a ) } else { mat4_mul( a, b ) };
+      let mut result = Self::default();
+      result.raw_slice_mut().copy_from_slice( &raw );
+      result
+    }
+
+    /// SIMD version of the matrix-vector multiplication
+    pub fn mul_vec_simd( &self, rhs : Vector< f32, 4 > ) -> Vector< f32, 4 >
+    {
+      let a : &[ f32; 16 ] = self.raw_slice().try_into().unwrap();
+      if < Descriptor as mat::Descriptor >::IS_ROW_MAJOR
+      {
+        let rows = [ 0, 1, 2, 3 ].map( | r | [ a[ r * 4 ], a[ r * 4 + 1 ], a[ r * 4 + 2 ], a[ r * 4 + 3 ] ] );
+        Vector( rows.map( | row | dot4( row, rhs.0 ) ) )
+      }
+      else
+      {
+        Vector( mat4_mul_vec4( a, rhs.0 ) )
+      }
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    dot4,
+    normalize4,
+    normalize3,
+    mat4_mul,
+    mat4_mul_vec4
+  };
+}
@@ -0,0 +1,65 @@
+//! Transformations: translation, rotation and scale, and hierarchies of them.
+
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Transformation, which scales, then rotates and then translates.
+  ///
+  /// Unlike a matrix, it keeps its components separately, so they can be edited and interpolated.
+  /// Not every affine matrix can be represented: shear is lost by `from_matrix`.
+  #[ derive( Clone, Copy, PartialEq, Debug ) ]
+  pub struct Transform< E >
+  where E : MatEl
+  {
+    /// Translation
+    pub t : Vector< E, 3 >,
+    /// Rotation
+    pub r : Quat< E >,
+    /// Scale
+    pub s : Vector< E, 3 >,
+  }
+  pub type TransformF32 = Transform< f32 >;
+  pub type TransformF64 = Transform< f64 >;
+
+  /// Stack of world matrices for walking a transform hierarchy.
+  ///
+  /// The bottom of the stack is the root, which is never popped.
+  /// Every pushed local transformation is composed with the current top, so the top is always
+  /// the world matrix of the node being visited.
+  #[ derive( Clone, Debug ) ]
+  pub struct TransformStack< E, Descriptor >
+  where
+    E : MatEl,
+    Descriptor : mat::Descriptor,
+  {
+    pub( crate ) stack : Vec< Mat4< E, Descriptor > >,
+  }
+
+  impl< E : MatEl + nd::NdFloat > Default for Transform< E >
+  {
+    #[ inline( always ) ]
+    fn default() -> Self
+    {
+      Self::identity()
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+  /// General for the `Transform` implementations
+  layer general;
+  /// Hierarchy traversal
+  layer stack;
+
+  exposed use
+  {
+    Transform,
+    TransformF32,
+    TransformF64,
+    TransformStack
+  };
+}
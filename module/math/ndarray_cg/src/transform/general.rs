@@ -0,0 +1,137 @@
+mod private
+{
+  use crate::*;
+
+  fn mul_components< E : MatEl + nd::NdFloat >( a : Vector< E, 3 >, b : Vector< E, 3 > ) -> Vector< E, 3 >
+  {
+    Vector( [ a.0[ 0 ] * b.0[ 0 ], a.0[ 1 ] * b.0[ 1 ], a.0[ 2 ] * b.0[ 2 ] ] )
+  }
+
+  impl< E : MatEl + nd::NdFloat > Transform< E >
+  {
+    /// Creates a transformation from translation, rotation and scale
+    pub fn new( t : Vector< E, 3 >, r : Quat< E >, s : Vector< E, 3 > ) -> Self
+    {
+      Self { t, r, s }
+    }
+
+    /// Transformation that doesn't change anything
+    pub fn identity() -> Self
+    {
+      Self { t : Vector::default(), r : Quat::identity(), s : Vector::from( [ E::one(); 3 ] ) }
+    }
+
+    /// Transformation that only translates
+    pub fn from_translation( t : Vector< E, 3 > ) -> Self
+    {
+      Self { t, ..Self::identity() }
+    }
+
+    /// Transformation that only rotates
+    pub fn from_rotation( r : Quat< E > ) -> Self
+    {
+      Self { r, ..Self::identity() }
+    }
+
+    /// Transformation that only scales
+    pub fn from_scale( s : Vector< E, 3 > ) -> Self
+    {
+      Self { s, ..Self::identity() }
+    }
+
+    /// Converts the transformation to a homogenous matrix
+    pub fn to_matrix< Descriptor >( &self ) -> Mat4< E, Descriptor >
+    where
+      Descriptor : mat::Descriptor,
+      Mat4< E, Descriptor > :
+        ScalarMut< Scalar = E, Index = Ix2 > +
+        RawSliceMut< Scalar = E > +
+        ConstLayout< Index = Ix2 > +
+        IndexingMut< Scalar = E, Index = Ix2 >,
+    {
+      Mat4::from_trs( self.t, self.r, self.s )
+    }
+
+    /// Decomposes an affine matrix into a transformation.
+    /// If any scale of the matrix is zero - return `None`
+    pub fn from_matrix< Descriptor >( mat : &Mat4< E, Descriptor > ) -> Option< Self >
+    where
+      Descriptor : mat::Descriptor,
+      Mat4< E, Descriptor > :
+        ScalarMut< Scalar = E, Index = Ix2 > +
+        RawSliceMut< Scalar = E > +
+        ConstLayout< Index = Ix2 > +
+        IndexingMut< Scalar = E, Index = Ix2 >,
+      Mat3< E, Descriptor > : ScalarMut< Scalar = E, Index = Ix2 >
+    {
+      let ( t, r, s ) = mat.decompose()?;
+      Some( Self { t, r, s } )
+    }
+
+    /// Transforms a point
+    pub fn transform_point( &self, p : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      self.r.rotate( mul_components( p, self.s ) ) + self.t
+    }
+
+    /// Transforms a direction, ignoring the translation
+    pub fn transform_vector( &self, v : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      self.r.rotate( mul_components( v, self.s ) )
+    }
+
+    /// Composes two transformations, so `child` is applied first and `self` after it.
+    /// The result is exact only when `self` has uniform scale, otherwise the shear is lost -
+    /// compose matrices instead.
+    pub fn then( &self, child : &Self ) -> Self
+    {
+      Self
+      {
+        t : self.transform_point( child.t ),
+        r : self.r * child.r,
+        s : mul_components( self.s, child.s ),
+      }
+    }
+
+    /// Inverse of the transformation.
+    /// Exact only for uniform scale. If any scale is zero - return `None`
+    pub fn inverse( &self ) -> Option< Self >
+    {
+      if self.s.0.iter().any( | s | s.abs() <= E::epsilon() )
+      {
+        return None;
+      }
+      let s = Vector( self.s.0.map( | s | E::one() / s ) );
+      let r = self.r.conjugate();
+      let t = mul_components( r.rotate( self.t ), s ) * -E::one();
+      Some( Self { t, r, s } )
+    }
+
+    /// Interpolates translation and scale linearly and rotation spherically
+    pub fn lerp( &self, other : &Self, t : E ) -> Self
+    {
+      Self
+      {
+        t : self.t + ( other.t - self.t ) * t,
+        r : self.r.slerp( other.r, t ),
+        s : self.s + ( other.s - self.s ) * t,
+      }
+    }
+  }
+
+  impl< E : MatEl + nd::NdFloat > Mul for Transform< E >
+  {
+    type Output = Self;
+
+    fn mul( self, rhs : Self ) -> Self::Output
+    {
+      self.then( &rhs )
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+
+}
@@ -0,0 +1,112 @@
+mod private
+{
+  use crate::*;
+
+  impl< E, Descriptor > TransformStack< E, Descriptor >
+  where
+    E : MatEl + nd::NdFloat,
+    Descriptor : mat::Descriptor,
+    Mat4< E, Descriptor > :
+      ScalarMut< Scalar = E, Index = Ix2 > +
+      RawSliceMut< Scalar = E > +
+      ConstLayout< Index = Ix2 > +
+      IndexingMut< Scalar = E, Index = Ix2 >,
+  {
+    /// Creates a stack with the identity root
+    pub fn new() -> Self
+    {
+      Self::with_root( Mat4::identity() )
+    }
+
+    /// Creates a stack with the given root, for example a view matrix
+    pub fn with_root( root : Mat4< E, Descriptor > ) -> Self
+    {
+      Self { stack : vec![ root ] }
+    }
+
+    /// World matrix of the current node
+    pub fn world( &self ) -> &Mat4< E, Descriptor >
+    {
+      self.stack.last().expect( "Root is never popped" )
+    }
+
+    /// Number of pushed nodes, the root is not counted
+    pub fn depth( &self ) -> usize
+    {
+      self.stack.len() - 1
+    }
+
+    /// Enters a child node with the given local matrix and returns its world matrix
+    pub fn push( &mut self, local : &Mat4< E, Descriptor > ) -> &Mat4< E, Descriptor >
+    {
+      let world = self.world() * local;
+      self.stack.push( world );
+      self.world()
+    }
+
+    /// Enters a child node with the given local transformation and returns its world matrix
+    pub fn push_transform( &mut self, local : &Transform< E > ) -> &Mat4< E, Descriptor >
+    {
+      self.push( &local.to_matrix() )
+    }
+
+    /// Leaves the current node and returns its world matrix.
+    /// If only the root is left - return `None`
+    pub fn pop( &mut self ) -> Option< Mat4< E, Descriptor > >
+    {
+      if self.stack.len() > 1
+      {
+        self.stack.pop()
+      }
+      else
+      {
+        None
+      }
+    }
+
+    /// Pushes `local`, runs `f` and returns to the current node, even if `f` left nodes pushed
+    pub fn scoped< R >( &mut self, local : &Mat4< E, Descriptor >, f : impl FnOnce( &mut Self ) -> R ) -> R
+    {
+      let depth = self.stack.len();
+      self.push( local );
+      let result = f( self );
+      self.stack.truncate( depth );
+      result
+    }
+
+    /// Pops everything except the root
+    pub fn reset( &mut self )
+    {
+      self.stack.truncate( 1 );
+    }
+
+    /// Transforms a point from the space of the current node to the world space
+    pub fn transform_point( &self, p : Vector< E, 3 > ) -> Vector< E, 3 >
+    {
+      let p = self.world() * &Vector( [ p.0[ 0 ], p.0[ 1 ], p.0[ 2 ], E::one() ] );
+      Vector( [ p.0[ 0 ], p.0[ 1 ], p.0[ 2 ] ] )
+    }
+  }
+
+  impl< E, Descriptor > Default for TransformStack< E, Descriptor >
+  where
+    E : MatEl + nd::NdFloat,
+    Descriptor : mat::Descriptor,
+    Mat4< E, Descriptor > :
+      ScalarMut< Scalar = E, Index = Ix2 > +
+      RawSliceMut< Scalar = E > +
+      ConstLayout< Index = Ix2 > +
+      IndexingMut< Scalar = E, Index = Ix2 >,
+  {
+    fn default() -> Self
+    {
+      Self::new()
+    }
+  }
+
+}
+
+crate::mod_interface!
+{
+
+}
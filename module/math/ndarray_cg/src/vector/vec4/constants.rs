@@ -2,43 +2,53 @@ mod private
 {
   use crate::*;
 
-  impl F32x4 
+  macro_rules! constants
   {
-    /// Unit `x` vector
-    pub const X : Self = Self::new( 1.0, 0.0, 0.0, 0.0 );
-
-    /// Unit `y` vector
-    pub const Y : Self = Self::new( 0.0, 1.0, 0.0, 0.0 );
-    
-    /// Unit `z` vector
-    pub const Z : Self = Self::new( 0.0, 0.0, 1.0, 0.0 );
-
-    /// Unit `w` vector
-    pub const W : Self = Self::new( 0.0, 0.0, 0.0, 1.0);
-
-    /// All elements are `f32::MIN`
-    pub const MIN : Self = Self::splat( f32::MIN );
-
-    /// All elements are `f32::MAX`
-    pub const MAX : Self = Self::splat( f32::MAX );
-
-    /// All elemets are `ZERO`
-    pub const ZERO : Self = Self::splat( 0.0 );
-
-    /// Creates a new vector
-    #[inline(always)]
-    pub const fn new( x : f32, y : f32, z : f32, w : f32 ) -> Self
-    {
-        Vector::< f32, 4 >( [ x, y, z, w ] )
-    } 
-
-    /// Creates a vector from a single value : [ v ; N ]
-    #[inline(always)]
-    pub const fn splat( v : f32 ) -> Self
+    ( $( $Ty : ty, $E : ident );* ) =>
     {
-        Vector::< f32, 4 >( [ v; 4 ] )
-    }
+      $(
+      impl $Ty 
+      {
+        /// Unit `x` vector
+        pub const X : Self = Self::new( 1.0, 0.0, 0.0, 0.0 );
+
+        /// Unit `y` vector
+        pub const Y : Self = Self::new( 0.0, 1.0, 0.0, 0.0 );
+    
+        /// Unit `z` vector
+        pub const Z : Self = Self::new( 0.0, 0.0, 1.0, 0.0 );
+
+        /// Unit `w` vector
+        pub const W : Self = Self::new( 0.0, 0.0, 0.0, 1.0);
+
+        /// All elements are the minimum finite value
+        pub const MIN : Self = Self::splat( $E::MIN );
+
+        /// All elements are the maximum finite value
+        pub const MAX : Self = Self::splat( $E::MAX );
+
+        /// All elemets are `ZERO`
+        pub const ZERO : Self = Self::splat( 0.0 );
+
+        /// Creates a new vector
+        #[inline(always)]
+        pub const fn new( x : $E, y : $E, z : $E, w : $E ) -> Self
+        {
+            Vector::< $E, 4 >( [ x, y, z, w ] )
+        } 
+
+        /// Creates a vector from a single value : [ v ; N ]
+        #[inline(always)]
+        pub const fn splat( v : $E ) -> Self
+        {
+            Vector::< $E, 4 >( [ v; 4 ] )
+        }
+      }
+      )*
+    };
   }
+
+  constants!( F32x4, f32; F64x4, f64 );
   
 }
 
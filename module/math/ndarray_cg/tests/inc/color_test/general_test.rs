@@ -0,0 +1,124 @@
+use super::*;
+use the_module::
+{
+  color,
+  F32x3,
+  F64x3,
+  Vector
+};
+
+fn assert_close( got : F64x3, exp : F64x3, eps : f64 )
+{
+  assert!( got.distance( &exp ) < eps, "got {:?}, expected {:?}", got, exp );
+}
+
+#[ test ]
+fn test_srgb()
+{
+  assert_eq!( color::srgb::decode( 0.0f64 ), 0.0 );
+  assert_eq!( color::srgb::decode( 1.0f64 ), 1.0 );
+  assert!( ( color::srgb::decode( 0.5f64 ) - 0.214_041 ).abs() < 1e-5 );
+
+  for c in [ 0.0, 0.001, 0.04, 0.2, 0.5, 0.9, 1.0 ]
+  {
+    assert!( ( color::srgb::encode( color::srgb::decode( c ) ) - c ).abs() < 1e-9 );
+  }
+
+  let rgb = F64x3::from( [ 0.1, 0.5, 0.8 ] );
+  assert_close( color::srgb::from_linear( color::srgb::to_linear( rgb ) ), rgb, 1e-9 );
+
+  assert_eq!( color::srgb::from_hex::< f32 >( "#ff8000" ), Some( F32x3::from( [ 1.0, 128.0 / 255.0, 0.0 ] ) ) );
+  assert_eq!( color::srgb::from_hex::< f32 >( "0f0" ), Some( F32x3::from( [ 0.0, 1.0, 0.0 ] ) ) );
+  assert_eq!( color::srgb::from_hex::< f32 >( "#12345" ), None );
+  assert_eq!( color::srgb::from_hex::< f32 >( "#zz0000" ), None );
+}
+
+#[ test ]
+fn test_hsv_hsl()
+{
+  let cases =
+  [
+    // rgb, hsv, hsl
+    ( [ 1.0, 0.0, 0.0 ], [ 0.0, 1.0, 1.0 ], [ 0.0, 1.0, 0.5 ] ),
+    ( [ 0.0, 1.0, 0.0 ], [ 120.0, 1.0, 1.0 ], [ 120.0, 1.0, 0.5 ] ),
+    ( [ 0.0, 0.0, 0.5 ], [ 240.0, 1.0, 0.5 ], [ 240.0, 1.0, 0.25 ] ),
+    ( [ 1.0, 0.0, 1.0 ], [ 300.0, 1.0, 1.0 ], [ 300.0, 1.0, 0.5 ] ),
+    ( [ 0.5, 0.5, 0.5 ], [ 0.0, 0.0, 0.5 ], [ 0.0, 0.0, 0.5 ] ),
+    ( [ 0.75, 0.5, 0.25 ], [ 30.0, 2.0 / 3.0, 0.75 ], [ 30.0, 0.5, 0.5 ] ),
+  ];
+
+  for ( rgb, hsv, hsl ) in cases
+  {
+    let ( rgb, hsv, hsl ) = ( Vector( rgb ), Vector( hsv ), Vector( hsl ) );
+    assert_close( color::hsv::from_rgb( rgb ), hsv, 1e-9 );
+    assert_close( color::hsv::to_rgb( hsv ), rgb, 1e-9 );
+    assert_close( color::hsl::from_rgb( rgb ), hsl, 1e-9 );
+    assert_close( color::hsl::to_rgb( hsl ), rgb, 1e-9 );
+  }
+
+  assert_eq!( color::hsv::wrap_hue( -30.0f64 ), 330.0 );
+  assert_eq!( color::hsv::wrap_hue( 725.0f64 ), 5.0 );
+  assert_close( color::hsv::to_rgb( Vector( [ 360.0, 1.0, 1.0 ] ) ), Vector( [ 1.0, 0.0, 0.0 ] ), 1e-9 );
+}
+
+#[ test ]
+fn test_oklab()
+{
+  // Reference values from the OKLab definition
+  assert_close( color::oklab::from_linear_rgb( Vector( [ 1.0, 1.0, 1.0 ] ) ), Vector( [ 1.0, 0.0, 0.0 ] ), 1e-6 );
+  assert_close( color::oklab::from_linear_rgb( Vector( [ 1.0, 0.0, 0.0 ] ) ), Vector( [ 0.627_955, 0.224_863, 0.125_846 ] ), 1e-5 );
+
+  let rgb = F64x3::from( [ 0.2, 0.7, 0.4 ] );
+  let lab = color::oklab::from_linear_rgb( rgb );
+  assert_close( color::oklab::to_linear_rgb( lab ), rgb, 1e-9 );
+
+  let lch = color::oklab::to_oklch( lab );
+  assert!( lch.0[ 2 ] >= 0.0 && lch.0[ 2 ] < 360.0 );
+  assert_close( color::oklab::from_oklch( lch ), lab, 1e-9 );
+
+  // Mixing complementary colors in OKLab doesn't go through dark grey as in RGB
+  let mid = color::oklab::mix( Vector( [ 1.0, 0.0, 0.0 ] ), Vector( [ 0.0, 0.0, 1.0 ] ), 0.5 );
+  let l = color::oklab::from_linear_rgb( mid ).0[ 0 ];
+  let rgb_mid = color::oklab::from_linear_rgb( Vector( [ 0.5, 0.0, 0.5 ] ) ).0[ 0 ];
+  assert!( l > rgb_mid );
+  assert_close( color::oklab::mix( rgb, Vector( [ 0.0, 0.0, 0.0 ] ), 0.0 ), rgb, 1e-9 );
+}
+
+#[ test ]
+fn test_temperature()
+{
+  let warm = color::temperature::to_rgb( 2000.0f64 );
+  let white = color::temperature::to_rgb( 6600.0f64 );
+  let cold = color::temperature::to_rgb( 15000.0f64 );
+
+  assert_eq!( warm.0[ 0 ], 1.0 );
+  assert!( warm.0[ 2 ] < warm.0[ 1 ] );
+  assert!( white.0.iter().all( | c | *c > 0.95 ) );
+  assert_eq!( cold.0[ 2 ], 1.0 );
+  assert!( cold.0[ 0 ] < cold.0[ 2 ] );
+  assert_eq!( color::temperature::to_rgb( 100.0f64 ), color::temperature::to_rgb( 1000.0f64 ) );
+}
+
+#[ test ]
+fn test_gamut()
+{
+  assert!( color::gamut::in_gamut( F64x3::from( [ 0.0, 0.5, 1.0 ] ) ) );
+  assert!( !color::gamut::in_gamut( F64x3::from( [ -0.1, 0.5, 1.0 ] ) ) );
+  assert_eq!( color::gamut::clamp( F64x3::from( [ -0.1, 0.5, 1.2 ] ) ), Vector( [ 0.0, 0.5, 1.0 ] ) );
+
+  // Very saturated green is out of sRGB
+  let lch = F64x3::from( [ 0.8, 0.4, 140.0 ] );
+  let raw = color::oklab::to_linear_rgb( color::oklab::from_oklch( lch ) );
+  assert!( !color::gamut::in_gamut( raw ) );
+
+  let mapped = color::gamut::map_oklch( lch );
+  assert!( color::gamut::in_gamut( mapped ) );
+  let mapped_lch = color::oklab::to_oklch( color::oklab::from_linear_rgb( mapped ) );
+  assert!( ( mapped_lch.0[ 0 ] - 0.8 ).abs() < 1e-3 );
+  assert!( ( mapped_lch.0[ 2 ] - 140.0 ).abs() < 0.5 );
+  assert!( mapped_lch.0[ 1 ] < 0.4 );
+
+  assert!( color::gamut::in_gamut( color::gamut::map_rgb( raw ) ) );
+  let inside = F64x3::from( [ 0.3, 0.6, 0.9 ] );
+  assert_eq!( color::gamut::map_rgb( inside ), inside );
+}
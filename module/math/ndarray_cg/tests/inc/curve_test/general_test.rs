@@ -0,0 +1,159 @@
+use super::*;
+use the_module::
+{
+  Curve,
+  QuadraticBezier,
+  CubicBezier,
+  CatmullRom,
+  BSpline,
+  F32x2,
+  Vector
+};
+
+fn assert_close< const N : usize >( got : Vector< f32, N >, exp : Vector< f32, N >, eps : f32 )
+{
+  assert!( got.distance( &exp ) < eps, "got {:?}, expected {:?}", got, exp );
+}
+
+/// Compares the derivative with a central difference
+fn check_derivative< C : Curve< f32, 2 > >( curve : &C )
+{
+  let h = 1e-3;
+  for t in [ 0.1, 0.3, 0.5, 0.7, 0.9 ]
+  {
+    let numeric = ( curve.point( t + h ) - curve.point( t - h ) ) / ( 2.0 * h );
+    let got = curve.derivative( t );
+    assert!( got.distance( &numeric ) < 1e-2 * numeric.mag().max( 1.0 ), "t = {t}, got {:?}, expected {:?}", got, numeric );
+  }
+}
+
+fn cubic() -> CubicBezier< f32, 2 >
+{
+  CubicBezier::new
+  (
+    F32x2::from( [ 0.0, 0.0 ] ),
+    F32x2::from( [ 0.0, 1.0 ] ),
+    F32x2::from( [ 1.0, 1.0 ] ),
+    F32x2::from( [ 1.0, 0.0 ] )
+  )
+}
+
+#[ test ]
+fn test_bezier()
+{
+  let curve = cubic();
+  assert_close( curve.point( 0.0 ), curve.p0, 1e-6 );
+  assert_close( curve.point( 1.0 ), curve.p3, 1e-6 );
+  assert_close( curve.point( 0.5 ), F32x2::from( [ 0.5, 0.75 ] ), 1e-6 );
+  check_derivative( &curve );
+
+  let ( a, b ) = curve.split( 0.3 );
+  assert_close( a.point( 0.5 ), curve.point( 0.15 ), 1e-6 );
+  assert_close( b.point( 0.5 ), curve.point( 0.65 ), 1e-6 );
+
+  let quadratic = QuadraticBezier::new( F32x2::from( [ 0.0, 0.0 ] ), F32x2::from( [ 1.0, 2.0 ] ), F32x2::from( [ 2.0, 0.0 ] ) );
+  check_derivative( &quadratic );
+  let cubic = quadratic.to_cubic();
+  for t in [ 0.0, 0.25, 0.5, 0.75, 1.0 ]
+  {
+    assert_close( cubic.point( t ), quadratic.point( t ), 1e-6 );
+  }
+}
+
+#[ test ]
+fn test_catmull_rom()
+{
+  let points = vec!
+  [
+    F32x2::from( [ 0.0, 0.0 ] ),
+    F32x2::from( [ 1.0, 1.0 ] ),
+    F32x2::from( [ 3.0, 1.0 ] ),
+    F32x2::from( [ 4.0, 0.0 ] )
+  ];
+
+  for spline in [ CatmullRom::new( points.clone() ), CatmullRom::uniform( points.clone() ), CatmullRom::new( points.clone() ).alpha( 1.0 ) ]
+  {
+    // Passes through all the points
+    for ( i, p ) in points.iter().enumerate()
+    {
+      assert_close( spline.point( i as f32 / 3.0 ), *p, 1e-5 );
+    }
+    check_derivative( &spline );
+    assert_close( spline.point( f32::NAN ), points[ 0 ], 1e-6 );
+  }
+}
+
+#[ test ]
+fn test_bspline()
+{
+  let control = vec!
+  [
+    F32x2::from( [ 0.0, 0.0 ] ),
+    F32x2::from( [ 0.0, 1.0 ] ),
+    F32x2::from( [ 1.0, 1.0 ] ),
+    F32x2::from( [ 1.0, 0.0 ] )
+  ];
+
+  // Clamped cubic B-spline with 4 control points is the Bézier curve
+  let spline = BSpline::clamped( 3, control.clone() ).unwrap();
+  let bezier = cubic();
+  for t in [ 0.0, 0.2, 0.5, 0.8, 1.0 ]
+  {
+    assert_close( spline.point( t ), bezier.point( t ), 1e-5 );
+    assert_close( spline.derivative( t ), bezier.derivative( t ), 1e-4 );
+  }
+
+  assert_close( spline.point( f32::NAN ), control[ 0 ], 1e-6 );
+
+  let spline = BSpline::clamped( 2, control.clone() ).unwrap();
+  assert_close( spline.point( 0.0 ), control[ 0 ], 1e-6 );
+  assert_close( spline.point( 1.0 ), control[ 3 ], 1e-6 );
+  check_derivative( &spline );
+
+  assert!( BSpline::clamped( 4, control.clone() ).is_none() );
+  assert!( BSpline::new( 2, control, vec![ 0.0; 3 ] ).is_none() );
+}
+
+#[ test ]
+fn test_flatten()
+{
+  let line = QuadraticBezier::new( F32x2::from( [ 0.0, 0.0 ] ), F32x2::from( [ 1.0, 0.0 ] ), F32x2::from( [ 2.0, 0.0 ] ) );
+  let coarse = line.flatten( 0.01 );
+  assert_close( coarse[ 0 ], line.p0, 1e-6 );
+  assert_close( *coarse.last().unwrap(), line.p2, 1e-6 );
+
+  let curve = cubic();
+  let points = curve.flatten( 1e-3 );
+  assert!( points.len() > coarse.len() );
+  // Midpoints of the polyline segments stay close to the curve
+  for w in points.windows( 2 )
+  {
+    let mid = ( w[ 0 ] + w[ 1 ] ) * 0.5;
+    let closest = ( 0..=10000 ).map( | i | curve.point( i as f32 / 10000.0 ).distance( &mid ) ).fold( f32::MAX, f32::min );
+    assert!( closest < 3e-3 );
+  }
+}
+
+#[ test ]
+fn test_arc_length()
+{
+  let line = QuadraticBezier::new( F32x2::from( [ 0.0, 0.0 ] ), F32x2::from( [ 0.5, 0.0 ] ), F32x2::from( [ 4.0, 0.0 ] ) );
+  let table = line.arc_length_table( 256 );
+  assert!( ( table.length() - 4.0 ).abs() < 1e-4 );
+
+  // Constant speed traversal despite non-uniform parameterization
+  for u in [ 0.0, 0.25, 0.5, 0.75, 1.0 ]
+  {
+    let p = line.point( table.t_at_fraction( u ) );
+    assert!( ( p.0[ 0 ] - 4.0 * u ).abs() < 1e-2, "u = {u}, got {:?}", p );
+  }
+
+  let t = table.t_at_length( 1.0 );
+  assert!( ( table.length_at( t ) - 1.0 ).abs() < 1e-4 );
+
+  // NaN is treated as the start of the curve
+  assert_eq!( table.length_at( f32::NAN ), 0.0 );
+
+  // Longer than the polyline through its middle point
+  assert!( cubic().length( 512 ) > 1.8 );
+}
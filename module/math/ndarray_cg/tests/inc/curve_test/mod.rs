@@ -0,0 +1,3 @@
+use super::*;
+
+mod general_test;
@@ -0,0 +1,66 @@
+use super::*;
+use the_module::
+{
+  RawSlice,
+  F64x3,
+  F64x4,
+  F64x3x3,
+  F64x4x4,
+  QuatF64,
+  Aabb,
+  Sphere,
+  Intersects,
+  Vector
+};
+
+#[ test ]
+fn test_vector_constants()
+{
+  assert_eq!( F64x3::X.cross( F64x3::Y ), F64x3::Z );
+  assert_eq!( F64x3::splat( 2.0 ), F64x3::new( 2.0, 2.0, 2.0 ) );
+  assert_eq!( F64x4::W.0, [ 0.0, 0.0, 0.0, 1.0 ] );
+  assert_eq!( the_module::F64x2::MAX.0, [ f64::MAX; 2 ] );
+  assert_eq!( F64x3::ZERO, F64x3::default() );
+}
+
+#[ test ]
+fn test_large_coordinates()
+{
+  // At this distance from the origin f32 can't represent a millimeter step
+  let far = F64x3::new( 1.0e7, 0.0, 0.0 );
+  let near = far + F64x3::new( 0.001, 0.0, 0.0 );
+  assert!( ( far.distance( &near ) - 0.001 ).abs() < 1e-9 );
+
+  let translation = F64x4x4::from_trs( far, QuatF64::identity(), F64x3::splat( 1.0 ) );
+  let moved = translation * F64x4::new( 0.001, 0.0, 0.0, 1.0 );
+  assert!( ( moved.0[ 0 ] - 10_000_000.001 ).abs() < 1e-9 );
+
+  let inverse = translation.inverse().unwrap();
+  let back = inverse * moved;
+  assert!( ( back.0[ 0 ] - 0.001 ).abs() < 1e-9 );
+}
+
+#[ test ]
+fn test_rotations()
+{
+  let angle = core::f64::consts::FRAC_PI_3;
+  let mat = the_module::d2::mat3x3::from_axis_angle::< f64, _ >( F64x3::Z, angle );
+  let quat = QuatF64::from_axis_angle( F64x3::Z, angle );
+  let got : F64x3x3 = quat.to_mat3();
+  for ( g, e ) in got.raw_slice().iter().zip( mat.raw_slice() )
+  {
+    assert!( ( g - e ).abs() < 1e-12 );
+  }
+
+  let rotated = quat * F64x3::X;
+  assert!( rotated.distance( &F64x3::new( angle.cos(), angle.sin(), 0.0 ) ) < 1e-12 );
+}
+
+#[ test ]
+fn test_geometry()
+{
+  let a = Aabb::new( F64x3::splat( 1.0e8 ), F64x3::splat( 1.0e8 + 1.0 ) );
+  let b = Sphere::new( Vector( [ 1.0e8 + 1.5, 1.0e8 + 0.5, 1.0e8 + 0.5 ] ), 0.6 );
+  assert!( a.intersects( &b ) );
+  assert!( !a.intersects( &Sphere::new( b.center, 0.4 ) ) );
+}
@@ -0,0 +1,129 @@
+use super::*;
+use the_module::
+{
+  Intersects,
+  Contains,
+  PlaneSide,
+  Plane,
+  Aabb,
+  Sphere,
+  Obb,
+  QuatF32,
+  F32x3,
+  Vector
+};
+
+fn v( x : f32, y : f32, z : f32 ) -> F32x3
+{
+  Vector( [ x, y, z ] )
+}
+
+fn unit_box() -> Aabb< f32 >
+{
+  Aabb::new( v( -1.0, -1.0, -1.0 ), v( 1.0, 1.0, 1.0 ) )
+}
+
+#[ test ]
+fn test_plane()
+{
+  let plane = Plane::from_points( v( 0.0, 1.0, 0.0 ), v( 0.0, 1.0, 1.0 ), v( 1.0, 1.0, 0.0 ) ).unwrap();
+  assert_eq!( plane.normal, v( 0.0, 1.0, 0.0 ) );
+  assert_eq!( plane.signed_distance( v( 5.0, 3.0, 2.0 ) ), 2.0 );
+  assert_eq!( plane.project( v( 5.0, 3.0, 2.0 ) ), v( 5.0, 1.0, 2.0 ) );
+  assert_eq!( plane.side( v( 0.0, 0.0, 0.0 ), 0.5 ), PlaneSide::Back );
+  assert_eq!( plane.flip().side( v( 0.0, 0.0, 0.0 ), 0.5 ), PlaneSide::Front );
+  assert_eq!( plane.ray_intersection( v( 0.0, 5.0, 0.0 ), v( 0.0, -2.0, 0.0 ) ), Some( 2.0 ) );
+  assert!( Plane::from_points( v( 0.0, 0.0, 0.0 ), v( 1.0, 0.0, 0.0 ), v( 2.0, 0.0, 0.0 ) ).is_none() );
+
+  let other = Plane::from_normal_point( v( 0.0, 2.0, 0.0 ), v( 0.0, 3.0, 0.0 ) );
+  assert!( !plane.intersects( &other ) );
+  assert!( plane.intersects( &Plane::from_normal_point( v( 1.0, 1.0, 0.0 ), v( 0.0, 0.0, 0.0 ) ) ) );
+
+  assert!( plane.intersects( &unit_box() ) );
+  assert!( !other.intersects( &unit_box() ) );
+  assert!( unit_box().intersects( &plane ) );
+  assert!( plane.intersects( &Sphere::new( v( 0.0, 1.5, 0.0 ), 0.5 ) ) );
+  assert!( !plane.intersects( &Sphere::new( v( 0.0, 1.6, 0.0 ), 0.5 ) ) );
+
+  // Rotated by 45 degrees the box reaches sqrt( 2 ) along y
+  let obb = Obb::from_aabb( &unit_box(), QuatF32::from_axis_angle( v( 0.0, 0.0, 1.0 ), core::f32::consts::FRAC_PI_4 ) );
+  let high = Plane::from_normal_point( v( 0.0, 1.0, 0.0 ), v( 0.0, 1.3, 0.0 ) );
+  assert!( high.intersects( &obb ) );
+  assert!( !high.intersects( &unit_box() ) );
+}
+
+#[ test ]
+fn test_aabb()
+{
+  let aabb = Aabb::from_points( [ v( 1.0, 0.0, 2.0 ), v( -1.0, 3.0, 0.0 ), v( 0.0, 1.0, 1.0 ) ] ).unwrap();
+  assert_eq!( aabb, Aabb::new( v( -1.0, 0.0, 0.0 ), v( 1.0, 3.0, 2.0 ) ) );
+  assert_eq!( aabb.center(), v( 0.0, 1.5, 1.0 ) );
+  assert_eq!( aabb.half_extents(), v( 1.0, 1.5, 1.0 ) );
+  assert_eq!( aabb.closest_point( v( 5.0, 1.0, -3.0 ) ), v( 1.0, 1.0, 0.0 ) );
+  assert!( Aabb::< f32 >::from_points( [] ).is_none() );
+
+  let a = unit_box();
+  let b = Aabb::new( v( 0.5, 0.5, 0.5 ), v( 2.0, 2.0, 2.0 ) );
+  let c = Aabb::new( v( 1.5, 0.0, 0.0 ), v( 2.0, 1.0, 1.0 ) );
+  assert!( a.intersects( &b ) );
+  assert!( !a.intersects( &c ) );
+  assert!( a.merge( b ).contains( &b ) );
+  assert!( !a.contains( &b ) );
+  assert!( a.contains( &v( 1.0, 0.0, -1.0 ) ) );
+
+  assert!( a.intersects( &Sphere::new( v( 2.0, 0.0, 0.0 ), 1.0 ) ) );
+  assert!( !a.intersects( &Sphere::new( v( 2.0, 2.0, 0.0 ), 1.0 ) ) );
+  assert!( a.contains( &Sphere::new( v( 0.0, 0.0, 0.0 ), 1.0 ) ) );
+  assert!( !a.contains( &Sphere::new( v( 0.1, 0.0, 0.0 ), 1.0 ) ) );
+}
+
+#[ test ]
+fn test_sphere()
+{
+  let a = Sphere::new( v( 0.0, 0.0, 0.0 ), 1.0 );
+  let b = Sphere::new( v( 1.5, 0.0, 0.0 ), 1.0 );
+  let c = Sphere::new( v( 4.0, 0.0, 0.0 ), 1.0 );
+  assert!( a.intersects( &b ) );
+  assert!( !a.intersects( &c ) );
+  assert!( a.contains( &Sphere::new( v( 0.5, 0.0, 0.0 ), 0.5 ) ) );
+
+  let merged = a.merge( c );
+  assert!( merged.contains( &a ) );
+  assert!( merged.contains( &c ) );
+  assert_eq!( merged, Sphere::new( v( 2.0, 0.0, 0.0 ), 3.0 ) );
+
+  let bounding = Sphere::from_points( unit_box().corners() ).unwrap();
+  assert_eq!( bounding.center, v( 0.0, 0.0, 0.0 ) );
+  assert!( ( bounding.radius - 3.0f32.sqrt() ).abs() < 1e-6 );
+  assert!( !a.contains( &unit_box() ) );
+}
+
+#[ test ]
+fn test_obb()
+{
+  let rotation = QuatF32::from_axis_angle( v( 0.0, 0.0, 1.0 ), core::f32::consts::FRAC_PI_4 );
+  let obb = Obb::from_aabb( &unit_box(), rotation );
+  let reach = 2.0f32.sqrt();
+
+  assert!( obb.contains( &v( 0.0, reach - 0.01, 0.0 ) ) );
+  assert!( !obb.contains( &v( 0.9, 0.9, 0.0 ) ) );
+  let aabb = obb.to_aabb();
+  assert!( ( aabb.max.0[ 0 ] - reach ).abs() < 1e-5 );
+  assert!( aabb.contains( &obb ) );
+
+  // Corner of the axis-aligned box is cut off by the rotated one
+  let corner = Aabb::new( v( 1.1, 1.1, -1.0 ), v( 2.0, 2.0, 1.0 ) );
+  assert!( !obb.intersects( &corner ) );
+  assert!( unit_box().intersects( &obb ) );
+
+  let tilted = Obb::new( v( 0.0, 0.0, 0.0 ), QuatF32::from_euler_xyz( v( 0.3, 0.4, 0.5 ) ), v( 1.0, 1.0, 1.0 ) );
+  let far = Obb::new( v( 4.0, 0.0, 0.0 ), QuatF32::identity(), v( 1.0, 1.0, 1.0 ) );
+  assert!( !tilted.intersects( &far ) );
+  assert!( tilted.intersects( &Obb::new( v( 2.0, 0.0, 0.0 ), QuatF32::identity(), v( 1.0, 1.0, 1.0 ) ) ) );
+
+  assert!( obb.intersects( &Sphere::new( v( 0.0, 2.0, 0.0 ), 0.6 ) ) );
+  assert!( !obb.intersects( &Sphere::new( v( 0.0, 2.0, 0.0 ), 0.5 ) ) );
+  assert!( obb.contains( &Sphere::new( v( 0.0, 0.0, 0.0 ), 1.0 ) ) );
+  assert!( obb.contains( &Aabb::new( v( -0.5, -0.5, -0.5 ), v( 0.5, 0.5, 0.5 ) ) ) );
+  assert!( !obb.contains( &unit_box() ) );
+}
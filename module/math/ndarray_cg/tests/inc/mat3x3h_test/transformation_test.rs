@@ -0,0 +1,134 @@
+use super::*;
+use the_module::
+{
+  mat3x3h,
+  geometry::Plane,
+  F32x3,
+  F32x4,
+  F32x4x4,
+  Vector
+};
+
+/// Projects a view space point and returns normalized device coordinates
+fn ndc( projection : &F32x4x4, p : [ f32; 3 ] ) -> [ f32; 3 ]
+{
+  let clip = *projection * F32x4::from( [ p[ 0 ], p[ 1 ], p[ 2 ], 1.0 ] );
+  [ clip.0[ 0 ] / clip.0[ 3 ], clip.0[ 1 ] / clip.0[ 3 ], clip.0[ 2 ] / clip.0[ 3 ] ]
+}
+
+fn assert_close( got : [ f32; 3 ], exp : [ f32; 3 ] )
+{
+  for ( g, e ) in got.iter().zip( exp )
+  {
+    assert!( ( g - e ).abs() < 1e-4, "got {:?}, expected {:?}", got, exp );
+  }
+}
+
+#[ test ]
+fn test_perspective()
+{
+  let fovy = std::f32::consts::FRAC_PI_2;
+  let ( near, far ) = ( 0.5, 100.0 );
+
+  let gl = mat3x3h::perspective_rh_gl( fovy, 2.0, near, far );
+  assert_close( ndc( &gl, [ 1.0, 0.5, -near ] ), [ 1.0, 1.0, -1.0 ] );
+  assert_close( ndc( &gl, [ 0.0, 0.0, -far ] ), [ 0.0, 0.0, 1.0 ] );
+
+  let zo = mat3x3h::perspective_rh( fovy, 2.0, near, far );
+  assert_close( ndc( &zo, [ 0.0, 0.0, -near ] ), [ 0.0, 0.0, 0.0 ] );
+  assert_close( ndc( &zo, [ 0.0, 0.0, -far ] ), [ 0.0, 0.0, 1.0 ] );
+
+  let reversed = mat3x3h::perspective_rh_reversed( fovy, 2.0, near, far );
+  assert_close( ndc( &reversed, [ 0.0, 0.0, -near ] ), [ 0.0, 0.0, 1.0 ] );
+  assert_close( ndc( &reversed, [ 0.0, 0.0, -far ] ), [ 0.0, 0.0, 0.0 ] );
+}
+
+#[ test ]
+fn test_perspective_infinite()
+{
+  let fovy = std::f32::consts::FRAC_PI_2;
+  let near = 0.5;
+  let distant = [ 0.0, 0.0, -1.0e6 ];
+
+  let gl = mat3x3h::perspective_rh_gl_infinite( fovy, 1.0, near );
+  assert_close( ndc( &gl, [ 0.0, 0.0, -near ] ), [ 0.0, 0.0, -1.0 ] );
+  assert_close( ndc( &gl, distant ), [ 0.0, 0.0, 1.0 ] );
+
+  let zo = mat3x3h::perspective_rh_infinite( fovy, 1.0, near );
+  assert_close( ndc( &zo, [ 0.0, 0.0, -near ] ), [ 0.0, 0.0, 0.0 ] );
+  assert_close( ndc( &zo, distant ), [ 0.0, 0.0, 1.0 ] );
+
+  let reversed = mat3x3h::perspective_rh_infinite_reversed( fovy, 1.0, near );
+  assert_close( ndc( &reversed, [ 0.0, 0.0, -near ] ), [ 0.0, 0.0, 1.0 ] );
+  assert!( ndc( &reversed, distant )[ 2 ] > 0.0 );
+  assert_close( ndc( &reversed, distant ), [ 0.0, 0.0, 0.0 ] );
+}
+
+#[ test ]
+fn test_frustum()
+{
+  let ( near, far ) = ( 1.0, 10.0 );
+
+  // Symmetric frustum is the same as the perspective
+  let symmetric = mat3x3h::frustum_rh_gl( -1.0, 1.0, -1.0, 1.0, near, far );
+  let perspective = mat3x3h::perspective_rh_gl( std::f32::consts::FRAC_PI_2, 1.0, near, far );
+  assert_close( ndc( &symmetric, [ 0.3, 0.2, -4.0 ] ), ndc( &perspective, [ 0.3, 0.2, -4.0 ] ) );
+
+  let gl = mat3x3h::frustum_rh_gl( 0.0, 2.0, -1.0, 3.0, near, far );
+  assert_close( ndc( &gl, [ 0.0, -1.0, -near ] ), [ -1.0, -1.0, -1.0 ] );
+  assert_close( ndc( &gl, [ 20.0, 30.0, -far ] ), [ 1.0, 1.0, 1.0 ] );
+
+  let zo = mat3x3h::frustum_rh( 0.0, 2.0, -1.0, 3.0, near, far );
+  assert_close( ndc( &zo, [ 0.0, -1.0, -near ] ), [ -1.0, -1.0, 0.0 ] );
+  assert_close( ndc( &zo, [ 20.0, 30.0, -far ] ), [ 1.0, 1.0, 1.0 ] );
+}
+
+#[ test ]
+fn test_orthographic()
+{
+  let gl = mat3x3h::orthographic_rh_gl( -2.0, 4.0, 0.0, 3.0, 1.0, 11.0 );
+  assert_close( ndc( &gl, [ -2.0, 0.0, -1.0 ] ), [ -1.0, -1.0, -1.0 ] );
+  assert_close( ndc( &gl, [ 4.0, 3.0, -11.0 ] ), [ 1.0, 1.0, 1.0 ] );
+  assert_close( ndc( &gl, [ 1.0, 1.5, -6.0 ] ), [ 0.0, 0.0, 0.0 ] );
+
+  let zo = mat3x3h::orthographic_rh( -2.0, 4.0, 0.0, 3.0, 1.0, 11.0 );
+  assert_close( ndc( &zo, [ -2.0, 0.0, -1.0 ] ), [ -1.0, -1.0, 0.0 ] );
+  assert_close( ndc( &zo, [ 4.0, 3.0, -11.0 ] ), [ 1.0, 1.0, 1.0 ] );
+}
+
+#[ test ]
+fn test_oblique_clip()
+{
+  let ( near, far ) = ( 0.5, 100.0 );
+  let fovy = std::f32::consts::FRAC_PI_2;
+
+  // Clipping by the near plane itself doesn't change the projection
+  let near_plane = Plane::from_coefficients( 0.0, 0.0, -1.0, -near );
+  let gl = mat3x3h::perspective_rh_gl( fovy, 1.0, near, far );
+  let same = mat3x3h::oblique_clip_gl( &gl, &near_plane ).unwrap();
+  assert_close( ndc( &same, [ 0.2, 0.1, -3.0 ] ), ndc( &gl, [ 0.2, 0.1, -3.0 ] ) );
+  let zo = mat3x3h::perspective_rh( fovy, 1.0, near, far );
+  let same = mat3x3h::oblique_clip( &zo, &near_plane ).unwrap();
+  assert_close( ndc( &same, [ 0.2, 0.1, -3.0 ] ), ndc( &zo, [ 0.2, 0.1, -3.0 ] ) );
+
+  // A tilted mirror plane in front of the camera
+  let point = F32x3::from( [ 0.0, -1.0, -5.0 ] );
+  let plane = Plane::from_normal_point( Vector( [ 0.0, 1.0, -1.0 ] ), point );
+  let on_plane = [ 0.5, -3.0, -7.0 ];
+  assert!( plane.signed_distance( Vector( on_plane ) ).abs() < 1e-5 );
+
+  let oblique = mat3x3h::oblique_clip_gl( &gl, &plane ).unwrap();
+  assert!( ( ndc( &oblique, on_plane )[ 2 ] + 1.0 ).abs() < 1e-4 );
+  // Points on the positive side are inside, on the negative side - clipped
+  assert!( ndc( &oblique, [ 0.0, 0.0, -7.0 ] )[ 2 ] > -1.0 );
+  assert!( ndc( &oblique, [ 0.0, -5.0, -7.0 ] )[ 2 ] < -1.0 );
+
+  let oblique = mat3x3h::oblique_clip( &zo, &plane ).unwrap();
+  assert!( ndc( &oblique, on_plane )[ 2 ].abs() < 1e-4 );
+  assert!( ndc( &oblique, [ 0.0, 0.0, -7.0 ] )[ 2 ] > 0.0 );
+  assert!( ndc( &oblique, [ 0.0, -5.0, -7.0 ] )[ 2 ] < 0.0 );
+
+  // X and y are not affected
+  let p = [ 0.3, 3.0, -9.0 ];
+  assert_close( [ ndc( &oblique, p )[ 0 ], ndc( &oblique, p )[ 1 ], 0.0 ], [ ndc( &zo, p )[ 0 ], ndc( &zo, p )[ 1 ], 0.0 ] );
+}
@@ -9,6 +9,7 @@ use the_module::
   IndexingMut,
   Mat3,
   Mat4,
+  Mat3x4,
   mat
 };
 
@@ -125,4 +126,78 @@ fn test_inverse_row_major()
 fn test_inverse_column_major()
 {
   test_inverse_generic::< mat::DescriptorOrderColumnMajor >();
-}
\ No newline at end of file
+}
+
+fn test_normal_matrix_generic< Descriptor : mat::Descriptor >()
+where 
+  Mat4< f32, Descriptor > : 
+      RawSliceMut< Scalar = f32 > +
+      ScalarMut< Scalar = f32, Index = Ix2 > + 
+      ConstLayout< Index = Ix2 > + 
+      IndexingMut< Scalar = f32, Index = Ix2 >,
+  Mat3< f32, Descriptor > : 
+      RawSliceMut< Scalar = f32 > +
+      ScalarMut< Scalar = f32, Index = Ix2 > + 
+      ConstLayout< Index = Ix2 > + 
+      IndexingMut< Scalar = f32, Index = Ix2 > +
+      PartialEq
+{
+  let mat = Mat4::< f32, Descriptor >::from_row_major
+  ([ 
+    2.0, 0.0, 0.0, 5.0,
+    0.0, 4.0, 0.0, 6.0, 
+    0.0, 0.0, 8.0, 7.0,
+    0.0, 0.0, 0.0, 1.0
+  ]);
+
+  let exp = Mat3::< f32, Descriptor >::from_row_major( [ 2.0, 0.0, 0.0, 0.0, 4.0, 0.0, 0.0, 0.0, 8.0 ] );
+  let got = mat.truncate();
+  assert_eq!( got, exp );
+
+  let exp = Mat3::< f32, Descriptor >::from_row_major( [ 0.5, 0.0, 0.0, 0.0, 0.25, 0.0, 0.0, 0.0, 0.125 ] );
+  let got = mat.normal_matrix().unwrap();
+  assert_eq!( got, exp );
+
+  let mat = Mat4::< f32, Descriptor >::default();
+  assert!( mat.normal_matrix().is_none() );
+}
+
+#[ test ]
+fn test_normal_matrix_row_major()
+{
+  test_normal_matrix_generic::< mat::DescriptorOrderRowMajor >();
+}
+
+#[ test ]
+fn test_normal_matrix_column_major()
+{
+  test_normal_matrix_generic::< mat::DescriptorOrderColumnMajor >();
+}
+
+#[ test ]
+fn test_mat3x4()
+{
+  let mat = the_module::F32x4x4::from_row_major
+  ([ 
+    1.0, 2.0, 3.0, 4.0, 
+    5.0, 6.0, 7.0, 8.0, 
+    9.0, 10.0, 11.0, 12.0,
+    0.0, 0.0, 0.0, 1.0 
+  ]);
+
+  let got = mat.to_mat3x4();
+  let exp = Mat3x4::< f32, mat::DescriptorOrderColumnMajor >::from_row_major
+  ([ 
+    1.0, 2.0, 3.0, 4.0, 
+    5.0, 6.0, 7.0, 8.0, 
+    9.0, 10.0, 11.0, 12.0,
+  ]);
+  assert_eq!( got, exp );
+  assert_eq!( got.to_array(), [ 1.0, 5.0, 9.0, 2.0, 6.0, 10.0, 3.0, 7.0, 11.0, 4.0, 8.0, 12.0 ] );
+  assert_eq!( got.to_mat4(), mat );
+
+  let got = got.transpose();
+  assert_eq!( got.to_array(), [ 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0, 8.0, 9.0, 10.0, 11.0, 12.0 ] );
+
+  assert_eq!( the_module::F32x4x4::identity().to_mat3x4().to_mat4(), the_module::F32x4x4::identity() );
+}
@@ -1,7 +1,18 @@
 use super::*;
 
+mod color_test;
+mod curve_test;
 mod d2_test;
+mod f64_test;
+mod geometry_test;
 mod mat2x2_test;
 mod mat2x2h_test;
 mod mat3x3_test;
+mod mat3x3h_test;
 mod mat4x4_test;
+mod noise_test;
+mod quaternion_test;
+mod random_test;
+#[ cfg( feature = "simd" ) ]
+mod simd_test;
+mod transform_test;
@@ -0,0 +1,116 @@
+use super::*;
+use the_module::
+{
+  Noise,
+  Perlin,
+  Simplex,
+  OpenSimplex2,
+  Fbm,
+  Ridged,
+  DomainWarp,
+  F32x2,
+  F32x3,
+  F64x3,
+  Vector
+};
+
+fn grid2() -> impl Iterator< Item = F32x2 >
+{
+  ( 0..40 ).flat_map( | i | ( 0..40 ).map( move | j | Vector( [ i as f32 * 0.173 - 3.0, j as f32 * 0.219 - 4.0 ] ) ) )
+}
+
+fn grid3() -> impl Iterator< Item = F32x3 >
+{
+  grid2().map( | p | Vector( [ p.0[ 0 ], p.0[ 1 ], p.0[ 0 ] * 0.7 - p.0[ 1 ] ] ) )
+}
+
+/// Checks determinism, range, variation and continuity
+fn check< Nz, const N : usize >( noise : &Nz, points : impl Iterator< Item = Vector< f32, N > >, min : f32, max : f32 )
+where
+  Nz : Noise< f32, N >
+{
+  let mut values = Vec::new();
+  for p in points
+  {
+    let v = noise.sample( p );
+    assert_eq!( v, noise.sample( p ) );
+    assert!( v >= min && v <= max, "{v} at {:?}", p );
+    let near = noise.sample( p + Vector( [ 1e-3; N ] ) );
+    assert!( ( near - v ).abs() < 0.05, "discontinuity at {:?}", p );
+    values.push( v );
+  }
+  let spread = values.iter().cloned().fold( f32::MIN, f32::max ) - values.iter().cloned().fold( f32::MAX, f32::min );
+  assert!( spread > 0.3 * ( max - min ), "noise is too flat: {spread}" );
+}
+
+#[ test ]
+fn test_perlin()
+{
+  let noise = Perlin::new( 42 );
+  check::< _, 2 >( &noise, grid2(), -1.0, 1.0 );
+  check::< _, 3 >( &noise, grid3(), -1.0, 1.0 );
+
+  // Gradient noise is zero at lattice points
+  assert_eq!( Noise::< f32, 3 >::sample( &noise, Vector( [ 3.0, -2.0, 7.0 ] ) ), 0.0 );
+
+  // Different seeds give different noise
+  let other = Perlin::new( 43 );
+  let p = F32x3::from( [ 0.3, 0.6, 0.2 ] );
+  assert_ne!( noise.sample( p ), other.sample( p ) );
+  assert_eq!( noise, Perlin::new( 42 ) );
+}
+
+#[ test ]
+fn test_simplex()
+{
+  let noise = Simplex::new( 7 );
+  check::< _, 2 >( &noise, grid2(), -1.0, 1.0 );
+  check::< _, 3 >( &noise, grid3(), -1.0, 1.0 );
+
+  let p = F32x2::from( [ 0.3, 0.6 ] );
+  assert_ne!( noise.sample( p ), Simplex::new( 8 ).sample( p ) );
+
+  // Same noise in double precision
+  let p = F64x3::from( [ 0.3, 0.6, 0.2 ] );
+  let single : f32 = noise.sample( F32x3::from( [ 0.3, 0.6, 0.2 ] ) );
+  assert!( ( noise.sample( p ) - f64::from( single ) ).abs() < 1e-5 );
+}
+
+#[ test ]
+fn test_open_simplex()
+{
+  let noise = OpenSimplex2::new( 7 );
+  check::< _, 2 >( &noise, grid2(), -1.0, 1.0 );
+  check::< _, 3 >( &noise, grid3(), -1.0, 1.0 );
+
+  let p = F32x3::from( [ 0.3, 0.6, 0.2 ] );
+  assert_ne!( noise.sample( p ), OpenSimplex2::new( 8 ).sample( p ) );
+
+  // Differs from classic Simplex with the same seed
+  let p = F32x2::from( [ 0.3, 0.6 ] );
+  assert_ne!( noise.sample( p ), Simplex::new( 7 ).sample( p ) );
+
+  // Same noise in double precision
+  let p = F64x3::from( [ 0.3, 0.6, 0.2 ] );
+  let single : f32 = noise.sample( F32x3::from( [ 0.3, 0.6, 0.2 ] ) );
+  assert!( ( noise.sample( p ) - f64::from( single ) ).abs() < 1e-5 );
+}
+
+#[ test ]
+fn test_fractal()
+{
+  let fbm = Fbm::new( Perlin::new( 1 ) ).octaves( 5 );
+  check::< _, 2 >( &fbm, grid2(), -1.0, 1.0 );
+
+  // Single octave is the base noise
+  let p = F32x2::from( [ 0.37, 1.21 ] );
+  assert_eq!( Fbm::new( Perlin::new( 1 ) ).octaves( 1 ).sample( p ), Perlin::new( 1 ).sample( p ) );
+
+  let ridged = Ridged::new( Simplex::new( 2 ) );
+  check::< _, 3 >( &ridged, grid3(), 0.0, 1.0 );
+
+  let warp = DomainWarp::new( Simplex::new( 3 ), Fbm::new( Simplex::new( 4 ) ).octaves( 3 ), 0.5 );
+  check::< _, 2 >( &warp, grid2(), -1.0, 1.0 );
+  assert_ne!( warp.sample( p ), Simplex::new( 3 ).sample( p ) );
+  assert_eq!( DomainWarp::new( Simplex::new( 3 ), Simplex::new( 4 ), 0.0 ).sample( p ), Simplex::new( 3 ).sample( p ) );
+}
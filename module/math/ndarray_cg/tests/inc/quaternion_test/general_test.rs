@@ -0,0 +1,185 @@
+use super::*;
+use the_module::
+{
+  Ix2,
+  RawSlice,
+  ScalarRef,
+  Mat3,
+  mat,
+  EulerOrder,
+  QuatF32,
+  DualQuatF32,
+  F32x3,
+  Vector
+};
+
+fn assert_close( got : &[ f32 ], exp : &[ f32 ] )
+{
+  assert_eq!( got.len(), exp.len() );
+  for ( g, e ) in got.iter().zip( exp )
+  {
+    assert!( ( g - e ).abs() < 1e-5, "got {:?}, expected {:?}", got, exp );
+  }
+}
+
+fn assert_same_rotation( got : QuatF32, exp : QuatF32 )
+{
+  assert!( got.dot( &exp ).abs() > 1.0 - 1e-5, "got {:?}, expected {:?}", got, exp );
+}
+
+#[ test ]
+fn test_axis_angle()
+{
+  let q = QuatF32::from_axis_angle( Vector( [ 0.0, 0.0, 2.0 ] ), std::f32::consts::FRAC_PI_2 );
+  let got = q * F32x3::from( [ 1.0, 0.0, 0.0 ] );
+  assert_close( &got.0, &[ 0.0, 1.0, 0.0 ] );
+
+  let ( axis, angle ) = q.to_axis_angle();
+  assert_close( &axis.0, &[ 0.0, 0.0, 1.0 ] );
+  assert_close( &[ angle ], &[ std::f32::consts::FRAC_PI_2 ] );
+}
+
+#[ test ]
+fn test_matrix()
+{
+  let q = QuatF32::from_axis_angle( Vector( [ 1.0, 2.0, 3.0 ] ), 0.7 );
+  let exp = the_module::d2::mat3x3::from_axis_angle::< f32, _ >( F32x3::from( [ 1.0, 2.0, 3.0 ] ).normalize(), 0.7 );
+
+  let got = q.to_mat3::< mat::DescriptorOrderColumnMajor >();
+  assert_close( got.raw_slice(), exp.raw_slice() );
+
+  assert_same_rotation( QuatF32::from_rotation_matrix( &exp ), q );
+
+  let row_major = q.to_mat3::< mat::DescriptorOrderRowMajor >();
+  assert_eq!( *row_major.scalar_ref( Ix2( 0, 1 ) ), *got.scalar_ref( Ix2( 0, 1 ) ) );
+  assert_same_rotation( QuatF32::from_rotation_matrix( &row_major ), q );
+
+  // Rotations by ~180 degrees take the other branches
+  for axis in [ [ 1.0, 0.0, 0.0 ], [ 0.0, 1.0, 0.0 ], [ 0.0, 0.0, 1.0 ] ]
+  {
+    let q = QuatF32::from_axis_angle( Vector( axis ), 3.0 );
+    let m : Mat3< f32, mat::DescriptorOrderColumnMajor > = q.to_mat3();
+    assert_same_rotation( QuatF32::from_rotation_matrix( &m ), q );
+  }
+}
+
+#[ test ]
+fn test_euler()
+{
+  let orders =
+  [
+    EulerOrder::Xyz,
+    EulerOrder::Xzy,
+    EulerOrder::Yxz,
+    EulerOrder::Yzx,
+    EulerOrder::Zxy,
+    EulerOrder::Zyx
+  ];
+  let angles = F32x3::from( [ 0.3, -0.5, 1.1 ] );
+
+  for order in orders
+  {
+    let q = QuatF32::from_euler( angles, order );
+    let got = q.to_euler( order );
+    assert_close( &got.0, &angles.0 );
+    assert_same_rotation( QuatF32::from_euler( got, order ), q );
+  }
+
+  let x = QuatF32::from_axis_angle( Vector( [ 1.0, 0.0, 0.0 ] ), 0.3 );
+  let y = QuatF32::from_axis_angle( Vector( [ 0.0, 1.0, 0.0 ] ), -0.5 );
+  let z = QuatF32::from_axis_angle( Vector( [ 0.0, 0.0, 1.0 ] ), 1.1 );
+  assert_same_rotation( QuatF32::from_euler_xyz( angles ), x * y * z );
+  assert_same_rotation( QuatF32::from_euler( angles, EulerOrder::Zyx ), z * y * x );
+
+  // Gimbal lock still gives the same rotation
+  for order in orders
+  {
+    let q = match order
+    {
+      EulerOrder::Xyz | EulerOrder::Zyx => QuatF32::from_euler( Vector( [ 0.4, std::f32::consts::FRAC_PI_2, 0.4 ] ), order ),
+      EulerOrder::Yxz | EulerOrder::Zxy => QuatF32::from_euler( Vector( [ std::f32::consts::FRAC_PI_2, 0.4, 0.4 ] ), order ),
+      _ => QuatF32::from_euler( Vector( [ 0.4, 0.4, std::f32::consts::FRAC_PI_2 ] ), order ),
+    };
+    assert_same_rotation( QuatF32::from_euler( q.to_euler( order ), order ), q );
+  }
+}
+
+#[ test ]
+fn test_look_rotation()
+{
+  let forward = F32x3::from( [ 1.0, 0.0, 1.0 ] );
+  let q = QuatF32::look_rotation( forward, Vector( [ 0.0, 1.0, 0.0 ] ) );
+  assert_close( &( q * F32x3::from( [ 0.0, 0.0, 1.0 ] ) ).0, &forward.normalize().0 );
+  assert_close( &( q * F32x3::from( [ 0.0, 1.0, 0.0 ] ) ).0, &[ 0.0, 1.0, 0.0 ] );
+
+  let q = QuatF32::look_rotation( Vector( [ 0.0, 3.0, 0.0 ] ), Vector( [ 0.0, 1.0, 0.0 ] ) );
+  assert_close( &( q * F32x3::from( [ 0.0, 0.0, 1.0 ] ) ).0, &[ 0.0, 1.0, 0.0 ] );
+}
+
+#[ test ]
+fn test_rotate_towards()
+{
+  let from = QuatF32::identity();
+  let to = QuatF32::from_axis_angle( Vector( [ 0.0, 1.0, 0.0 ] ), 1.0 );
+
+  let got = from.rotate_towards( to, 0.25 );
+  assert_same_rotation( got, QuatF32::from_axis_angle( Vector( [ 0.0, 1.0, 0.0 ] ), 0.25 ) );
+  assert!( ( got.angle_to( &from ) - 0.25 ).abs() < 1e-5 );
+
+  assert_eq!( from.rotate_towards( to, 2.0 ), to );
+}
+
+#[ test ]
+fn test_trs()
+{
+  let translation = F32x3::from( [ 1.0, -2.0, 3.0 ] );
+  let rotation = QuatF32::from_axis_angle( Vector( [ 1.0, 1.0, 0.0 ] ), 0.8 );
+  let scale = F32x3::from( [ 2.0, 0.5, 3.0 ] );
+
+  let mat = the_module::F32x4x4::from_trs( translation, rotation, scale );
+  let ( t, r, s ) = mat.decompose().unwrap();
+  assert_close( &t.0, &translation.0 );
+  assert_same_rotation( r, rotation );
+  assert_close( &s.0, &scale.0 );
+
+  let mirrored = the_module::F32x4x4::from_trs( translation, rotation, Vector( [ -2.0, 0.5, 3.0 ] ) );
+  let ( _, r, s ) = mirrored.decompose().unwrap();
+  assert_same_rotation( r, rotation );
+  assert_close( &s.0, &[ -2.0, 0.5, 3.0 ] );
+
+  assert!( the_module::F32x4x4::default().decompose().is_none() );
+}
+
+#[ test ]
+fn test_dual_quat()
+{
+  let rotation = QuatF32::from_axis_angle( Vector( [ 0.0, 0.0, 1.0 ] ), std::f32::consts::FRAC_PI_2 );
+  let translation = F32x3::from( [ 1.0, 2.0, 3.0 ] );
+  let dq = DualQuatF32::from_rotation_translation( rotation, translation );
+
+  assert_close( &dq.translation().0, &translation.0 );
+  assert_close( &dq.transform_point( Vector( [ 1.0, 0.0, 0.0 ] ) ).0, &[ 1.0, 3.0, 3.0 ] );
+
+  let mat = dq.to_mat4::< mat::DescriptorOrderColumnMajor >();
+  let back = DualQuatF32::from_mat4( &mat ).unwrap();
+  assert_same_rotation( back.real, dq.real );
+  assert_close( &back.translation().0, &translation.0 );
+
+  // Composition applies the right-hand side first
+  let shift = DualQuatF32::from_rotation_translation( QuatF32::identity(), Vector( [ 1.0, 0.0, 0.0 ] ) );
+  let got = ( dq * shift ).transform_point( Vector( [ 0.0, 0.0, 0.0 ] ) );
+  assert_close( &got.0, &[ 1.0, 3.0, 3.0 ] );
+
+  // Blending rotations around the same pivot keeps the distance to it
+  let a = DualQuatF32::from_rotation_translation( QuatF32::identity(), Vector( [ 0.0, 0.0, 0.0 ] ) );
+  let b = DualQuatF32::from_rotation_translation( rotation, Vector( [ 0.0, 0.0, 0.0 ] ) );
+  let got = a.lerp( b, 0.5 ).transform_point( Vector( [ 2.0, 0.0, 0.0 ] ) );
+  assert!( ( got.mag() - 2.0 ).abs() < 1e-5 );
+
+  // Antipodal quaternions describe the same rotation and are blended as such
+  let flipped = DualQuatF32 { real : QuatF32( b.real.0 * -1.0 ), dual : QuatF32( b.dual.0 * -1.0 ) };
+  let got = DualQuatF32::blend( &[ ( b, 1.0 ), ( flipped, 1.0 ) ] ).unwrap();
+  assert_same_rotation( got.real, rotation );
+
+  assert!( DualQuatF32::blend( &[] ).is_none() );
+}
@@ -0,0 +1,128 @@
+use super::*;
+use the_module::
+{
+  random,
+  Pcg32,
+  F32x2,
+  F32x3,
+  Vector
+};
+
+#[ test ]
+fn test_pcg()
+{
+  let mut a = Pcg32::new( 42 );
+  let mut b = Pcg32::new( 42 );
+  let seq : Vec< u32 > = ( 0..16 ).map( | _ | a.next_u32() ).collect();
+  assert_eq!( seq, ( 0..16 ).map( | _ | b.next_u32() ).collect::< Vec< _ > >() );
+  assert_ne!( seq, ( 0..16 ).map( | _ | Pcg32::with_stream( 42, 1 ).next_u32() ).collect::< Vec< _ > >() );
+
+  let mut rng = Pcg32::new( 1 );
+  let mut sum = 0.0;
+  for _ in 0..10_000
+  {
+    let f : f32 = rng.next_float();
+    assert!( ( 0.0..1.0 ).contains( &f ) );
+    sum += f;
+    assert!( rng.next_below( 7 ) < 7 );
+    let r = rng.range( -2.0f64, 3.0 );
+    assert!( ( -2.0..3.0 ).contains( &r ) );
+  }
+  assert!( ( sum / 10_000.0 - 0.5 ).abs() < 0.02 );
+
+  let mut items : Vec< u32 > = ( 0..32 ).collect();
+  rng.shuffle( &mut items );
+  assert_ne!( items, ( 0..32 ).collect::< Vec< _ > >() );
+  items.sort_unstable();
+  assert_eq!( items, ( 0..32 ).collect::< Vec< _ > >() );
+}
+
+#[ test ]
+fn test_distribution()
+{
+  let mut rng = Pcg32::new( 7 );
+  let mut mean = F32x3::default();
+  let mut mean_cos = 0.0;
+  let count = 20_000;
+  for _ in 0..count
+  {
+    let u : F32x2 = rng.next_vector();
+
+    let s = random::uniform_sphere( u );
+    assert!( ( s.mag() - 1.0 ).abs() < 1e-5 );
+    mean += s;
+
+    let h = random::uniform_hemisphere( u );
+    assert!( h.0[ 2 ] >= 0.0 && ( h.mag() - 1.0 ).abs() < 1e-5 );
+
+    let c = random::cosine_hemisphere( u );
+    assert!( c.0[ 2 ] >= 0.0 && ( c.mag() - 1.0 ).abs() < 1e-5 );
+    mean_cos += c.0[ 2 ];
+
+    assert!( random::uniform_disk( u ).mag() <= 1.0 );
+    assert!( random::uniform_ball( rng.next_vector() ).mag() <= 1.0 );
+  }
+
+  // Sphere is balanced around the origin, cosine-weighted hemisphere has E[ cos ] = 2 / 3
+  assert!( ( mean / count as f32 ).mag() < 0.02 );
+  assert!( ( mean_cos / count as f32 - 2.0 / 3.0 ).abs() < 0.01 );
+  assert!( ( random::cosine_hemisphere_pdf( 1.0f32 ) - core::f32::consts::FRAC_1_PI ).abs() < 1e-7 );
+
+  for normal in [ F32x3::from( [ 0.0, 0.0, 1.0 ] ), F32x3::from( [ 0.0, 0.0, -1.0 ] ), F32x3::from( [ 1.0, 2.0, -3.0 ] ).normalize() ]
+  {
+    assert!( random::orient( F32x3::from( [ 0.0, 0.0, 1.0 ] ), normal ).distance( &normal ) < 1e-6 );
+    let v = random::orient( random::cosine_hemisphere( Vector( [ 0.3f32, 0.8 ] ) ), normal );
+    assert!( ( v.mag() - 1.0 ).abs() < 1e-5 );
+    assert!( the_module::vector::arithmetics::inner_product::dot( &v, &normal ) >= 0.0 );
+  }
+}
+
+#[ test ]
+fn test_sequence()
+{
+  assert_eq!( random::radical_inverse::< f64 >( 1, 2 ), 0.5 );
+  assert_eq!( random::radical_inverse::< f64 >( 6, 2 ), 0.375 );
+  assert!( ( random::radical_inverse::< f64 >( 5, 3 ) - 7.0 / 9.0 ).abs() < 1e-12 );
+
+  let h : Vector< f64, 3 > = random::halton( 1 );
+  assert!( ( h.0[ 0 ] - 0.5 ).abs() < 1e-12 && ( h.0[ 1 ] - 1.0 / 3.0 ).abs() < 1e-12 && ( h.0[ 2 ] - 0.2 ).abs() < 1e-12 );
+
+  assert_eq!( random::hammersley::< f64 >( 3, 4 ), Vector( [ 0.75, 0.75 ] ) );
+
+  // First points of the Sobol sequence
+  let exp = [ [ 0.0, 0.0 ], [ 0.5, 0.5 ], [ 0.25, 0.75 ], [ 0.75, 0.25 ], [ 0.125, 0.625 ] ];
+  for ( i, e ) in exp.iter().enumerate()
+  {
+    assert_eq!( random::sobol2::< f64 >( i as u32 ).0, *e );
+  }
+
+  // Every quarter of the square gets exactly one of the first four points
+  let mut quarters = [ 0; 4 ];
+  for i in 0..4
+  {
+    let p = random::sobol2::< f32 >( i );
+    quarters[ ( p.0[ 0 ] * 2.0 ) as usize + 2 * ( p.0[ 1 ] * 2.0 ) as usize ] += 1;
+  }
+  assert_eq!( quarters, [ 1; 4 ] );
+}
+
+#[ test ]
+#[ should_panic ]
+fn test_radical_inverse_base_one()
+{
+  random::radical_inverse::< f64 >( 5, 1 );
+}
+
+#[ test ]
+#[ should_panic ]
+fn test_radical_inverse_base_zero()
+{
+  random::radical_inverse::< f64 >( 5, 0 );
+}
+
+#[ test ]
+#[ should_panic ]
+fn test_hammersley_empty()
+{
+  random::hammersley::< f64 >( 0, 0 );
+}
@@ -0,0 +1,66 @@
+use super::*;
+use the_module::
+{
+  RawSlice,
+  Mat4,
+  mat,
+  F32x3,
+  F32x4,
+  Vector
+};
+
+fn test_mul_generic< Descriptor : mat::Descriptor >()
+where
+  Mat4< f32, Descriptor > : the_module::RawSliceMut< Scalar = f32 > + PartialEq + core::fmt::Debug,
+  Mat4< f32, Descriptor > : core::ops::Mul< Output = Mat4< f32, Descriptor > > + core::ops::Mul< F32x4, Output = F32x4 > + Copy
+{
+  let a = Mat4::< f32, Descriptor >::from_row_major
+  ([
+    1.0, 2.0, 3.0, 4.0,
+    5.0, 6.0, 7.0, 8.0,
+    9.0, 10.0, 11.0, 12.0,
+    13.0, 14.0, 15.0, 16.0
+  ]);
+  let b = Mat4::< f32, Descriptor >::from_row_major
+  ([
+    0.5, 0.0, 1.0, 0.0,
+    0.0, 2.0, 0.0, 1.0,
+    1.0, 0.0, -1.0, 0.0,
+    0.0, 3.0, 0.0, 1.0
+  ]);
+
+  assert_eq!( a.mul_simd( &b ).raw_slice(), ( a * b ).raw_slice() );
+
+  let v = F32x4::from( [ 1.0, -1.0, 2.0, 0.5 ] );
+  assert_eq!( a.mul_vec_simd( v ), a * v );
+}
+
+#[ test ]
+fn test_mul_row_major()
+{
+  test_mul_generic::< mat::DescriptorOrderRowMajor >();
+}
+
+#[ test ]
+fn test_mul_column_major()
+{
+  test_mul_generic::< mat::DescriptorOrderColumnMajor >();
+}
+
+#[ test ]
+fn test_vector()
+{
+  let a = F32x4::from( [ 1.0, 2.0, 3.0, 4.0 ] );
+  let b = F32x4::from( [ -1.0, 0.5, 2.0, 1.0 ] );
+  assert_eq!( a.dot_simd( &b ), 10.0 );
+
+  let got = a.normalize_simd();
+  let exp = a.normalize();
+  for ( g, e ) in got.0.iter().zip( exp.0 )
+  {
+    assert!( ( g - e ).abs() < 1e-6 );
+  }
+
+  let got = F32x3::from( [ 3.0, 0.0, 4.0 ] ).normalize_simd();
+  assert_eq!( got, Vector( [ 0.6, 0.0, 0.8 ] ) );
+}
@@ -0,0 +1,94 @@
+use super::*;
+use the_module::
+{
+  F32x3,
+  F32x4x4,
+  QuatF32,
+  TransformF32,
+  TransformStack,
+  mat,
+  Vector
+};
+
+fn assert_close( got : &[ f32 ], exp : &[ f32 ] )
+{
+  assert_eq!( got.len(), exp.len() );
+  for ( g, e ) in got.iter().zip( exp )
+  {
+    assert!( ( g - e ).abs() < 1e-5, "got {:?}, expected {:?}", got, exp );
+  }
+}
+
+#[ test ]
+fn test_transform()
+{
+  let transform = TransformF32::new
+  (
+    F32x3::from( [ 1.0, 2.0, 3.0 ] ),
+    QuatF32::from_axis_angle( Vector( [ 0.0, 0.0, 1.0 ] ), std::f32::consts::FRAC_PI_2 ),
+    F32x3::from( [ 2.0, 2.0, 2.0 ] )
+  );
+
+  let p = F32x3::from( [ 1.0, 0.0, 0.0 ] );
+  assert_close( &transform.transform_point( p ).0, &[ 1.0, 4.0, 3.0 ] );
+  assert_close( &transform.transform_vector( p ).0, &[ 0.0, 2.0, 0.0 ] );
+
+  let mat : F32x4x4 = transform.to_matrix();
+  let from_mat = TransformF32::from_matrix( &mat ).unwrap();
+  assert_close( &from_mat.transform_point( p ).0, &transform.transform_point( p ).0 );
+
+  let inverse = transform.inverse().unwrap();
+  assert_close( &inverse.transform_point( transform.transform_point( p ) ).0, &p.0 );
+  assert_close( &( transform * inverse ).transform_point( p ).0, &p.0 );
+
+  let child = TransformF32::from_translation( F32x3::from( [ 0.0, 1.0, 0.0 ] ) );
+  assert_close( &( transform * child ).transform_point( p ).0, &transform.transform_point( child.transform_point( p ) ).0 );
+
+  assert_eq!( TransformF32::default().transform_point( p ), p );
+  assert!( TransformF32::from_scale( F32x3::from( [ 1.0, 0.0, 1.0 ] ) ).inverse().is_none() );
+
+  let halfway = TransformF32::identity().lerp( &transform, 0.5 );
+  assert_close( &halfway.t.0, &[ 0.5, 1.0, 1.5 ] );
+  assert_close( &halfway.s.0, &[ 1.5, 1.5, 1.5 ] );
+}
+
+#[ test ]
+fn test_stack()
+{
+  let mut stack = TransformStack::< f32, mat::DescriptorOrderColumnMajor >::new();
+  assert_eq!( stack.depth(), 0 );
+  assert!( stack.pop().is_none() );
+
+  let parent = TransformF32::new
+  (
+    F32x3::from( [ 10.0, 0.0, 0.0 ] ),
+    QuatF32::from_axis_angle( Vector( [ 0.0, 1.0, 0.0 ] ), std::f32::consts::FRAC_PI_2 ),
+    F32x3::from( [ 1.0, 1.0, 1.0 ] )
+  );
+  let child = TransformF32::from_translation( F32x3::from( [ 0.0, 0.0, 1.0 ] ) );
+
+  stack.push_transform( &parent );
+  stack.push_transform( &child );
+  assert_eq!( stack.depth(), 2 );
+  let origin = F32x3::from( [ 0.0, 0.0, 0.0 ] );
+  assert_close( &stack.transform_point( origin ).0, &[ 11.0, 0.0, 0.0 ] );
+
+  stack.pop().unwrap();
+  assert_close( &stack.transform_point( origin ).0, &[ 10.0, 0.0, 0.0 ] );
+
+  let world = stack.scoped
+  (
+    &child.to_matrix(),
+    | s |
+    {
+      s.push_transform( &child );
+      s.transform_point( origin )
+    }
+  );
+  assert_close( &world.0, &[ 12.0, 0.0, 0.0 ] );
+  assert_eq!( stack.depth(), 1 );
+
+  stack.reset();
+  assert_eq!( stack.depth(), 0 );
+  assert_close( &stack.transform_point( origin ).0, &origin.0 );
+}
@@ -23,6 +23,10 @@ crate::mod_interface!
   #[ cfg( feature = "webFuture"  ) ]
   layer future;
 
+  /// Loading and caching of assets.
+  #[ cfg( feature = "webFuture"  ) ]
+  layer asset;
+
   /// File processing.
   #[ cfg( all( feature = "webFuture", feature = "webFile" ) ) ]
   layer file;
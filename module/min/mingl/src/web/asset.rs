@@ -0,0 +1,489 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use crate::web::*;
+  use wasm_bindgen::JsCast;
+  use super::persistent::PersistentCache;
+  use std::
+  {
+    any::{ Any, TypeId },
+    cell::RefCell,
+    collections::HashMap,
+    future::Future,
+    pin::Pin,
+    rc::Rc,
+    task::{ Context, Poll, Waker },
+  };
+
+  /// Represents errors of loading assets.
+  #[ derive( Debug, Clone, error::typed::Error ) ]
+  pub enum AssetError
+  {
+    /// The request failed or the server responded with an error.
+    #[ error( "Failed to fetch {0}\n{1}" ) ]
+    Fetch( String, String ),
+    /// The data was fetched, but the decoder rejected it.
+    #[ error( "Failed to decode {0}\n{1}" ) ]
+    Decode( String, String ),
+    /// Loading was cancelled with `Handle::cancel`.
+    #[ error( "Loading of {0} was cancelled" ) ]
+    Cancelled( String ),
+  }
+
+  /// State of an asset behind a `Handle`.
+  #[ derive( Debug ) ]
+  pub enum LoadState< T >
+  {
+    /// Still being fetched or decoded
+    Loading,
+    /// Ready to use
+    Loaded( Rc< T > ),
+    /// Failed or cancelled
+    Failed( AssetError ),
+  }
+
+  impl< T > Clone for LoadState< T >
+  {
+    fn clone( &self ) -> Self
+    {
+      match self
+      {
+        Self::Loading => Self::Loading,
+        Self::Loaded( asset ) => Self::Loaded( asset.clone() ),
+        Self::Failed( error ) => Self::Failed( error.clone() ),
+      }
+    }
+  }
+
+  struct Slot< T >
+  {
+    state : LoadState< T >,
+    /// Waker of every pending `Wait`, by its id
+    wakers : HashMap< u64, Waker >,
+    next_waiter : u64,
+    abort : Option< web_sys::AbortController >,
+  }
+
+  /// Shared handle to an asset, which may be still loading.
+  ///
+  /// All handles of the same asset share its state, so requesting an asset twice doesn't load it twice.
+  /// Await `wait` to get the asset or poll `state` every frame.
+  pub struct Handle< T >
+  {
+    path : Rc< str >,
+    slot : Rc< RefCell< Slot< T > > >,
+  }
+
+  impl< T > Clone for Handle< T >
+  {
+    fn clone( &self ) -> Self
+    {
+      Self { path : self.path.clone(), slot : self.slot.clone() }
+    }
+  }
+
+  impl< T > core::fmt::Debug for Handle< T >
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let state = match &self.slot.borrow().state
+      {
+        LoadState::Loading => "Loading",
+        LoadState::Loaded( _ ) => "Loaded",
+        LoadState::Failed( _ ) => "Failed",
+      };
+      f.debug_struct( "Handle" ).field( "path", &self.path ).field( "state", &state ).finish()
+    }
+  }
+
+  impl< T > Handle< T >
+  {
+    fn new( path : &str ) -> Self
+    {
+      let slot = Slot { state : LoadState::Loading, wakers : HashMap::new(), next_waiter : 0, abort : None };
+      Self { path : path.into(), slot : Rc::new( RefCell::new( slot ) ) }
+    }
+
+    /// Path the asset is loaded from
+    pub fn path( &self ) -> &str
+    {
+      &self.path
+    }
+
+    /// Current state of the asset
+    pub fn state( &self ) -> LoadState< T >
+    {
+      self.slot.borrow().state.clone()
+    }
+
+    /// The asset, if it's loaded
+    pub fn get( &self ) -> Option< Rc< T > >
+    {
+      match &self.slot.borrow().state
+      {
+        LoadState::Loaded( asset ) => Some( asset.clone() ),
+        _ => None,
+      }
+    }
+
+    /// Whether the asset is still loading
+    pub fn is_loading( &self ) -> bool
+    {
+      matches!( self.slot.borrow().state, LoadState::Loading )
+    }
+
+    /// Stops loading the asset for all its handles and aborts the request if it's in flight.
+    /// Does nothing if the asset is already loaded or failed.
+    ///
+    /// Only handles returned by `AssetManager::bytes` own the request. Cancelling a handle returned by `AssetManager::load`
+    /// doesn't abort the fetch of the file, as other assets may be decoded from it, cancel `AssetManager::bytes` of the path for that.
+    pub fn cancel( &self )
+    {
+      let abort = self.slot.borrow_mut().abort.take();
+      if let Some( abort ) = abort
+      {
+        abort.abort();
+      }
+      self.finish( Err( AssetError::Cancelled( self.path.to_string() ) ) );
+    }
+
+    /// Waits until the asset is loaded or failed
+    pub fn wait( &self ) -> Wait< T >
+    {
+      let id =
+      {
+        let mut slot = self.slot.borrow_mut();
+        slot.next_waiter += 1;
+        slot.next_waiter
+      };
+      Wait { handle : self.clone(), id }
+    }
+
+    fn finish( &self, result : Result< T, AssetError > )
+    {
+      let wakers =
+      {
+        let mut slot = self.slot.borrow_mut();
+        if !matches!( slot.state, LoadState::Loading )
+        {
+          return;
+        }
+        slot.state = match result
+        {
+          Ok( asset ) => LoadState::Loaded( Rc::new( asset ) ),
+          Err( error ) => LoadState::Failed( error ),
+        };
+        slot.abort = None;
+        core::mem::take( &mut slot.wakers )
+      };
+      wakers.into_values().for_each( Waker::wake );
+    }
+  }
+
+  /// Future of `Handle::wait`.
+  #[ derive( Debug ) ]
+  pub struct Wait< T >
+  {
+    handle : Handle< T >,
+    id : u64,
+  }
+
+  impl< T > Future for Wait< T >
+  {
+    type Output = Result< Rc< T >, AssetError >;
+
+    fn poll( self : Pin< &mut Self >, cx : &mut Context< '_ > ) -> Poll< Self::Output >
+    {
+      let mut slot = self.handle.slot.borrow_mut();
+      match &slot.state
+      {
+        LoadState::Loading => {}
+        LoadState::Loaded( asset ) => return Poll::Ready( Ok( asset.clone() ) ),
+        LoadState::Failed( error ) => return Poll::Ready( Err( error.clone() ) ),
+      }
+
+      // One waker per waiter, replaced only if the task changed
+      match slot.wakers.get_mut( &self.id )
+      {
+        Some( waker ) if waker.will_wake( cx.waker() ) => {}
+        Some( waker ) => *waker = cx.waker().clone(),
+        None => { slot.wakers.insert( self.id, cx.waker().clone() ); }
+      }
+      Poll::Pending
+    }
+  }
+
+  impl< T > Drop for Wait< T >
+  {
+    fn drop( &mut self )
+    {
+      self.handle.slot.borrow_mut().wakers.remove( &self.id );
+    }
+  }
+
+  #[ derive( Default ) ]
+  struct Cache
+  {
+    root : String,
+    bytes : HashMap< String, Handle< Vec< u8 > > >,
+    decoded : HashMap< ( String, TypeId ), Box< dyn Any > >,
+    persistent : Option< PersistentCache >,
+  }
+
+  /// Loads and caches assets.
+  ///
+  /// Every path is fetched once, no matter how many assets are decoded from it,
+  /// and every decoded asset is cached by its path and type, so shared dependencies are neither downloaded nor decoded twice.
+  /// Failed and cancelled loads are not cached, requesting them again retries.
+  /// Clones share the same cache.
+  ///
+  /// ```rust, ignore
+  /// let assets = gl::asset::AssetManager::new();
+  /// let image = assets.load( "textures/albedo.png", | bytes | image::load_from_memory( bytes ).map_err( | e | e.to_string() ) );
+  /// let same = assets.load( "textures/albedo.png", | bytes | image::load_from_memory( bytes ).map_err( | e | e.to_string() ) );
+  /// let image = image.wait().await?;
+  /// ```
+  #[ derive( Clone, Default ) ]
+  pub struct AssetManager
+  {
+    cache : Rc< RefCell< Cache > >,
+  }
+
+  impl core::fmt::Debug for AssetManager
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      let cache = self.cache.borrow();
+      f.debug_struct( "AssetManager" )
+      .field( "root", &cache.root )
+      .field( "files", &cache.bytes.len() )
+      .field( "assets", &cache.decoded.len() )
+      .finish()
+    }
+  }
+
+  impl AssetManager
+  {
+    /// Creates a manager loading paths relative to `static/` of the origin, as `file::load` does
+    pub fn new() -> Self
+    {
+      let origin = web_sys::window()
+      .and_then( | w | w.location().origin().ok() )
+      .unwrap_or_default();
+      Self::with_root( &format!( "{}/static", origin ) )
+    }
+
+    /// Creates a manager loading paths relative to `root`
+    pub fn with_root( root : &str ) -> Self
+    {
+      let cache = Cache { root : root.trim_end_matches( '/' ).to_string(), ..Default::default() };
+      Self { cache : Rc::new( RefCell::new( cache ) ) }
+    }
+
+    /// Stores fetched files in the IndexedDB database `name`, so they survive page reloads.
+    /// Stored files are revalidated by their `ETag` and downloaded again only if they changed,
+    /// and are used as is when the server can't be reached.
+    pub fn with_persistent_cache( self, name : &str ) -> Self
+    {
+      self.cache.borrow_mut().persistent = Some( PersistentCache::new( name ) );
+      self
+    }
+
+    /// The persistent cache, if it's enabled with `with_persistent_cache`
+    pub fn persistent_cache( &self ) -> Option< PersistentCache >
+    {
+      self.cache.borrow().persistent.clone()
+    }
+
+    /// Raw content of the file at `path`
+    pub fn bytes( &self, path : &str ) -> Handle< Vec< u8 > >
+    {
+      let mut cache = self.cache.borrow_mut();
+      if let Some( handle ) = cache.bytes.get( path ).filter( | h | !is_failed( h ) )
+      {
+        return handle.clone();
+      }
+
+      let handle = Handle::new( path );
+      cache.bytes.insert( path.to_string(), handle.clone() );
+      let url = format!( "{}/{}", cache.root, path.trim_start_matches( '/' ) );
+      let persistent = cache.persistent.clone();
+      drop( cache );
+
+      let abort = web_sys::AbortController::new().ok();
+      let signal = abort.as_ref().map( | a | a.signal() );
+      handle.slot.borrow_mut().abort = abort;
+
+      let result = handle.clone();
+      spawn_local( async move
+      {
+        let bytes = fetch_cached( &url, signal.as_ref(), persistent.as_ref() ).await
+        .map_err( | e | AssetError::Fetch( handle.path.to_string(), e ) );
+        handle.finish( bytes );
+      });
+
+      result
+    }
+
+    /// Asset decoded by `decode` from the file at `path`.
+    /// The decoded asset is cached by its path and type, `decode` is called only for the first request.
+    pub fn load< T, F >( &self, path : &str, decode : F ) -> Handle< T >
+    where
+      T : 'static,
+      F : FnOnce( &[ u8 ] ) -> Result< T, String > + 'static,
+    {
+      let key = ( path.to_string(), TypeId::of::< T >() );
+      if let Some( handle ) = self.cache.borrow().decoded.get( &key )
+      .and_then( | h | h.downcast_ref::< Handle< T > >() )
+      .filter( | h | !is_failed( h ) )
+      {
+        return handle.clone();
+      }
+
+      let handle = Handle::new( path );
+      self.cache.borrow_mut().decoded.insert( key, Box::new( handle.clone() ) );
+
+      let bytes = self.bytes( path );
+      let result = handle.clone();
+      spawn_local( async move
+      {
+        // Cancelled before the bytes arrived
+        if !handle.is_loading()
+        {
+          return;
+        }
+        let asset = match bytes.wait().await
+        {
+          Ok( bytes ) => decode( &bytes ).map_err( | e | AssetError::Decode( handle.path.to_string(), e ) ),
+          Err( error ) => Err( error ),
+        };
+        handle.finish( asset );
+      });
+
+      result
+    }
+
+    /// Removes the file at `path` and every asset decoded from it from the cache.
+    /// Existing handles stay valid.
+    pub fn evict( &self, path : &str )
+    {
+      let mut cache = self.cache.borrow_mut();
+      cache.bytes.remove( path );
+      cache.decoded.retain( | ( p, _ ), _ | p != path );
+    }
+
+    /// Removes everything from the cache
+    pub fn clear( &self )
+    {
+      let mut cache = self.cache.borrow_mut();
+      cache.bytes.clear();
+      cache.decoded.clear();
+    }
+  }
+
+  fn is_failed< T >( handle : &Handle< T > ) -> bool
+  {
+    matches!( handle.slot.borrow().state, LoadState::Failed( _ ) )
+  }
+
+  /// Fetches `url` through the persistent cache, errors of the cache itself are ignored
+  async fn fetch_cached
+  (
+    url : &str,
+    signal : Option< &web_sys::AbortSignal >,
+    persistent : Option< &PersistentCache >,
+  )
+  -> Result< Vec< u8 >, String >
+  {
+    let Some( persistent ) = persistent else
+    {
+      return match fetch( url, signal, None ).await?
+      {
+        Fetched::Modified( bytes, _ ) => Ok( bytes ),
+        Fetched::NotModified => Err( "Unexpected status 304".into() ),
+      };
+    };
+
+    let stored = persistent.get( url ).await.ok().flatten();
+    match fetch( url, signal, stored.as_ref().map( | r | r.etag.as_str() ) ).await
+    {
+      Ok( Fetched::Modified( bytes, etag ) ) =>
+      {
+        if let Some( etag ) = etag
+        {
+          let _ = persistent.put( url, &etag, &bytes ).await;
+        }
+        Ok( bytes )
+      }
+      Ok( Fetched::NotModified ) => stored.map( | r | r.data ).ok_or( "Unexpected status 304".into() ),
+      // Offline or the server is down, the stored copy is better than nothing
+      Err( error ) => match stored
+      {
+        Some( record ) if !signal.is_some_and( | s | s.aborted() ) => Ok( record.data ),
+        _ => Err( error ),
+      },
+    }
+  }
+
+  enum Fetched
+  {
+    /// Content and `ETag` of the response
+    Modified( Vec< u8 >, Option< String > ),
+    /// The server confirmed that the content with the requested `ETag` is up to date
+    NotModified,
+  }
+
+  /// Fetches `url`, revalidating `etag` with `If-None-Match` if it's given
+  async fn fetch( url : &str, signal : Option< &web_sys::AbortSignal >, etag : Option< &str > ) -> Result< Fetched, String >
+  {
+    let opts = web_sys::RequestInit::new();
+    opts.set_method( "GET" );
+    opts.set_mode( web_sys::RequestMode::Cors );
+    opts.set_signal( signal );
+    if let Some( etag ) = etag
+    {
+      let headers = web_sys::Headers::new().map_err( | e | format!( "{:?}", e ) )?;
+      headers.set( "If-None-Match", etag ).map_err( | e | format!( "{:?}", e ) )?;
+      opts.set_headers( &headers );
+    }
+
+    let window = web_sys::window().ok_or( "No window" )?;
+    let request = web_sys::Request::new_with_str_and_init( url, &opts ).map_err( | e | format!( "{:?}", e ) )?;
+    let response : web_sys::Response = JsFuture::from( window.fetch_with_request( &request ) ).await
+    .map_err( | e | format!( "{:?}", e ) )?
+    .dyn_into()
+    .map_err( | e | format!( "{:?}", e ) )?;
+    if etag.is_some() && response.status() == 304
+    {
+      return Ok( Fetched::NotModified );
+    }
+    if !response.ok()
+    {
+      return Err( format!( "Status {}", response.status() ) );
+    }
+
+    let etag = response.headers().get( "ETag" ).ok().flatten();
+    let array_buffer = JsFuture::from( response.array_buffer().map_err( | e | format!( "{:?}", e ) )? ).await
+    .map_err( | e | format!( "{:?}", e ) )?;
+    Ok( Fetched::Modified( js_sys::Uint8Array::new( &array_buffer ).to_vec(), etag ) )
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  /// Persistent cache of fetched files in IndexedDB.
+  layer persistent;
+
+  own use
+  {
+    AssetError,
+    LoadState,
+    Handle,
+    Wait,
+    AssetManager,
+  };
+
+}
@@ -0,0 +1,237 @@
+/// Internal namespace.
+mod private
+{
+  use crate::web::*;
+  use wasm_bindgen::{ JsCast, closure::Closure };
+  use std::{ cell::RefCell, rc::Rc };
+
+  const STORE : &str = "assets";
+
+  /// A file stored in the persistent cache.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct Record
+  {
+    /// `ETag` of the response the data came with
+    pub etag : String,
+    /// Content of the file
+    pub data : Vec< u8 >,
+  }
+
+  /// Cache of fetched files in IndexedDB, which survives page reloads.
+  ///
+  /// Files are stored by URL together with their `ETag`. `AssetManager::with_persistent_cache` revalidates them
+  /// with `If-None-Match`, so a file is downloaded again only when it changed on the server.
+  /// Files served without `ETag` are not stored.
+  ///
+  /// Data derived from a file, like prefiltered IBL maps, is stored with `put_derived` under the URL and `ETag` of the file,
+  /// so it's recomputed only when the file changes.
+  #[ derive( Debug, Clone ) ]
+  pub struct PersistentCache
+  {
+    name : Rc< str >,
+    /// Opening of the database, shared by the calls made before it's finished
+    database : Rc< RefCell< Option< js_sys::Promise > > >,
+  }
+
+  impl PersistentCache
+  {
+    /// Creates a cache stored in the database `name`. The database is opened on the first use
+    pub fn new( name : &str ) -> Self
+    {
+      Self { name : name.into(), database : Default::default() }
+    }
+
+    /// The stored file for `url`
+    pub async fn get( &self, url : &str ) -> Result< Option< Record >, String >
+    {
+      self.get_record( &url.into() ).await
+    }
+
+    /// Stores the file for `url`, replacing the previous one
+    pub async fn put( &self, url : &str, etag : &str, data : &[ u8 ] ) -> Result< (), String >
+    {
+      self.put_record( &url.into(), etag, data ).await
+    }
+
+    /// Removes the file for `url` and the data derived from it
+    pub async fn remove( &self, url : &str ) -> Result< (), String >
+    {
+      let store = self.store( web_sys::IdbTransactionMode::Readwrite ).await?;
+      request( &store.delete( &url.into() ).map_err( js_error )? ).await?;
+      request( &store.delete( &derived_key( url ) ).map_err( js_error )? ).await?;
+      Ok( () )
+    }
+
+    /// Data derived from the file at `url`, if it was stored by `put_derived` for the same `etag` of the file
+    pub async fn get_derived< T : bytemuck::Pod >( &self, url : &str, etag : &str ) -> Result< Option< Vec< T > >, String >
+    {
+      let record = self.get_record( &derived_key( url ) ).await?;
+      Ok
+      (
+        record
+        .filter( | r | r.etag == etag && r.data.len() % core::mem::size_of::< T >() == 0 )
+        .map( | r | bytemuck::pod_collect_to_vec( &r.data ) )
+      )
+    }
+
+    /// Stores data derived from the file at `url` with `etag`, replacing the previous one
+    pub async fn put_derived< T : bytemuck::Pod >( &self, url : &str, etag : &str, data : &[ T ] ) -> Result< (), String >
+    {
+      self.put_record( &derived_key( url ), etag, bytemuck::cast_slice( data ) ).await
+    }
+
+    /// Removes all the files
+    pub async fn clear( &self ) -> Result< (), String >
+    {
+      let store = self.store( web_sys::IdbTransactionMode::Readwrite ).await?;
+      request( &store.clear().map_err( js_error )? ).await?;
+      Ok( () )
+    }
+
+    async fn get_record( &self, key : &JsValue ) -> Result< Option< Record >, String >
+    {
+      let store = self.store( web_sys::IdbTransactionMode::Readonly ).await?;
+      let value = request( &store.get( key ).map_err( js_error )? ).await?;
+      if value.is_undefined()
+      {
+        return Ok( None );
+      }
+
+      let etag = js_sys::Reflect::get( &value, &"etag".into() ).map_err( js_error )?.as_string().unwrap_or_default();
+      let data = js_sys::Reflect::get( &value, &"data".into() ).map_err( js_error )?;
+      let data = js_sys::Uint8Array::new( &data ).to_vec();
+      Ok( Some( Record { etag, data } ) )
+    }
+
+    async fn put_record( &self, key : &JsValue, etag : &str, data : &[ u8 ] ) -> Result< (), String >
+    {
+      let record = js_sys::Object::new();
+      js_sys::Reflect::set( &record, &"etag".into(), &etag.into() ).map_err( js_error )?;
+      js_sys::Reflect::set( &record, &"data".into(), &js_sys::Uint8Array::from( data ) ).map_err( js_error )?;
+
+      let store = self.store( web_sys::IdbTransactionMode::Readwrite ).await?;
+      request( &store.put_with_key( &record, key ).map_err( js_error )? ).await?;
+      Ok( () )
+    }
+
+    async fn store( &self, mode : web_sys::IdbTransactionMode ) -> Result< web_sys::IdbObjectStore, String >
+    {
+      let database = self.database().await?;
+      database.transaction_with_str_and_mode( STORE, mode ).map_err( js_error )?
+      .object_store( STORE ).map_err( js_error )
+    }
+
+    async fn database( &self ) -> Result< web_sys::IdbDatabase, String >
+    {
+      // The opening runs on its own, so it's finished even if the first caller is dropped
+      let promise = self.database.borrow_mut()
+      .get_or_insert_with( || future_to_promise( open( self.name.clone() ) ) )
+      .clone();
+
+      match JsFuture::from( promise.clone() ).await
+      {
+        Ok( database ) => database.dyn_into().map_err( js_error ),
+        Err( error ) =>
+        {
+          // Let the next call try again, unless it already did
+          let mut database = self.database.borrow_mut();
+          if database.as_ref().is_some_and( | p | js_sys::Object::is( p, &promise ) )
+          {
+            *database = None;
+          }
+          Err( js_error( error ) )
+        }
+      }
+    }
+  }
+
+  /// Opens the database `name`, creating the store on the first use
+  async fn open( name : Rc< str > ) -> Result< JsValue, JsValue >
+  {
+    let factory = web_sys::window()
+    .ok_or( "No window" )?
+    .indexed_db()?
+    .ok_or( "IndexedDB is not available" )?;
+    let open = factory.open_with_u32( &name, 1 )?;
+
+    let _open = open.clone();
+    let on_upgrade = Closure::once( move ||
+    {
+      if let Some( database ) = _open.result().ok().and_then( | r | r.dyn_into::< web_sys::IdbDatabase >().ok() )
+      {
+        let _ = database.create_object_store( STORE );
+      }
+    });
+    open.set_onupgradeneeded( Some( on_upgrade.as_ref().unchecked_ref() ) );
+
+    let database = request( &open ).await;
+    open.set_onupgradeneeded( None );
+    drop( on_upgrade );
+    database.map_err( JsValue::from )
+  }
+
+  /// Key of the data derived from the file at `url`, which can't collide with the string keys of files
+  fn derived_key( url : &str ) -> JsValue
+  {
+    js_sys::Array::of2( &url.into(), &"derived".into() ).into()
+  }
+
+  /// Success and error handlers of a request, detached from it when dropped
+  struct Handlers
+  {
+    request : web_sys::IdbRequest,
+    _on_success : Closure< dyn FnMut() >,
+    _on_error : Closure< dyn FnMut() >,
+  }
+
+  impl Drop for Handlers
+  {
+    fn drop( &mut self )
+    {
+      self.request.set_onsuccess( None );
+      self.request.set_onerror( None );
+    }
+  }
+
+  /// Waits for the IndexedDB request and returns its result.
+  /// The handlers are kept until the request settles or the future is dropped, and released after that
+  async fn request( request : &web_sys::IdbRequest ) -> Result< wasm_bindgen::JsValue, String >
+  {
+    let mut handlers = None;
+    let promise = js_sys::Promise::new( &mut | resolve, reject |
+    {
+      let _request = request.clone();
+      let on_success = Closure::once( move ||
+      {
+        let _ = resolve.call1( &JsValue::NULL, &_request.result().unwrap_or( JsValue::UNDEFINED ) );
+      });
+      let on_error = Closure::once( move ||
+      {
+        let _ = reject.call1( &JsValue::NULL, &"IndexedDB request failed".into() );
+      });
+      request.set_onsuccess( Some( on_success.as_ref().unchecked_ref() ) );
+      request.set_onerror( Some( on_error.as_ref().unchecked_ref() ) );
+      handlers = Some( Handlers { request : request.clone(), _on_success : on_success, _on_error : on_error } );
+    });
+    let result = JsFuture::from( promise ).await.map_err( js_error );
+    drop( handlers );
+    result
+  }
+
+  fn js_error( error : wasm_bindgen::JsValue ) -> String
+  {
+    format!( "{:?}", error )
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  own use
+  {
+    Record,
+    PersistentCache,
+  };
+
+}
@@ -107,6 +107,134 @@ mod private
     make()
   }
 
+  /// Keeps the size of the drawing buffer of a canvas equal to its size on the screen in device pixels.
+  ///
+  /// Owns a `ResizeObserver` watching the canvas, so the canvas follows any layout change, not only the window resize.
+  /// The size is taken from `devicePixelContentBoxSize` where the browser reports it, which is exact in device pixels,
+  /// otherwise from the CSS content box scaled by the device pixel ratio.
+  /// The device pixel ratio is clamped by `max_pixel_ratio` to bound the cost of rendering on high density screens.
+  /// After every change of the size `on_resize` is invoked with the new width and height in pixels,
+  /// which is the place to update the viewport and the projection.
+  /// Observing stops when the value is dropped.
+  ///
+  /// The canvas must be sized by CSS, for example `width : 100%; height : 100%`, as `make` does.
+  /// Without a CSS size the layout size of a canvas follows its `width` and `height` attributes,
+  /// so every resize would trigger another one and the canvas would grow without limit.
+  ///
+  /// ```rust, ignore
+  /// let responsive = gl::canvas::ResponsiveCanvas::new( &canvas, 2.0, move | width, height |
+  /// {
+  ///   gl.viewport( 0, 0, width as i32, height as i32 );
+  /// })?;
+  /// ```
+  pub struct ResponsiveCanvas
+  {
+    canvas : HtmlCanvasElement,
+    observer : web_sys::ResizeObserver,
+    _on_resize : Closure< dyn FnMut( js_sys::Array ) >,
+  }
+
+  impl ResponsiveCanvas
+  {
+    /// Starts observing the canvas. The first `on_resize` is invoked as soon as the canvas is laid out.
+    pub fn new< F >( canvas : &HtmlCanvasElement, max_pixel_ratio : f64, mut on_resize : F ) -> Result< Self, Error >
+    where
+      F : FnMut( u32, u32 ) + 'static,
+    {
+      let device_pixels = device_pixel_box_supported();
+      let _canvas = canvas.clone();
+      let on_resize = Closure::wrap( Box::new( move | entries : js_sys::Array |
+      {
+        // Only the canvas is observed, so the last entry is the latest size
+        let Some( entry ) = entries.iter().last() else { return };
+        let entry : web_sys::ResizeObserverEntry = entry.unchecked_into();
+        let ratio = pixel_ratio( max_pixel_ratio );
+        let ( width, height ) = if device_pixels
+        {
+          let size : web_sys::ResizeObserverSize = entry.device_pixel_content_box_size().get( 0 ).unchecked_into();
+          // The box is in device pixels, rescale it when the ratio is clamped
+          let device_ratio = web_sys::window().map( | w | w.device_pixel_ratio() ).unwrap_or( 1.0 );
+          let scale = ratio / device_ratio;
+          ( size.inline_size() * scale, size.block_size() * scale )
+        }
+        else
+        {
+          let rect = entry.content_rect();
+          ( rect.width() * ratio, rect.height() * ratio )
+        };
+        let width = ( width.round() as u32 ).max( 1 );
+        let height = ( height.round() as u32 ).max( 1 );
+
+        if width != _canvas.width() || height != _canvas.height()
+        {
+          _canvas.set_width( width );
+          _canvas.set_height( height );
+          on_resize( width, height );
+        }
+      }) as Box< dyn FnMut( js_sys::Array ) > );
+
+      let observer = web_sys::ResizeObserver::new( on_resize.as_ref().unchecked_ref() )
+      .map_err( | e | Error::BindgenError( "Cant create ResizeObserver", format!( "{:?}", e ) ) )?;
+      if device_pixels
+      {
+        let options = web_sys::ResizeObserverOptions::new();
+        options.set_box( web_sys::ResizeObserverBoxOptions::DevicePixelContentBox );
+        observer.observe_with_options( canvas, &options );
+      }
+      else
+      {
+        observer.observe( canvas );
+      }
+
+      Ok( Self { canvas : canvas.clone(), observer, _on_resize : on_resize } )
+    }
+
+    /// The observed canvas
+    pub fn canvas( &self ) -> &HtmlCanvasElement
+    {
+      &self.canvas
+    }
+
+    /// Current size of the drawing buffer in pixels
+    pub fn size( &self ) -> ( u32, u32 )
+    {
+      ( self.canvas.width(), self.canvas.height() )
+    }
+  }
+
+  impl Drop for ResponsiveCanvas
+  {
+    fn drop( &mut self )
+    {
+      self.observer.disconnect();
+    }
+  }
+
+  impl core::fmt::Debug for ResponsiveCanvas
+  {
+    fn fmt( &self, f : &mut core::fmt::Formatter< '_ > ) -> core::fmt::Result
+    {
+      f.debug_struct( "ResponsiveCanvas" ).field( "size", &self.size() ).finish()
+    }
+  }
+
+  /// Whether the browser reports `devicePixelContentBoxSize` in `ResizeObserverEntry`, Safari doesn't
+  fn device_pixel_box_supported() -> bool
+  {
+    let Some( window ) = web_sys::window() else { return false };
+    js_sys::Reflect::get( &window, &"ResizeObserverEntry".into() )
+    .and_then( | entry | js_sys::Reflect::get( &entry, &"prototype".into() ) )
+    .and_then( | prototype | js_sys::Reflect::has( &prototype, &"devicePixelContentBoxSize".into() ) )
+    .unwrap_or( false )
+  }
+
+  /// Device pixel ratio of the window clamped to `[ 1.0, max ]`
+  pub fn pixel_ratio( max : f64 ) -> f64
+  {
+    let ratio = web_sys::window().map( | w | w.device_pixel_ratio() ).unwrap_or( 1.0 );
+    ratio.min( max ).max( 1.0 )
+  }
+
   // Function to resize the canvas
   fn resize_canvas( canvas: &HtmlCanvasElement, parent: &Element )
   {
@@ -132,6 +260,8 @@ crate::mod_interface!
     retrieve,
     make,
     retrieve_or_make,
+    ResponsiveCanvas,
+    pixel_ratio,
   };
 
 }
@@ -38,6 +38,318 @@ mod private
     request_animation_frame( render_loop.borrow().as_ref().unwrap() );
   }
 
+  /// Settings of the fixed timestep loop, times are in milliseconds.
+  #[ derive( Debug, Clone, Copy, PartialEq ) ]
+  pub struct FixedStep
+  {
+    /// Duration of a single update
+    pub step : f64,
+    /// Longest frame taken into account, longer frames are clamped to it,
+    /// so a hitch doesn't trigger a burst of updates, which makes the next frame even longer
+    pub max_frame_time : f64,
+  }
+
+  impl Default for FixedStep
+  {
+    fn default() -> Self
+    {
+      Self { step : 1000.0 / 60.0, max_frame_time : 250.0 }
+    }
+  }
+
+  /// Timing of the fixed timestep loop, independent of the browser.
+  ///
+  /// Feed it timestamps of frames with `advance` to get the amount of updates to perform.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct FixedClock
+  {
+    config : FixedStep,
+    last : Option< f64 >,
+    accumulator : f64,
+  }
+
+  impl FixedClock
+  {
+    /// Creates a clock, which starts timing on the first frame.
+    ///
+    /// # Panics
+    /// If `step` is not positive or `max_frame_time` is negative, infinite or NaN,
+    /// because the loop would never catch up with the real time.
+    pub fn new( config : FixedStep ) -> Self
+    {
+      assert!( config.step > 0.0 && config.step.is_finite(), "Step must be positive and finite, got {}", config.step );
+      assert!
+      (
+        config.max_frame_time >= 0.0 && config.max_frame_time.is_finite(),
+        "Max frame time must be non-negative and finite, got {}",
+        config.max_frame_time
+      );
+      Self { config, last : None, accumulator : 0.0 }
+    }
+
+    /// Settings of the clock
+    pub fn config( &self ) -> FixedStep
+    {
+      self.config
+    }
+
+    /// Makes the next frame start the timing anew, so the time since the last frame is skipped
+    pub fn restart( &mut self )
+    {
+      self.last = None;
+    }
+
+    /// Advances the clock to the `timestamp` of a frame and returns how many updates to perform.
+    /// While `paused` the time doesn't accumulate and only `pending_steps` updates are performed.
+    pub fn advance( &mut self, timestamp : f64, paused : bool, pending_steps : u32 ) -> u32
+    {
+      let frame_time = self.last.map_or( 0.0, | last | ( timestamp - last ).clamp( 0.0, self.config.max_frame_time ) );
+      self.last = Some( timestamp );
+
+      if paused
+      {
+        return pending_steps;
+      }
+
+      let mut updates = 0;
+      self.accumulator += frame_time;
+      while self.accumulator >= self.config.step
+      {
+        self.accumulator -= self.config.step;
+        updates += 1;
+      }
+      updates
+    }
+
+    /// Fraction of the step elapsed since the last update, in `[ 0, 1 )`
+    pub fn alpha( &self ) -> f64
+    {
+      self.accumulator / self.config.step
+    }
+  }
+
+  /// Listener of `visibilitychange`, removed when dropped.
+  #[ derive( Debug ) ]
+  struct VisibilityListener
+  {
+    document : web_sys::Document,
+    closure : Closure< dyn Fn() >,
+  }
+
+  impl Drop for VisibilityListener
+  {
+    fn drop( &mut self )
+    {
+      use wasm_bindgen::JsCast;
+      let _ = self.document.remove_event_listener_with_callback( "visibilitychange", self.closure.as_ref().unchecked_ref() );
+    }
+  }
+
+  #[ derive( Debug, Default ) ]
+  struct State
+  {
+    paused : bool,
+    stopped : bool,
+    pending_steps : u32,
+    // The next frame starts the timing anew, set after the page becomes visible again
+    restart : bool,
+    visibility : Option< VisibilityListener >,
+  }
+
+  /// Handle to control a running loop. Clones control the same loop.
+  #[ derive( Debug, Clone, Default ) ]
+  pub struct Control
+  {
+    state : Rc< RefCell< State > >,
+  }
+
+  impl Control
+  {
+    /// Stops updating, rendering continues, so the paused state stays on the screen
+    pub fn pause( &self )
+    {
+      self.state.borrow_mut().paused = true;
+    }
+
+    /// Continues updating after `pause`. Time spent in pause is skipped
+    pub fn resume( &self )
+    {
+      let mut state = self.state.borrow_mut();
+      state.paused = false;
+      state.restart = true;
+    }
+
+    /// Performs a single update on the next frame while paused
+    pub fn step( &self )
+    {
+      self.state.borrow_mut().pending_steps += 1;
+    }
+
+    /// Ends the loop on the next frame
+    pub fn stop( &self )
+    {
+      let visibility =
+      {
+        let mut state = self.state.borrow_mut();
+        state.stopped = true;
+        state.visibility.take()
+      };
+      drop( visibility );
+    }
+
+    /// Whether the loop is paused
+    pub fn is_paused( &self ) -> bool
+    {
+      self.state.borrow().paused
+    }
+
+    /// Whether the loop was stopped
+    pub fn is_stopped( &self ) -> bool
+    {
+      self.state.borrow().stopped
+    }
+  }
+
+  /// Runs the loop with updates at a fixed rate and rendering at the display rate.
+  ///
+  /// `update` receives the duration of the step in milliseconds and is called as many times as needed to catch up with the real time.
+  /// `render` receives the fraction of the step elapsed since the last update, in `[ 0, 1 )`, to interpolate between the last two states,
+  /// and returns whether the loop should continue.
+  ///
+  /// While the page is hidden browsers don't fire animation frames. When it becomes visible again the elapsed time is skipped
+  /// instead of being simulated. Returns a handle to pause, resume, step and stop the loop.
+  ///
+  /// # Panics
+  /// If the `config` is invalid, see `FixedClock::new`.
+  ///
+  /// ```rust, ignore
+  /// let control = gl::exec_loop::run_fixed( Default::default(), move | dt | world.update( dt ), move | alpha | { world.draw( alpha ); true } );
+  /// ```
+  pub fn run_fixed< U, R >( config : FixedStep, mut update : U, mut render : R ) -> Control
+  where
+    U : 'static + FnMut( f64 ),
+    R : 'static + FnMut( f64 ) -> bool,
+  {
+    let mut clock = FixedClock::new( config );
+    let control = Control::default();
+    restart_on_visible( &control );
+
+    let _control = control.clone();
+    run( move | timestamp |
+    {
+      let ( paused, pending_steps ) =
+      {
+        let mut state = _control.state.borrow_mut();
+        if state.stopped
+        {
+          return false;
+        }
+        if state.restart
+        {
+          state.restart = false;
+          clock.restart();
+        }
+        ( state.paused, core::mem::take( &mut state.pending_steps ) )
+      };
+
+      for _ in 0..clock.advance( timestamp, paused, pending_steps )
+      {
+        update( config.step );
+      }
+
+      let continuing = render( clock.alpha() ) && !_control.is_stopped();
+      if !continuing
+      {
+        _control.stop();
+      }
+      continuing
+    });
+
+    control
+  }
+
+  /// Runs the loop with a variable timestep: `update_and_draw` receives the time since the previous frame in milliseconds,
+  /// clamped by `max_frame_time`, instead of the raw timestamp. Pausing stops calling `update_and_draw`.
+  ///
+  /// # Panics
+  /// If `max_frame_time` is negative, infinite or NaN.
+  pub fn run_variable< F >( max_frame_time : f64, mut update_and_draw : F ) -> Control
+  where
+    F : 'static + FnMut( f64 ) -> bool,
+  {
+    assert!
+    (
+      max_frame_time >= 0.0 && max_frame_time.is_finite(),
+      "Max frame time must be non-negative and finite, got {}",
+      max_frame_time
+    );
+    let control = Control::default();
+    restart_on_visible( &control );
+
+    let _control = control.clone();
+    let mut last : Option< f64 > = None;
+    run( move | timestamp |
+    {
+      let paused =
+      {
+        let mut state = _control.state.borrow_mut();
+        if state.stopped
+        {
+          return false;
+        }
+        if state.restart
+        {
+          state.restart = false;
+          last = None;
+        }
+        state.paused && core::mem::take( &mut state.pending_steps ) == 0
+      };
+
+      let delta = last.map_or( 0.0, | last | ( timestamp - last ).clamp( 0.0, max_frame_time ) );
+      last = Some( timestamp );
+
+      if paused
+      {
+        return true;
+      }
+      let continuing = update_and_draw( delta ) && !_control.is_stopped();
+      if !continuing
+      {
+        _control.stop();
+      }
+      continuing
+    });
+
+    control
+  }
+
+  /// Makes the loop restart its timing when the page becomes visible again.
+  /// The listener is kept in the state of the loop and removed when the loop stops
+  fn restart_on_visible( control : &Control )
+  {
+    use wasm_bindgen::JsCast;
+    let Some( document ) = web_sys::window().and_then( | w | w.document() ) else { return };
+
+    // Holds only a weak reference to the loop, so the state doesn't own itself
+    let state = Rc::downgrade( &control.state );
+    let _document = document.clone();
+    let closure = Closure::wrap( Box::new( move ||
+    {
+      if let Some( state ) = state.upgrade()
+      {
+        if !_document.hidden()
+        {
+          state.borrow_mut().restart = true;
+        }
+      }
+    }) as Box< dyn Fn() > );
+
+    if document.add_event_listener_with_callback( "visibilitychange", closure.as_ref().unchecked_ref() ).is_ok()
+    {
+      control.state.borrow_mut().visibility = Some( VisibilityListener { document, closure } );
+    }
+  }
+
   // Helper function to request animation frame
   pub fn request_animation_frame( f : &Closure< dyn FnMut( f64 ) > )
   {
@@ -54,6 +366,11 @@ crate::mod_interface!
 {
 
   own use run;
+  own use run_fixed;
+  own use run_variable;
+  own use FixedStep;
+  own use FixedClock;
+  own use Control;
   orphan use request_animation_frame;
 
 }
@@ -10,5 +10,7 @@ mod tests
   use super::*;
 
   mod nd_test;
+  #[ cfg( feature = "web" ) ]
+  mod exec_loop_test;
 
 }
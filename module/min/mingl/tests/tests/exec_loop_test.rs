@@ -0,0 +1,80 @@
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::web::exec_loop::{ FixedClock, FixedStep };
+
+fn clock() -> FixedClock
+{
+  FixedClock::new( FixedStep { step : 10.0, max_frame_time : 100.0 } )
+}
+
+#[ test ]
+fn accumulates()
+{
+  let mut clock = clock();
+
+  // The first frame only starts the timing
+  assert_eq!( clock.advance( 1000.0, false, 0 ), 0 );
+  assert_eq!( clock.advance( 1025.0, false, 0 ), 2 );
+  assert!( ( clock.alpha() - 0.5 ).abs() < 1e-9 );
+  assert_eq!( clock.advance( 1030.0, false, 0 ), 1 );
+  assert!( clock.alpha().abs() < 1e-9 );
+}
+
+#[ test ]
+fn pause_and_step()
+{
+  let mut clock = clock();
+  clock.advance( 0.0, false, 0 );
+
+  // Time doesn't accumulate while paused, only requested steps are performed
+  assert_eq!( clock.advance( 50.0, true, 0 ), 0 );
+  assert_eq!( clock.advance( 60.0, true, 1 ), 1 );
+  assert_eq!( clock.advance( 70.0, true, 3 ), 3 );
+  assert!( clock.alpha().abs() < 1e-9 );
+}
+
+#[ test ]
+fn resume()
+{
+  let mut clock = clock();
+  clock.advance( 0.0, false, 0 );
+  clock.advance( 5.0, true, 0 );
+
+  // Resuming restarts the timing, so the time spent in pause is skipped
+  clock.restart();
+  assert_eq!( clock.advance( 95.0, false, 0 ), 0 );
+  assert_eq!( clock.advance( 105.0, false, 0 ), 1 );
+}
+
+#[ test ]
+fn spiral_of_death_clamp()
+{
+  let mut clock = clock();
+  clock.advance( 0.0, false, 0 );
+
+  // A long hitch is clamped to max frame time
+  assert_eq!( clock.advance( 10_000.0, false, 0 ), 10 );
+  // Time going backwards doesn't produce updates
+  assert_eq!( clock.advance( 5_000.0, false, 0 ), 0 );
+}
+
+#[ test ]
+#[ should_panic ]
+fn zero_step()
+{
+  FixedClock::new( FixedStep { step : 0.0, max_frame_time : 100.0 } );
+}
+
+#[ test ]
+#[ should_panic ]
+fn negative_step()
+{
+  FixedClock::new( FixedStep { step : -1.0, max_frame_time : 100.0 } );
+}
+
+#[ test ]
+#[ should_panic ]
+fn infinite_max_frame_time()
+{
+  FixedClock::new( FixedStep { step : 10.0, max_frame_time : f64::INFINITY } );
+}
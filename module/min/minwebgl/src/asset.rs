@@ -0,0 +1,12 @@
+/// Internal namespace.
+mod private
+{
+  // use crate::*;
+}
+
+crate::mod_interface!
+{
+
+  reuse ::mingl::web::asset;
+
+}
@@ -33,6 +33,10 @@ mod private
     DomError( #[ from ] dom::Error ),
     #[ error( "Shader error :: {0}" ) ]
     ShaderError( #[ from ] shader::Error ),
+    #[ error( "Copy error :: {0}" ) ]
+    CopyError( #[ from ] texture::copy::Error ),
+    #[ error( "IBL error :: {0}" ) ]
+    IblError( #[ from ] ibl::Error ),
 
   }
 
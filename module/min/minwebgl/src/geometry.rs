@@ -0,0 +1,249 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Attribute slot of positions, `vec3`.
+  pub const POSITION_SLOT : u32 = 0;
+  /// Attribute slot of normals, `vec3`.
+  pub const NORMAL_SLOT : u32 = 1;
+  /// Attribute slot of texture coordinates, `vec2`.
+  pub const UV_SLOT : u32 = 2;
+
+  /// Vertex data of a triangle mesh on the CPU side.
+  /// Front faces are counter-clockwise.
+  #[ derive( Debug, Clone, Default, PartialEq ) ]
+  pub struct MeshData
+  {
+    /// Positions of vertices
+    pub positions : Vec< [ f32; 3 ] >,
+    /// Normals of vertices
+    pub normals : Vec< [ f32; 3 ] >,
+    /// Texture coordinates of vertices
+    pub uvs : Vec< [ f32; 2 ] >,
+    /// Three indices per triangle
+    pub indices : Vec< u32 >,
+  }
+
+  impl MeshData
+  {
+    /// A single triangle, which covers the whole clip space square `[ -1, 1 ]` with `uv` in `[ 0, 1 ]`.
+    /// Cheaper than a quad for post-processing, because there is no diagonal seam to shade twice.
+    pub fn fullscreen_triangle() -> Self
+    {
+      Self
+      {
+        positions : vec![ [ -1.0, -1.0, 0.0 ], [ 3.0, -1.0, 0.0 ], [ -1.0, 3.0, 0.0 ] ],
+        normals : vec![ [ 0.0, 0.0, 1.0 ]; 3 ],
+        uvs : vec![ [ 0.0, 0.0 ], [ 2.0, 0.0 ], [ 0.0, 2.0 ] ],
+        indices : vec![ 0, 1, 2 ],
+      }
+    }
+
+    /// Quad in the `XY` plane covering `[ -1, 1 ]`, facing `+Z`.
+    pub fn quad() -> Self
+    {
+      Self
+      {
+        positions : vec![ [ -1.0, -1.0, 0.0 ], [ 1.0, -1.0, 0.0 ], [ 1.0, 1.0, 0.0 ], [ -1.0, 1.0, 0.0 ] ],
+        normals : vec![ [ 0.0, 0.0, 1.0 ]; 4 ],
+        uvs : vec![ [ 0.0, 0.0 ], [ 1.0, 0.0 ], [ 1.0, 1.0 ], [ 0.0, 1.0 ] ],
+        indices : vec![ 0, 1, 2, 0, 2, 3 ],
+      }
+    }
+
+    /// Cube covering `[ -1, 1 ]` on every axis, with separate vertices per face, so normals are flat.
+    /// Faces look outside, for a skybox cull front faces instead of back ones.
+    pub fn cube() -> Self
+    {
+      let mut result = Self::default();
+      let normals = [ [ 1.0, 0.0, 0.0 ], [ -1.0, 0.0, 0.0 ], [ 0.0, 1.0, 0.0 ], [ 0.0, -1.0, 0.0 ], [ 0.0, 0.0, 1.0 ], [ 0.0, 0.0, -1.0 ] ];
+
+      for n in normals
+      {
+        // `u` and `v` span the face, so that `u x v = n`
+        let v = if n[ 1 ] == 0.0 { [ 0.0, 1.0, 0.0 ] } else { [ 0.0, 0.0, -n[ 1 ] ] };
+        let u = cross( v, n );
+
+        let base = result.positions.len() as u32;
+        for ( su, sv ) in [ ( -1.0, -1.0 ), ( 1.0, -1.0 ), ( 1.0, 1.0 ), ( -1.0, 1.0 ) ]
+        {
+          result.positions.push( [ 0, 1, 2 ].map( | i | n[ i ] + u[ i ] * su + v[ i ] * sv ) );
+          result.normals.push( n );
+          result.uvs.push( [ ( su + 1.0 ) * 0.5, ( sv + 1.0 ) * 0.5 ] );
+        }
+        result.indices.extend( [ base, base + 1, base + 2, base, base + 2, base + 3 ] );
+      }
+
+      result
+    }
+
+    /// Sphere of radius 1 made of `rings` bands of latitude and `segments` slices of longitude.
+    /// `u` goes around the `Y` axis starting at `+Z`, `v` goes from the south pole to the north one.
+    /// The seam has duplicated vertices, so texture coordinates don't wrap.
+    pub fn uv_sphere( segments : u32, rings : u32 ) -> Self
+    {
+      let segments = segments.max( 3 );
+      let rings = rings.max( 2 );
+      let mut result = Self::default();
+
+      for r in 0..=rings
+      {
+        let theta = std::f32::consts::PI * r as f32 / rings as f32;
+        for s in 0..=segments
+        {
+          let phi = std::f32::consts::TAU * s as f32 / segments as f32;
+          let p = [ theta.sin() * phi.sin(), theta.cos(), theta.sin() * phi.cos() ];
+          result.positions.push( p );
+          result.normals.push( p );
+          result.uvs.push( [ s as f32 / segments as f32, 1.0 - r as f32 / rings as f32 ] );
+        }
+      }
+
+      let row = segments + 1;
+      for r in 0..rings
+      {
+        for s in 0..segments
+        {
+          let a = r * row + s;
+          let b = a + row;
+          let c = b + 1;
+          let d = a + 1;
+          // Triangles touching the poles are degenerate
+          if r != rings - 1
+          {
+            result.indices.extend( [ a, b, c ] );
+          }
+          if r != 0
+          {
+            result.indices.extend( [ a, c, d ] );
+          }
+        }
+      }
+
+      result
+    }
+  }
+
+  fn cross( a : [ f32; 3 ], b : [ f32; 3 ] ) -> [ f32; 3 ]
+  {
+    [
+      a[ 1 ] * b[ 2 ] - a[ 2 ] * b[ 1 ],
+      a[ 2 ] * b[ 0 ] - a[ 0 ] * b[ 2 ],
+      a[ 0 ] * b[ 1 ] - a[ 1 ] * b[ 0 ],
+    ]
+  }
+
+  /// Handle to a mesh uploaded to the GPU.
+  ///
+  /// Attributes are bound to `POSITION_SLOT`, `NORMAL_SLOT` and `UV_SLOT`,
+  /// indices are `u32`. Owns its buffers, call `delete` to free them.
+  #[ derive( Debug, Clone ) ]
+  pub struct Mesh
+  {
+    /// Vertex array object with all the attributes and the index buffer bound
+    pub vao : WebGlVertexArrayObject,
+    /// Buffers of positions, normals and texture coordinates
+    pub buffers : [ WebGlBuffer; 3 ],
+    /// Buffer of indices
+    pub index_buffer : WebGlBuffer,
+    /// Amount of indices
+    pub count : i32,
+  }
+
+  impl Mesh
+  {
+    /// Uploads the mesh data to the GPU and configures a vertex array object for it.
+    /// Leaves the created vertex array object bound.
+    pub fn upload( gl : &GL, data : &MeshData ) -> Result< Self, WebglError >
+    {
+      let buffers = [ buffer::create( gl )?, buffer::create( gl )?, buffer::create( gl )? ];
+      let index_buffer = buffer::create( gl )?;
+      buffer::upload( gl, &buffers[ 0 ], &data.positions, GL::STATIC_DRAW );
+      buffer::upload( gl, &buffers[ 1 ], &data.normals, GL::STATIC_DRAW );
+      buffer::upload( gl, &buffers[ 2 ], &data.uvs, GL::STATIC_DRAW );
+
+      let vao = vao::create( gl )?;
+      gl.bind_vertex_array( Some( &vao ) );
+      BufferDescriptor::new::< [ f32; 3 ] >().attribute_pointer( gl, POSITION_SLOT, &buffers[ 0 ] )?;
+      BufferDescriptor::new::< [ f32; 3 ] >().attribute_pointer( gl, NORMAL_SLOT, &buffers[ 1 ] )?;
+      BufferDescriptor::new::< [ f32; 2 ] >().attribute_pointer( gl, UV_SLOT, &buffers[ 2 ] )?;
+      // Element array binding is a part of the vertex array object state
+      index::upload( gl, &index_buffer, &data.indices, GL::STATIC_DRAW );
+
+      Ok( Self { vao, buffers, index_buffer, count : data.indices.len() as i32 } )
+    }
+
+    /// Binds the vertex array object and draws the triangles with the current program
+    pub fn draw( &self, gl : &GL )
+    {
+      gl.bind_vertex_array( Some( &self.vao ) );
+      gl.draw_elements_with_i32( GL::TRIANGLES, self.count, GL::UNSIGNED_INT, 0 );
+    }
+
+    /// Draws `instances` copies of the mesh
+    pub fn draw_instanced( &self, gl : &GL, instances : i32 )
+    {
+      gl.bind_vertex_array( Some( &self.vao ) );
+      gl.draw_elements_instanced_with_i32( GL::TRIANGLES, self.count, GL::UNSIGNED_INT, 0, instances );
+    }
+
+    /// Frees the GPU resources of the mesh
+    pub fn delete( self, gl : &GL )
+    {
+      gl.delete_vertex_array( Some( &self.vao ) );
+      for buffer in &self.buffers
+      {
+        gl.delete_buffer( Some( buffer ) );
+      }
+      gl.delete_buffer( Some( &self.index_buffer ) );
+    }
+  }
+
+  /// Uploads `MeshData::fullscreen_triangle`. Draw it with depth test disabled for post-processing passes.
+  pub fn fullscreen_triangle( gl : &GL ) -> Result< Mesh, WebglError >
+  {
+    Mesh::upload( gl, &MeshData::fullscreen_triangle() )
+  }
+
+  /// Uploads `MeshData::quad`.
+  pub fn quad( gl : &GL ) -> Result< Mesh, WebglError >
+  {
+    Mesh::upload( gl, &MeshData::quad() )
+  }
+
+  /// Uploads `MeshData::cube`.
+  pub fn cube( gl : &GL ) -> Result< Mesh, WebglError >
+  {
+    Mesh::upload( gl, &MeshData::cube() )
+  }
+
+  /// Uploads `MeshData::uv_sphere`.
+  pub fn uv_sphere( gl : &GL, segments : u32, rings : u32 ) -> Result< Mesh, WebglError >
+  {
+    Mesh::upload( gl, &MeshData::uv_sphere( segments, rings ) )
+  }
+
+}
+
+crate::mod_interface!
+{
+
+  orphan use
+  {
+    POSITION_SLOT,
+    NORMAL_SLOT,
+    UV_SLOT,
+    MeshData,
+    Mesh,
+  };
+
+  own use
+  {
+    fullscreen_triangle,
+    quad,
+    cube,
+    uv_sphere,
+  };
+
+}
@@ -0,0 +1,615 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use web_sys::{ WebGlFramebuffer, WebGlProgram, WebGlTexture };
+
+  /// Represents errors of image based lighting preparation.
+  #[ derive( Debug, error::typed::Error ) ]
+  pub enum Error
+  {
+    /// Data is not a valid Radiance `.hdr` image.
+    #[ error( "Invalid HDR image : {0}" ) ]
+    InvalidHdr( &'static str ),
+    /// Rendering into float textures needs `EXT_color_buffer_float`.
+    #[ error( "EXT_color_buffer_float is not supported" ) ]
+    NoColorBufferFloat,
+    /// Failed to create a texture or a framebuffer.
+    #[ error( "Failed to create {0}" ) ]
+    FailedToCreate( &'static str ),
+  }
+
+  /// Largest width or height of an HDR image accepted by `HdrImage::decode`, larger than any GPU can hold in a texture.
+  pub const MAX_HDR_SIZE : u32 = 32768;
+
+  /// Decoded Radiance `.hdr` image.
+  #[ derive( Debug, Clone, PartialEq ) ]
+  pub struct HdrImage
+  {
+    /// Width in pixels
+    pub width : u32,
+    /// Height in pixels
+    pub height : u32,
+    /// Linear `RGB` of pixels, rows go from top to bottom
+    pub data : Vec< f32 >,
+  }
+
+  impl HdrImage
+  {
+    /// Decodes a Radiance `.hdr` image in `RGBE` format, either flat or run length encoded.
+    /// Only the standard `-Y height +X width` orientation is supported.
+    /// Images larger than `MAX_HDR_SIZE` on any side are rejected.
+    pub fn decode( bytes : &[ u8 ] ) -> Result< Self, Error >
+    {
+      let mut position = 0;
+
+      if !header_line( bytes, &mut position )?.starts_with( "#?" )
+      {
+        return Err( Error::InvalidHdr( "Missing signature" ) );
+      }
+      loop
+      {
+        let header = header_line( bytes, &mut position )?;
+        if header.is_empty()
+        {
+          break;
+        }
+        if header.starts_with( "FORMAT=" ) && header != "FORMAT=32-bit_rle_rgbe"
+        {
+          return Err( Error::InvalidHdr( "Only RGBE format is supported" ) );
+        }
+      }
+
+      let resolution : Vec< &str > = header_line( bytes, &mut position )?.split_whitespace().collect();
+      let ( height, width ) = match resolution.as_slice()
+      {
+        [ "-Y", h, "+X", w ] => ( h.parse::< u32 >(), w.parse::< u32 >() ),
+        _ => return Err( Error::InvalidHdr( "Unsupported orientation" ) ),
+      };
+      let ( height, width ) = match ( height, width )
+      {
+        ( Ok( h ), Ok( w ) ) if ( 1..=MAX_HDR_SIZE ).contains( &h ) && ( 1..=MAX_HDR_SIZE ).contains( &w ) => ( h, w ),
+        _ => return Err( Error::InvalidHdr( "Invalid resolution" ) ),
+      };
+
+      let len = ( width as usize ).checked_mul( height as usize ).and_then( | n | n.checked_mul( 3 ) )
+      .ok_or( Error::InvalidHdr( "Resolution is too large" ) )?;
+      // Even with run length encoding every 128 pixels take at least a byte, so a short file can't claim a huge image
+      if bytes.len() - position < ( width as usize ) * ( height as usize ) / 128
+      {
+        return Err( Error::InvalidHdr( "Unexpected end of data" ) );
+      }
+      let mut data = Vec::with_capacity( len );
+      let mut scanline = vec![ 0u8; width as usize * 4 ];
+      let mut bytes = &bytes[ position.. ];
+      for _ in 0..height
+      {
+        bytes = read_scanline( bytes, &mut scanline )?;
+        for rgbe in scanline.chunks_exact( 4 )
+        {
+          let scale = if rgbe[ 3 ] == 0 { 0.0 } else { 2.0f32.powi( rgbe[ 3 ] as i32 - 136 ) };
+          data.extend( [ rgbe[ 0 ], rgbe[ 1 ], rgbe[ 2 ] ].map( | c | ( c as f32 + 0.5 ) * scale ) );
+        }
+      }
+
+      Ok( Self { width, height, data } )
+    }
+  }
+
+  /// Reads a line of the header at `position` and moves past it
+  fn header_line< 'a >( bytes : &'a [ u8 ], position : &mut usize ) -> Result< &'a str, Error >
+  {
+    let rest = bytes.get( *position.. ).ok_or( Error::InvalidHdr( "Unexpected end of header" ) )?;
+    let end = rest.iter().position( | &b | b == b'\n' ).ok_or( Error::InvalidHdr( "Unexpected end of header" ) )?;
+    *position += end + 1;
+    std::str::from_utf8( &rest[ ..end ] ).map_err( | _ | Error::InvalidHdr( "Header is not a text" ) )
+  }
+
+  /// Reads a scanline of `RGBE` pixels and returns the rest of data
+  fn read_scanline< 'a >( bytes : &'a [ u8 ], scanline : &mut [ u8 ] ) -> Result< &'a [ u8 ], Error >
+  {
+    let width = scanline.len() / 4;
+    let rle = ( 8..0x8000 ).contains( &width )
+    && bytes.len() >= 4 && bytes[ 0 ] == 2 && bytes[ 1 ] == 2
+    && ( ( bytes[ 2 ] as usize ) << 8 | bytes[ 3 ] as usize ) == width;
+
+    if !rle
+    {
+      let flat = bytes.get( ..scanline.len() ).ok_or( Error::InvalidHdr( "Unexpected end of data" ) )?;
+      scanline.copy_from_slice( flat );
+      return Ok( &bytes[ scanline.len().. ] );
+    }
+
+    // Channels are stored one after another, each as runs and literals
+    let mut bytes = &bytes[ 4.. ];
+    for channel in 0..4
+    {
+      let mut x = 0;
+      while x < width
+      {
+        let [ count, rest @ .. ] = bytes else { return Err( Error::InvalidHdr( "Unexpected end of data" ) ) };
+        let ( count, run ) = if *count > 128 { ( ( *count - 128 ) as usize, true ) } else { ( *count as usize, false ) };
+        if count == 0 || x + count > width
+        {
+          return Err( Error::InvalidHdr( "Invalid run length" ) );
+        }
+        let used = if run { 1 } else { count };
+        let values = rest.get( ..used ).ok_or( Error::InvalidHdr( "Unexpected end of data" ) )?;
+        for i in 0..count
+        {
+          scanline[ ( x + i ) * 4 + channel ] = if run { values[ 0 ] } else { values[ i ] };
+        }
+        x += count;
+        bytes = &rest[ used.. ];
+      }
+    }
+    Ok( bytes )
+  }
+
+  /// Sizes and quality of the prefiltered maps.
+  #[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+  pub struct Options
+  {
+    /// Size of faces of the environment cube map
+    pub environment_size : i32,
+    /// Size of faces of the irradiance cube map
+    pub irradiance_size : i32,
+    /// Size of faces of the base level of the specular cube map
+    pub specular_size : i32,
+    /// Amount of mip levels of the specular cube map, roughness goes from 0 to 1 across them
+    pub specular_levels : i32,
+    /// Size of the BRDF lookup table
+    pub brdf_size : i32,
+    /// Amount of samples per pixel of the specular convolution and the BRDF integration
+    pub sample_count : i32,
+  }
+
+  impl Default for Options
+  {
+    fn default() -> Self
+    {
+      Self
+      {
+        environment_size : 512,
+        irradiance_size : 32,
+        specular_size : 128,
+        specular_levels : 5,
+        brdf_size : 256,
+        sample_count : 512,
+      }
+    }
+  }
+
+  /// Maps of image based lighting, ready to be sampled by a PBR shader.
+  #[ derive( Debug, Clone ) ]
+  pub struct Ibl
+  {
+    /// Environment as a mipmapped `RGBA16F` cube map, to draw as a skybox
+    pub environment : WebGlTexture,
+    /// Diffuse irradiance, `RGBA16F` cube map
+    pub irradiance : WebGlTexture,
+    /// Prefiltered specular radiance, `RGBA16F` cube map with roughness `level / ( specular_levels - 1 )` at each level
+    pub specular : WebGlTexture,
+    /// Amount of mip levels of `specular`
+    pub specular_levels : i32,
+    /// Scale and bias of `F0` by `( NdotV, roughness )`, `RG16F` 2D texture
+    pub brdf_lut : WebGlTexture,
+  }
+
+  impl Ibl
+  {
+    /// Frees the textures
+    pub fn delete( self, gl : &GL )
+    {
+      for texture in [ &self.environment, &self.irradiance, &self.specular, &self.brdf_lut ]
+      {
+        gl.delete_texture( Some( texture ) );
+      }
+    }
+  }
+
+  /// Prepares image based lighting from an equirectangular HDR image on the GPU.
+  ///
+  /// The image is projected onto a cube map, which is convolved into the irradiance map and
+  /// into the specular mip chain with GGX importance sampling, and the BRDF lookup table is integrated.
+  /// Needs `EXT_color_buffer_float`, which it enables.
+  /// Changes the viewport and the bound program, vertex array, framebuffer and textures.
+  ///
+  /// ```rust, ignore
+  /// let bytes = gl::file::load( "environment.hdr" ).await?;
+  /// let image = gl::ibl::HdrImage::decode( &bytes )?;
+  /// let ibl = gl::ibl::prefilter( &gl, &image, &Default::default() )?;
+  /// ```
+  pub fn prefilter( gl : &GL, image : &HdrImage, options : &Options ) -> Result< Ibl, WebglError >
+  {
+    gl.get_extension( "EXT_color_buffer_float" ).ok().flatten().ok_or( Error::NoColorBufferFloat )?;
+
+    let mut scratch = Scratch { gl : Some( gl ), .. Default::default() };
+    let equirect = upload_equirect( gl, image, &mut scratch )?;
+    let framebuffer = gl.create_framebuffer().ok_or( Error::FailedToCreate( "framebuffer" ) )?;
+    scratch.framebuffer = Some( framebuffer.clone() );
+    let vao = vao::create( gl )?;
+    scratch.vao = Some( vao.clone() );
+    gl.bind_vertex_array( Some( &vao ) );
+    gl.bind_framebuffer( GL::FRAMEBUFFER, Some( &framebuffer ) );
+    gl.disable( GL::DEPTH_TEST );
+    gl.disable( GL::BLEND );
+
+    let environment_levels = levels( options.environment_size );
+    let environment = cube( gl, options.environment_size, environment_levels, &mut scratch )?;
+    let program = scratch.program( EQUIRECT_SHADER )?;
+    gl.active_texture( GL::TEXTURE0 );
+    gl.bind_texture( GL::TEXTURE_2D, Some( &equirect ) );
+    uniform::upload( gl, gl.get_uniform_location( &program, "source" ), &0i32 )?;
+    render_cube( gl, &program, &environment, options.environment_size, 0 );
+    gl.bind_texture( GL::TEXTURE_CUBE_MAP, Some( &environment ) );
+    gl.generate_mipmap( GL::TEXTURE_CUBE_MAP );
+
+    let irradiance = cube( gl, options.irradiance_size, 1, &mut scratch )?;
+    let program = scratch.program( IRRADIANCE_SHADER )?;
+    uniform::upload( gl, gl.get_uniform_location( &program, "source" ), &0i32 )?;
+    // Irradiance is smooth, so a small level is enough and keeps the convolution cheap
+    let level = ( environment_levels - 6 ).max( 0 ) as f32;
+    uniform::upload( gl, gl.get_uniform_location( &program, "level" ), &level )?;
+    render_cube( gl, &program, &irradiance, options.irradiance_size, 0 );
+
+    let specular_levels = options.specular_levels.clamp( 1, levels( options.specular_size ) );
+    let specular = cube( gl, options.specular_size, specular_levels, &mut scratch )?;
+    let program = scratch.program( SPECULAR_SHADER )?;
+    gl.bind_texture( GL::TEXTURE_CUBE_MAP, Some( &environment ) );
+    uniform::upload( gl, gl.get_uniform_location( &program, "source" ), &0i32 )?;
+    uniform::upload( gl, gl.get_uniform_location( &program, "sourceSize" ), &( options.environment_size as f32 ) )?;
+    uniform::upload( gl, gl.get_uniform_location( &program, "sampleCount" ), &options.sample_count )?;
+    for level in 0..specular_levels
+    {
+      let roughness = if specular_levels > 1 { level as f32 / ( specular_levels - 1 ) as f32 } else { 0.0 };
+      uniform::upload( gl, gl.get_uniform_location( &program, "roughness" ), &roughness )?;
+      render_cube( gl, &program, &specular, ( options.specular_size >> level ).max( 1 ), level );
+    }
+
+    let brdf_lut = gl.create_texture().ok_or( Error::FailedToCreate( "texture" ) )?;
+    scratch.targets.push( brdf_lut.clone() );
+    gl.bind_texture( GL::TEXTURE_2D, Some( &brdf_lut ) );
+    gl.tex_storage_2d( GL::TEXTURE_2D, 1, GL::RG16F, options.brdf_size, options.brdf_size );
+    texture::d2::filter_linear( gl );
+    texture::d2::wrap_clamp( gl );
+    let program = scratch.program( BRDF_SHADER )?;
+    uniform::upload( gl, gl.get_uniform_location( &program, "sampleCount" ), &options.sample_count )?;
+    gl.framebuffer_texture_2d( GL::FRAMEBUFFER, GL::COLOR_ATTACHMENT0, GL::TEXTURE_2D, Some( &brdf_lut ), 0 );
+    gl.viewport( 0, 0, options.brdf_size, options.brdf_size );
+    gl.draw_arrays( GL::TRIANGLES, 0, 3 );
+
+    // The maps are the result, everything else is freed by the scratch
+    scratch.targets.clear();
+    Ok( Ibl { environment, irradiance, specular, specular_levels, brdf_lut } )
+  }
+
+  /// GPU objects created by `prefilter`, freed when dropped.
+  /// Render targets are freed too, unless they're taken out of `targets`, so nothing leaks on an error.
+  #[ derive( Default ) ]
+  struct Scratch< 'a >
+  {
+    gl : Option< &'a GL >,
+    textures : Vec< WebGlTexture >,
+    targets : Vec< WebGlTexture >,
+    programs : Vec< WebGlProgram >,
+    framebuffer : Option< WebGlFramebuffer >,
+    vao : Option< WebGlVertexArrayObject >,
+  }
+
+  impl Scratch< '_ >
+  {
+    /// Compiles a program with the fragment shader `body` and makes it current
+    fn program( &mut self, body : &str ) -> Result< WebGlProgram, WebglError >
+    {
+      let gl = self.gl.expect( "Scratch is created with a context" );
+      let program = ProgramFromSources::new( VERTEX_SHADER, &format!( "{}{}", FRAGMENT_COMMON, body ) ).compile_and_link( gl )?;
+      self.programs.push( program.clone() );
+      gl.use_program( Some( &program ) );
+      Ok( program )
+    }
+  }
+
+  impl Drop for Scratch< '_ >
+  {
+    fn drop( &mut self )
+    {
+      let Some( gl ) = self.gl else { return };
+      gl.bind_framebuffer( GL::FRAMEBUFFER, None );
+      gl.bind_vertex_array( None );
+      gl.use_program( None );
+      gl.delete_framebuffer( self.framebuffer.as_ref() );
+      gl.delete_vertex_array( self.vao.as_ref() );
+      for program in &self.programs
+      {
+        gl.delete_program( Some( program ) );
+      }
+      for texture in self.textures.iter().chain( &self.targets )
+      {
+        gl.delete_texture( Some( texture ) );
+      }
+    }
+  }
+
+  /// Amount of mip levels down to 1x1
+  fn levels( size : i32 ) -> i32
+  {
+    32 - ( size.max( 1 ) as u32 ).leading_zeros() as i32
+  }
+
+  fn upload_equirect( gl : &GL, image : &HdrImage, scratch : &mut Scratch< '_ > ) -> Result< WebGlTexture, WebglError >
+  {
+    let texture = gl.create_texture().ok_or( Error::FailedToCreate( "texture" ) )?;
+    scratch.textures.push( texture.clone() );
+    gl.bind_texture( GL::TEXTURE_2D, Some( &texture ) );
+    gl.pixel_storei( GL::UNPACK_FLIP_Y_WEBGL, 0 );
+    gl.pixel_storei( GL::UNPACK_ALIGNMENT, 4 );
+    let data = js_sys::Float32Array::from( image.data.as_slice() );
+    gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_array_buffer_view
+    (
+      GL::TEXTURE_2D,
+      0,
+      GL::RGB16F as i32,
+      image.width as i32,
+      image.height as i32,
+      0,
+      GL::RGB,
+      GL::FLOAT,
+      Some( &data ),
+    )
+    .map_err( | _ | Error::FailedToCreate( "equirectangular texture" ) )?;
+    texture::d2::filter_linear( gl );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_WRAP_S, GL::REPEAT as i32 );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_WRAP_T, GL::CLAMP_TO_EDGE as i32 );
+    Ok( texture )
+  }
+
+  fn cube( gl : &GL, size : i32, levels : i32, scratch : &mut Scratch< '_ > ) -> Result< WebGlTexture, Error >
+  {
+    let texture = gl.create_texture().ok_or( Error::FailedToCreate( "texture" ) )?;
+    scratch.targets.push( texture.clone() );
+    // The source is bound to the unit 0, render targets go to another one to not replace it
+    gl.active_texture( GL::TEXTURE1 );
+    gl.bind_texture( GL::TEXTURE_CUBE_MAP, Some( &texture ) );
+    gl.tex_storage_2d( GL::TEXTURE_CUBE_MAP, levels, GL::RGBA16F, size, size );
+    let min_filter = if levels > 1 { GL::LINEAR_MIPMAP_LINEAR } else { GL::LINEAR };
+    gl.tex_parameteri( GL::TEXTURE_CUBE_MAP, GL::TEXTURE_MIN_FILTER, min_filter as i32 );
+    gl.tex_parameteri( GL::TEXTURE_CUBE_MAP, GL::TEXTURE_MAG_FILTER, GL::LINEAR as i32 );
+    for wrap in [ GL::TEXTURE_WRAP_S, GL::TEXTURE_WRAP_T, GL::TEXTURE_WRAP_R ]
+    {
+      gl.tex_parameteri( GL::TEXTURE_CUBE_MAP, wrap, GL::CLAMP_TO_EDGE as i32 );
+    }
+    gl.active_texture( GL::TEXTURE0 );
+    Ok( texture )
+  }
+
+  /// Renders every face of a level of the cube map with the program, which gets the face in `face` uniform
+  fn render_cube( gl : &GL, program : &WebGlProgram, target : &WebGlTexture, size : i32, level : i32 )
+  {
+    let face_location = gl.get_uniform_location( program, "face" );
+    gl.viewport( 0, 0, size, size );
+    for face in 0..6
+    {
+      gl.framebuffer_texture_2d
+      (
+        GL::FRAMEBUFFER,
+        GL::COLOR_ATTACHMENT0,
+        GL::TEXTURE_CUBE_MAP_POSITIVE_X + face,
+        Some( target ),
+        level
+      );
+      gl.uniform1i( face_location.as_ref(), face as i32 );
+      gl.draw_arrays( GL::TRIANGLES, 0, 3 );
+    }
+  }
+
+  /// Fullscreen triangle without attributes, draw it with `draw_arrays( TRIANGLES, 0, 3 )` and any vertex array bound.
+  /// `vUv` covers `[ 0, 1 ]` over the viewport.
+  const VERTEX_SHADER : &str = r#"#version 300 es
+out vec2 vUv;
+void main()
+{
+  vec2 p = vec2( ( gl_VertexID << 1 ) & 2, gl_VertexID & 2 );
+  vUv = p;
+  gl_Position = vec4( p * 2.0 - 1.0, 0.0, 1.0 );
+}
+"#;
+
+  /// Prelude of every fragment shader: cube face directions, Hammersley sequence and GGX importance sampling.
+  /// `direction` follows the cube map face conventions, so the framebuffer row 0 is the face row 0.
+  const FRAGMENT_COMMON : &str = r#"#version 300 es
+precision highp float;
+in vec2 vUv;
+out vec4 frag_color;
+uniform int face;
+const float PI = 3.14159265359;
+
+// Direction through the pixel of the face, following the cube map conventions
+vec3 direction()
+{
+  vec2 p = vUv * 2.0 - 1.0;
+  vec3 d;
+  if( face == 0 ) d = vec3( 1.0, -p.y, -p.x );
+  else if( face == 1 ) d = vec3( -1.0, -p.y, p.x );
+  else if( face == 2 ) d = vec3( p.x, 1.0, p.y );
+  else if( face == 3 ) d = vec3( p.x, -1.0, -p.y );
+  else if( face == 4 ) d = vec3( p.x, -p.y, 1.0 );
+  else d = vec3( -p.x, -p.y, -1.0 );
+  return normalize( d );
+}
+
+vec2 hammersley( uint i, uint n )
+{
+  uint bits = i;
+  bits = ( bits << 16u ) | ( bits >> 16u );
+  bits = ( ( bits & 0x55555555u ) << 1u ) | ( ( bits & 0xAAAAAAAAu ) >> 1u );
+  bits = ( ( bits & 0x33333333u ) << 2u ) | ( ( bits & 0xCCCCCCCCu ) >> 2u );
+  bits = ( ( bits & 0x0F0F0F0Fu ) << 4u ) | ( ( bits & 0xF0F0F0F0u ) >> 4u );
+  bits = ( ( bits & 0x00FF00FFu ) << 8u ) | ( ( bits & 0xFF00FF00u ) >> 8u );
+  return vec2( float( i ) / float( n ), float( bits ) * 2.3283064365386963e-10 );
+}
+
+vec3 importance_sample_ggx( vec2 xi, vec3 n, float roughness )
+{
+  float a = roughness * roughness;
+  float phi = 2.0 * PI * xi.x;
+  float cos_theta = sqrt( ( 1.0 - xi.y ) / ( 1.0 + ( a * a - 1.0 ) * xi.y ) );
+  float sin_theta = sqrt( 1.0 - cos_theta * cos_theta );
+  vec3 h = vec3( cos( phi ) * sin_theta, sin( phi ) * sin_theta, cos_theta );
+  vec3 up = abs( n.z ) < 0.999 ? vec3( 0.0, 0.0, 1.0 ) : vec3( 1.0, 0.0, 0.0 );
+  vec3 tangent = normalize( cross( up, n ) );
+  vec3 bitangent = cross( n, tangent );
+  return normalize( tangent * h.x + bitangent * h.y + n * h.z );
+}
+"#;
+
+  /// Projects the equirectangular `source` onto the cube face `face`.
+  /// The `+Y` axis maps to the top row of the image, and `u = 0.5` looks at `+X`.
+  const EQUIRECT_SHADER : &str = r#"
+uniform sampler2D source;
+void main()
+{
+  vec3 d = direction();
+  vec2 uv = vec2( atan( d.z, d.x ) / ( 2.0 * PI ) + 0.5, acos( clamp( d.y, -1.0, 1.0 ) ) / PI );
+  frag_color = vec4( texture( source, uv ).rgb, 1.0 );
+}
+"#;
+
+  /// Cosine weighted convolution of the hemisphere around each direction of the cube map `source`.
+  /// Uses a fixed grid of about 16k samples, so `level` should point to a small mip of the source to avoid aliasing
+  /// and the output should be small, 32 is plenty.
+  const IRRADIANCE_SHADER : &str = r#"
+uniform samplerCube source;
+uniform float level;
+void main()
+{
+  vec3 n = direction();
+  vec3 up = abs( n.y ) < 0.999 ? vec3( 0.0, 1.0, 0.0 ) : vec3( 0.0, 0.0, 1.0 );
+  vec3 right = normalize( cross( up, n ) );
+  up = cross( n, right );
+
+  vec3 irradiance = vec3( 0.0 );
+  float count = 0.0;
+  const float delta = 0.025;
+  for( float phi = 0.0; phi < 2.0 * PI; phi += delta )
+  {
+    for( float theta = 0.0; theta < 0.5 * PI; theta += delta )
+    {
+      vec3 t = vec3( sin( theta ) * cos( phi ), sin( theta ) * sin( phi ), cos( theta ) );
+      vec3 d = t.x * right + t.y * up + t.z * n;
+      irradiance += textureLod( source, d, level ).rgb * cos( theta ) * sin( theta );
+      count += 1.0;
+    }
+  }
+  frag_color = vec4( PI * irradiance / count, 1.0 );
+}
+"#;
+
+  /// Prefilters the cube map `source` with the GGX lobe of `roughness` by importance sampling, assuming the view equals the normal.
+  /// Picks the source mip by the density of samples, so `source` must have a full mip chain and `sourceSize` its base size.
+  /// Roughness 0 is a plain copy.
+  const SPECULAR_SHADER : &str = r#"
+uniform samplerCube source;
+uniform float sourceSize;
+uniform float roughness;
+uniform int sampleCount;
+
+float distribution_ggx( float n_dot_h, float roughness )
+{
+  float a = roughness * roughness;
+  float a2 = a * a;
+  float d = n_dot_h * n_dot_h * ( a2 - 1.0 ) + 1.0;
+  return a2 / ( PI * d * d );
+}
+
+void main()
+{
+  vec3 n = direction();
+  if( roughness == 0.0 )
+  {
+    frag_color = vec4( textureLod( source, n, 0.0 ).rgb, 1.0 );
+    return;
+  }
+
+  // View direction is assumed to be equal to the normal
+  vec3 color = vec3( 0.0 );
+  float weight = 0.0;
+  uint count = uint( sampleCount );
+  for( uint i = 0u; i < count; i++ )
+  {
+    vec3 h = importance_sample_ggx( hammersley( i, count ), n, roughness );
+    vec3 l = normalize( 2.0 * dot( n, h ) * h - n );
+    float n_dot_l = dot( n, l );
+    if( n_dot_l > 0.0 )
+    {
+      // Sample a blurrier level for rare directions to avoid bright dots
+      float n_dot_h = max( dot( n, h ), 0.0 );
+      float pdf = distribution_ggx( n_dot_h, roughness ) / 4.0 + 0.0001;
+      float texel = 4.0 * PI / ( 6.0 * sourceSize * sourceSize );
+      float sample_angle = 1.0 / ( float( count ) * pdf + 0.0001 );
+      float level = 0.5 * log2( sample_angle / texel );
+      color += textureLod( source, l, max( level, 0.0 ) ).rgb * n_dot_l;
+      weight += n_dot_l;
+    }
+  }
+  frag_color = vec4( color / max( weight, 0.0001 ), 1.0 );
+}
+"#;
+
+  /// Integrates the split-sum scale and bias of `F0` for `NdotV` along `x` and roughness along `y`.
+  /// Uses `k = roughness^2 / 2` of Smith-Schlick geometry for image based lighting, not the analytic lights one.
+  const BRDF_SHADER : &str = r#"
+uniform int sampleCount;
+
+float geometry_schlick_ggx( float n_dot_v, float roughness )
+{
+  float k = roughness * roughness / 2.0;
+  return n_dot_v / ( n_dot_v * ( 1.0 - k ) + k );
+}
+
+void main()
+{
+  float n_dot_v = max( vUv.x, 0.001 );
+  float roughness = vUv.y;
+  vec3 v = vec3( sqrt( 1.0 - n_dot_v * n_dot_v ), 0.0, n_dot_v );
+  vec3 n = vec3( 0.0, 0.0, 1.0 );
+
+  float scale = 0.0;
+  float bias = 0.0;
+  uint count = uint( sampleCount );
+  for( uint i = 0u; i < count; i++ )
+  {
+    vec3 h = importance_sample_ggx( hammersley( i, count ), n, roughness );
+    vec3 l = normalize( 2.0 * dot( v, h ) * h - v );
+    float n_dot_l = max( l.z, 0.0 );
+    float n_dot_h = max( h.z, 0.0 );
+    float v_dot_h = max( dot( v, h ), 0.0 );
+    if( n_dot_l > 0.0 )
+    {
+      float g = geometry_schlick_ggx( n_dot_v, roughness ) * geometry_schlick_ggx( n_dot_l, roughness );
+      float g_vis = g * v_dot_h / ( n_dot_h * n_dot_v );
+      float fc = pow( 1.0 - v_dot_h, 5.0 );
+      scale += ( 1.0 - fc ) * g_vis;
+      bias += fc * g_vis;
+    }
+  }
+  frag_color = vec4( scale / float( count ), bias / float( count ), 0.0, 1.0 );
+}
+"#;
+
+}
+
+crate::mod_interface!
+{
+
+  own use
+  {
+    Error,
+    MAX_HDR_SIZE,
+    HdrImage,
+    Options,
+    Ibl,
+    prefilter,
+  };
+
+}
@@ -40,6 +40,10 @@ mod_interface!
   layer dom;
   /// Loop-related.
   layer exec_loop;
+  /// Ready-made meshes: fullscreen triangle, quad, cube and sphere.
+  layer geometry;
+  /// Image based lighting prefiltered on the GPU from a single HDR image.
+  layer ibl;
 
   /// Vertex indices.
   layer index;
@@ -80,6 +84,10 @@ mod_interface!
   #[ cfg( all( feature = "future", feature = "file" ) ) ]
   layer file;
 
+  /// Loading and caching of assets.
+  #[ cfg( feature = "future" ) ]
+  layer asset;
+
   /// Future processing.
   #[ cfg( feature = "future" ) ]
   layer future;
@@ -3,12 +3,20 @@ mod private
   
 }
 
+pub mod copy;
 pub mod d2;
+pub mod d3;
+pub mod unit;
+pub mod video;
 
 crate::mod_interface!
 {
   own use 
   {
+    copy,
     d2,
+    d3,
+    unit,
+    video,
   };
 }
\ No newline at end of file
@@ -0,0 +1,284 @@
+use crate::*;
+use web_sys::{ WebGlFramebuffer, WebGlTexture };
+
+/// Represents errors of copying between textures and framebuffers.
+#[ derive( Debug, error::typed::Error ) ]
+pub enum Error
+{
+  /// Filter is neither `NEAREST` nor `LINEAR`.
+  #[ error( "Filter must be NEAREST or LINEAR, got {0}" ) ]
+  InvalidFilter( u32 ),
+  /// Depth and stencil can be blitted only with `NEAREST` filter.
+  #[ error( "Depth and stencil buffers can be blitted only with NEAREST filter" ) ]
+  LinearDepthStencil,
+  /// Mask contains bits other than color, depth and stencil.
+  #[ error( "Mask must be a combination of COLOR_BUFFER_BIT, DEPTH_BUFFER_BIT and STENCIL_BUFFER_BIT, got {0}" ) ]
+  InvalidMask( u32 ),
+  /// Target of a texture image is not supported.
+  #[ error( "Unsupported texture target {0}" ) ]
+  InvalidTarget( u32 ),
+  /// Texture image can't be attached to a framebuffer, usually because its format is not color-renderable.
+  #[ error( "Framebuffer is incomplete, status {0}" ) ]
+  IncompleteFramebuffer( u32 ),
+  /// Failed to create a temporary framebuffer.
+  #[ error( "Failed to create a framebuffer" ) ]
+  FailedToCreateFramebuffer,
+}
+
+/// A single 2D image of a texture: a mip level of a 2D texture, of a cube map face or of a layer of an array or 3D texture.
+#[ derive( Debug, Clone, Copy ) ]
+pub struct Image< 'a >
+{
+  /// The texture
+  pub texture : &'a WebGlTexture,
+  /// `TEXTURE_2D`, `TEXTURE_CUBE_MAP_POSITIVE_X` + face, `TEXTURE_2D_ARRAY` or `TEXTURE_3D`
+  pub target : u32,
+  /// Mip level
+  pub level : i32,
+  /// Layer of an array or 3D texture, ignored for others
+  pub layer : i32,
+}
+
+impl< 'a > Image< 'a >
+{
+  /// Base level of a 2D texture
+  pub fn d2( texture : &'a WebGlTexture ) -> Self
+  {
+    Self { texture, target : GL::TEXTURE_2D, level : 0, layer : 0 }
+  }
+
+  /// Base level of a cube map face, faces are ordered as `+X, -X, +Y, -Y, +Z, -Z`
+  pub fn cube_face( texture : &'a WebGlTexture, face : u32 ) -> Self
+  {
+    Self { texture, target : GL::TEXTURE_CUBE_MAP_POSITIVE_X + face, level : 0, layer : 0 }
+  }
+
+  /// Base level of a layer of a 2D array texture
+  pub fn layer( texture : &'a WebGlTexture, layer : i32 ) -> Self
+  {
+    Self { texture, target : GL::TEXTURE_2D_ARRAY, level : 0, layer }
+  }
+
+  /// Base level of a slice of a 3D texture
+  pub fn slice( texture : &'a WebGlTexture, slice : i32 ) -> Self
+  {
+    Self { texture, target : GL::TEXTURE_3D, level : 0, layer : slice }
+  }
+
+  /// Sets the mip level
+  pub fn level( mut self, level : i32 ) -> Self
+  {
+    self.level = level;
+    self
+  }
+
+  fn is_layered( &self ) -> bool
+  {
+    self.target == GL::TEXTURE_2D_ARRAY || self.target == GL::TEXTURE_3D
+  }
+
+  fn validate( &self ) -> Result< (), Error >
+  {
+    let cube_face = ( GL::TEXTURE_CUBE_MAP_POSITIVE_X..=GL::TEXTURE_CUBE_MAP_NEGATIVE_Z ).contains( &self.target );
+    if self.target == GL::TEXTURE_2D || cube_face || self.is_layered()
+    {
+      Ok( () )
+    }
+    else
+    {
+      Err( Error::InvalidTarget( self.target ) )
+    }
+  }
+
+  /// Target the texture is bound to
+  fn bind_target( &self ) -> u32
+  {
+    if self.target == GL::TEXTURE_2D || self.is_layered() { self.target } else { GL::TEXTURE_CUBE_MAP }
+  }
+
+  fn attach( &self, gl : &GL, framebuffer_target : u32 )
+  {
+    if self.is_layered()
+    {
+      gl.framebuffer_texture_layer( framebuffer_target, GL::COLOR_ATTACHMENT0, Some( self.texture ), self.level, self.layer );
+    }
+    else
+    {
+      gl.framebuffer_texture_2d( framebuffer_target, GL::COLOR_ATTACHMENT0, self.target, Some( self.texture ), self.level );
+    }
+  }
+}
+
+/// Copies a rectangle of pixels between framebuffers with `blitFramebuffer`, scaling it if the sizes differ.
+/// `None` stands for the default framebuffer. Rectangles are `[ x0, y0, x1, y1 ]`, flipping is done by swapping the corners.
+/// `mask` is a combination of `COLOR_BUFFER_BIT`, `DEPTH_BUFFER_BIT` and `STENCIL_BUFFER_BIT`,
+/// `filter` is `NEAREST` or `LINEAR`, the latter only for color.
+///
+/// Leaves the read and draw framebuffers unbound.
+pub fn blit
+(
+  gl : &GL,
+  src : Option< &WebGlFramebuffer >,
+  src_rect : [ i32; 4 ],
+  dst : Option< &WebGlFramebuffer >,
+  dst_rect : [ i32; 4 ],
+  mask : u32,
+  filter : u32,
+)
+-> Result< (), Error >
+{
+  validate_blit( mask, filter )?;
+
+  gl.bind_framebuffer( GL::READ_FRAMEBUFFER, src );
+  gl.bind_framebuffer( GL::DRAW_FRAMEBUFFER, dst );
+  gl.blit_framebuffer
+  (
+    src_rect[ 0 ], src_rect[ 1 ], src_rect[ 2 ], src_rect[ 3 ],
+    dst_rect[ 0 ], dst_rect[ 1 ], dst_rect[ 2 ], dst_rect[ 3 ],
+    mask,
+    filter
+  );
+  gl.bind_framebuffer( GL::READ_FRAMEBUFFER, None );
+  gl.bind_framebuffer( GL::DRAW_FRAMEBUFFER, None );
+
+  Ok( () )
+}
+
+/// Copies color between texture images with `blitFramebuffer`, scaling it if the sizes differ.
+/// Rectangles are `[ x0, y0, x1, y1 ]`. Both images must be color-renderable, which is checked.
+/// Useful to downsample images for ping-pong passes, use `image` for copies without scaling.
+pub fn blit_image
+(
+  gl : &GL,
+  src : Image< '_ >,
+  src_rect : [ i32; 4 ],
+  dst : Image< '_ >,
+  dst_rect : [ i32; 4 ],
+  filter : u32,
+)
+-> Result< (), Error >
+{
+  validate_blit( GL::COLOR_BUFFER_BIT, filter )?;
+  src.validate()?;
+  dst.validate()?;
+
+  let read = framebuffer( gl, GL::READ_FRAMEBUFFER, &src )?;
+  let draw = match framebuffer( gl, GL::DRAW_FRAMEBUFFER, &dst )
+  {
+    Ok( draw ) => draw,
+    Err( e ) =>
+    {
+      gl.bind_framebuffer( GL::READ_FRAMEBUFFER, None );
+      gl.delete_framebuffer( Some( &read ) );
+      return Err( e );
+    }
+  };
+
+  let result = blit( gl, Some( &read ), src_rect, Some( &draw ), dst_rect, GL::COLOR_BUFFER_BIT, filter );
+  gl.delete_framebuffer( Some( &read ) );
+  gl.delete_framebuffer( Some( &draw ) );
+  result
+}
+
+/// Copies a `width` x `height` rectangle at `src_offset` of one texture image to `dst_offset` of another with `copyTexSubImage`.
+/// Images can be of different kinds, for example a cube map face can be copied into a layer of a 2D array.
+/// The source must be color-renderable, which is checked, and formats must be compatible.
+/// Leaves the destination texture bound to the active unit.
+pub fn image
+(
+  gl : &GL,
+  src : Image< '_ >,
+  src_offset : [ i32; 2 ],
+  dst : Image< '_ >,
+  dst_offset : [ i32; 2 ],
+  width : i32,
+  height : i32,
+)
+-> Result< (), Error >
+{
+  src.validate()?;
+  dst.validate()?;
+
+  let read = framebuffer( gl, GL::READ_FRAMEBUFFER, &src )?;
+  let result = from_framebuffer( gl, Some( &read ), src_offset, dst, dst_offset, width, height );
+  gl.delete_framebuffer( Some( &read ) );
+  result
+}
+
+/// Copies a `width` x `height` rectangle at `src_offset` of the color buffer of a framebuffer to `dst_offset` of a texture image.
+/// `None` stands for the default framebuffer.
+/// Leaves the destination texture bound to the active unit and the read framebuffer unbound.
+pub fn from_framebuffer
+(
+  gl : &GL,
+  src : Option< &WebGlFramebuffer >,
+  src_offset : [ i32; 2 ],
+  dst : Image< '_ >,
+  dst_offset : [ i32; 2 ],
+  width : i32,
+  height : i32,
+)
+-> Result< (), Error >
+{
+  dst.validate()?;
+
+  gl.bind_framebuffer( GL::READ_FRAMEBUFFER, src );
+  gl.bind_texture( dst.bind_target(), Some( dst.texture ) );
+  if dst.is_layered()
+  {
+    gl.copy_tex_sub_image_3d
+    (
+      dst.target, dst.level,
+      dst_offset[ 0 ], dst_offset[ 1 ], dst.layer,
+      src_offset[ 0 ], src_offset[ 1 ], width, height
+    );
+  }
+  else
+  {
+    gl.copy_tex_sub_image_2d
+    (
+      dst.target, dst.level,
+      dst_offset[ 0 ], dst_offset[ 1 ],
+      src_offset[ 0 ], src_offset[ 1 ], width, height
+    );
+  }
+  gl.bind_framebuffer( GL::READ_FRAMEBUFFER, None );
+
+  Ok( () )
+}
+
+fn validate_blit( mask : u32, filter : u32 ) -> Result< (), Error >
+{
+  let all = GL::COLOR_BUFFER_BIT | GL::DEPTH_BUFFER_BIT | GL::STENCIL_BUFFER_BIT;
+  if mask & !all != 0
+  {
+    return Err( Error::InvalidMask( mask ) );
+  }
+  if filter != GL::NEAREST && filter != GL::LINEAR
+  {
+    return Err( Error::InvalidFilter( filter ) );
+  }
+  if filter == GL::LINEAR && mask & ( GL::DEPTH_BUFFER_BIT | GL::STENCIL_BUFFER_BIT ) != 0
+  {
+    return Err( Error::LinearDepthStencil );
+  }
+  Ok( () )
+}
+
+/// Creates a temporary framebuffer with the image attached as the color attachment and leaves it bound to `target`
+fn framebuffer( gl : &GL, target : u32, image : &Image< '_ > ) -> Result< WebGlFramebuffer, Error >
+{
+  let framebuffer = gl.create_framebuffer().ok_or( Error::FailedToCreateFramebuffer )?;
+  gl.bind_framebuffer( target, Some( &framebuffer ) );
+  image.attach( gl, target );
+
+  let status = gl.check_framebuffer_status( target );
+  if status != GL::FRAMEBUFFER_COMPLETE
+  {
+    gl.bind_framebuffer( target, None );
+    gl.delete_framebuffer( Some( &framebuffer ) );
+    return Err( Error::IncompleteFramebuffer( status ) );
+  }
+
+  Ok( framebuffer )
+}
@@ -0,0 +1,160 @@
+use crate::*;
+
+type GL = web_sys::WebGl2RenderingContext;
+
+// 3D textures and 2D array textures share the same API, `target` is either `TEXTURE_3D` or `TEXTURE_2D_ARRAY`.
+
+/// Creates a texture of immutable storage for `TEXTURE_3D` or `TEXTURE_2D_ARRAY` and leaves it bound.
+/// `levels` is the amount of mip levels, 1 for no mipmaps.
+/// Contents are undefined until uploaded with `upload` or `upload_layer`.
+///
+/// # When it useful
+/// - Color grading LUTs and volumes with `TEXTURE_3D`
+/// - Tile sets and shadow cascades with `TEXTURE_2D_ARRAY`
+pub fn create
+(
+  gl : &GL,
+  target : u32,
+  internal_format : u32,
+  levels : i32,
+  size : [ i32; 3 ],
+)
+-> Result< web_sys::WebGlTexture, WebglError >
+{
+  let texture = gl.create_texture().ok_or( WebglError::FailedToAllocateResource( "Texture" ) )?;
+  gl.bind_texture( target, Some( &texture ) );
+  gl.tex_storage_3d( target, levels.max( 1 ), internal_format, size[ 0 ], size[ 1 ], size[ 2 ] );
+  texture_parameters( gl, target );
+  Ok( texture )
+}
+
+/// Uploads tightly packed raw data to a region of a 3D or 2D array texture at mip level `level`.
+/// `offset` and `size` are in texels, the third component is the depth or the layer.
+/// `format` and `data_type` describe `data`, for example `GL::RGBA` and `GL::UNSIGNED_BYTE`.
+///
+/// # Example
+///
+/// ```rust, ignore
+/// let lut = gl::texture::d3::create( &gl, GL::TEXTURE_3D, GL::RGBA8, 1, [ 32, 32, 32 ] )?;
+/// gl::texture::d3::upload( &gl, GL::TEXTURE_3D, &lut, 0, [ 0, 0, 0 ], [ 32, 32, 32 ], GL::RGBA, GL::UNSIGNED_BYTE, &data );
+/// ```
+#[ allow( clippy::too_many_arguments ) ]
+pub fn upload< Data >
+(
+  gl : &GL,
+  target : u32,
+  texture : &web_sys::WebGlTexture,
+  level : i32,
+  offset : [ i32; 3 ],
+  size : [ i32; 3 ],
+  format : u32,
+  data_type : u32,
+  data : &Data,
+)
+where
+  Data : mem::AsBytes + ?Sized,
+{
+  gl.bind_texture( target, Some( texture ) );
+  gl.pixel_storei( GL::UNPACK_ALIGNMENT, 1 );
+  gl.tex_sub_image_3d_with_opt_u8_array
+  (
+    target,
+    level,
+    offset[ 0 ],
+    offset[ 1 ],
+    offset[ 2 ],
+    size[ 0 ],
+    size[ 1 ],
+    size[ 2 ],
+    format,
+    data_type,
+    Some( data.as_bytes() )
+  ).expect( "Failed to upload data to texture" );
+  gl.pixel_storei( GL::UNPACK_ALIGNMENT, 4 );
+}
+
+/// Replaces the whole base level of a single layer of a 2D array or a single slice of a 3D texture.
+/// Use it to update a texture partially instead of uploading it again.
+#[ allow( clippy::too_many_arguments ) ]
+pub fn upload_layer< Data >
+(
+  gl : &GL,
+  target : u32,
+  texture : &web_sys::WebGlTexture,
+  layer : i32,
+  size : [ i32; 2 ],
+  format : u32,
+  data_type : u32,
+  data : &Data,
+)
+where
+  Data : mem::AsBytes + ?Sized,
+{
+  upload( gl, target, texture, 0, [ 0, 0, layer ], [ size[ 0 ], size[ 1 ], 1 ], format, data_type, data );
+}
+
+/// Creates a `TEXTURE_2D_ARRAY` with a layer per image and generates mipmaps for it.
+/// Image format and internal format are assumed to be RGBA unsigned bytes.
+/// All images must be loaded and have the same size, the size of the first one is used.
+/// Returns `None` if there are no images.
+///
+/// # When it useful
+/// - Loading tile sets, where each tile is a separate image
+pub fn upload_array_from_images
+(
+  gl : &GL,
+  images : &[ web_sys::HtmlImageElement ],
+)
+-> Result< Option< web_sys::WebGlTexture >, WebglError >
+{
+  let Some( first ) = images.first() else { return Ok( None ) };
+  let ( width, height ) = ( first.width() as i32, first.height() as i32 );
+  let levels = 32 - ( width.max( height ).max( 1 ) as u32 ).leading_zeros() as i32;
+  let texture = create( gl, GL::TEXTURE_2D_ARRAY, GL::RGBA8, levels, [ width, height, images.len() as i32 ] )?;
+
+  for ( layer, image ) in images.iter().enumerate()
+  {
+    upload_layer_from_image( gl, &texture, layer as i32, image );
+  }
+  gl.generate_mipmap( GL::TEXTURE_2D_ARRAY );
+  gl.tex_parameteri( GL::TEXTURE_2D_ARRAY, GL::TEXTURE_MIN_FILTER, GL::LINEAR_MIPMAP_LINEAR as i32 );
+
+  Ok( Some( texture ) )
+}
+
+/// Replaces a layer of a `TEXTURE_2D_ARRAY` of RGBA unsigned bytes by an image.
+/// Mipmaps are not regenerated.
+pub fn upload_layer_from_image
+(
+  gl : &GL,
+  texture : &web_sys::WebGlTexture,
+  layer : i32,
+  image : &web_sys::HtmlImageElement,
+)
+{
+  gl.bind_texture( GL::TEXTURE_2D_ARRAY, Some( texture ) );
+  gl.tex_sub_image_3d_with_html_image_element
+  (
+    GL::TEXTURE_2D_ARRAY,
+    0,
+    0,
+    0,
+    layer,
+    image.width() as i32,
+    image.height() as i32,
+    1,
+    GL::RGBA,
+    GL::UNSIGNED_BYTE,
+    image
+  ).expect( "Failed to upload data to texture" );
+}
+
+/// Set filters to LINEAR and wrap mode to CLAMP_TO_EDGE, which suits both LUTs and arrays
+fn texture_parameters( gl : &GL, target : u32 )
+{
+  gl.tex_parameteri( target, GL::TEXTURE_MIN_FILTER, GL::LINEAR as i32 );
+  gl.tex_parameteri( target, GL::TEXTURE_MAG_FILTER, GL::LINEAR as i32 );
+  gl.tex_parameteri( target, GL::TEXTURE_WRAP_S, GL::CLAMP_TO_EDGE as i32 );
+  gl.tex_parameteri( target, GL::TEXTURE_WRAP_T, GL::CLAMP_TO_EDGE as i32 );
+  gl.tex_parameteri( target, GL::TEXTURE_WRAP_R, GL::CLAMP_TO_EDGE as i32 );
+}
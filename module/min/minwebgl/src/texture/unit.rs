@@ -0,0 +1,91 @@
+use crate::*;
+use std::collections::HashMap;
+
+/// Assigns texture units to the samplers of a program by name.
+///
+/// Each sampler gets its own unit on the first `assign`, and the unit index is uploaded to the sampler uniform once.
+/// After that textures are bound by the sampler name, without `GL::TEXTURE0 + n` arithmetic.
+///
+/// ```rust, ignore
+/// let mut units = gl::texture::unit::TextureUnits::new( &program );
+/// units.assign( &gl, "envMap", GL::TEXTURE_CUBE_MAP )?;
+/// units.assign( &gl, "albedo", GL::TEXTURE_2D )?;
+/// // In the render loop
+/// units.bind( &gl, "albedo", Some( &texture ) );
+/// ```
+#[ derive( Debug, Clone ) ]
+pub struct TextureUnits
+{
+  program : web_sys::WebGlProgram,
+  samplers : HashMap< String, Sampler >,
+}
+
+/// Texture unit of a sampler and the target it's bound to.
+#[ derive( Debug, Clone, Copy, PartialEq, Eq ) ]
+pub struct Sampler
+{
+  /// Index of the texture unit, starting from 0
+  pub unit : u32,
+  /// Texture target, like `GL::TEXTURE_2D` or `GL::TEXTURE_CUBE_MAP`
+  pub target : u32,
+}
+
+impl TextureUnits
+{
+  /// Creates an allocator without any assigned units for the program
+  pub fn new( program : &web_sys::WebGlProgram ) -> Self
+  {
+    Self { program : program.clone(), samplers : HashMap::new() }
+  }
+
+  /// Assigns the next free texture unit to the sampler `name` and uploads it to the sampler uniform.
+  /// Assigning the same name again returns the unit assigned before and updates the target.
+  /// The program is made current. If the sampler is not active in the program the unit is still reserved.
+  ///
+  /// Returns an error if all texture units of the context are taken.
+  pub fn assign( &mut self, gl : &GL, name : &str, target : u32 ) -> Result< u32, WebglError >
+  {
+    if let Some( sampler ) = self.samplers.get_mut( name )
+    {
+      sampler.target = target;
+      return Ok( sampler.unit );
+    }
+
+    let unit = self.samplers.len() as u32;
+    let max_units = gl.get_parameter( GL::MAX_COMBINED_TEXTURE_IMAGE_UNITS )
+    .ok()
+    .and_then( | v | v.as_f64() )
+    .unwrap_or( 32.0 ) as u32;
+    if unit >= max_units
+    {
+      return Err( WebglError::FailedToAllocateResource( "Texture unit" ) );
+    }
+
+    gl.use_program( Some( &self.program ) );
+    gl.uniform1i( gl.get_uniform_location( &self.program, name ).as_ref(), unit as i32 );
+    self.samplers.insert( name.to_string(), Sampler { unit, target } );
+    Ok( unit )
+  }
+
+  /// Unit and target assigned to the sampler `name`
+  pub fn get( &self, name : &str ) -> Option< Sampler >
+  {
+    self.samplers.get( name ).copied()
+  }
+
+  /// Makes the unit of the sampler `name` active and binds `texture` to it, `None` unbinds the texture.
+  /// Returns the unit or `None` if no unit is assigned to the sampler.
+  pub fn bind( &self, gl : &GL, name : &str, texture : Option< &web_sys::WebGlTexture > ) -> Option< u32 >
+  {
+    let sampler = self.samplers.get( name )?;
+    gl.active_texture( GL::TEXTURE0 + sampler.unit );
+    gl.bind_texture( sampler.target, texture );
+    Some( sampler.unit )
+  }
+
+  /// Program the units are assigned for
+  pub fn program( &self ) -> &web_sys::WebGlProgram
+  {
+    &self.program
+  }
+}
@@ -0,0 +1,148 @@
+use crate::*;
+use wasm_bindgen::JsCast;
+
+type GL = web_sys::WebGl2RenderingContext;
+
+/// `HAVE_CURRENT_DATA` ready state of a media element, the current frame can be drawn.
+const HAVE_CURRENT_DATA : u16 = 2;
+
+/// A 2D texture streaming frames of a video element, which can play a file or a `MediaStream` from a camera.
+///
+/// The texture is reallocated only when the size of the video changes, other frames are uploaded with `texSubImage2D`.
+/// Until the first frame is available the texture is a single black texel, so it can be sampled right away.
+///
+/// ```rust, ignore
+/// let mut video = gl::texture::video::VideoTexture::from_stream( &gl, &camera_stream )?;
+/// // In the render loop
+/// video.update( &gl );
+/// gl.bind_texture( GL::TEXTURE_2D, Some( video.texture() ) );
+/// ```
+#[ derive( Debug, Clone ) ]
+pub struct VideoTexture
+{
+  texture : web_sys::WebGlTexture,
+  video : web_sys::HtmlVideoElement,
+  size : [ u32; 2 ],
+  time : f64,
+}
+
+impl VideoTexture
+{
+  /// Creates a texture, which streams frames of the video element.
+  /// Sets filters to LINEAR and wrap mode to CLAMP_TO_EDGE, as videos are rarely power of two.
+  pub fn new( gl : &GL, video : web_sys::HtmlVideoElement ) -> Result< Self, WebglError >
+  {
+    let texture = gl.create_texture().ok_or( WebglError::FailedToAllocateResource( "Video texture" ) )?;
+    gl.bind_texture( GL::TEXTURE_2D, Some( &texture ) );
+    gl.tex_image_2d_with_i32_and_i32_and_i32_and_format_and_type_and_opt_u8_array
+    (
+      GL::TEXTURE_2D,
+      0,
+      GL::RGBA as i32,
+      1,
+      1,
+      0,
+      GL::RGBA,
+      GL::UNSIGNED_BYTE,
+      Some( &[ 0, 0, 0, 255 ] )
+    ).expect( "Failed to upload data to texture" );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_MIN_FILTER, GL::LINEAR as i32 );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_MAG_FILTER, GL::LINEAR as i32 );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_WRAP_S, GL::CLAMP_TO_EDGE as i32 );
+    gl.tex_parameteri( GL::TEXTURE_2D, GL::TEXTURE_WRAP_T, GL::CLAMP_TO_EDGE as i32 );
+
+    Ok( Self { texture, video, size : [ 1, 1 ], time : -1.0 } )
+  }
+
+  /// Creates a muted, inline video element playing the stream, for example from `getUserMedia`, and a texture for it.
+  pub fn from_stream( gl : &GL, stream : &web_sys::MediaStream ) -> Result< Self, WebglError >
+  {
+    let video = web_sys::window()
+    .and_then( | w | w.document() )
+    .and_then( | d | d.create_element( "video" ).ok() )
+    .and_then( | e | e.dyn_into::< web_sys::HtmlVideoElement >().ok() )
+    .ok_or( WebglError::FailedToAllocateResource( "Video element" ) )?;
+
+    video.set_src_object( Some( stream ) );
+    video.set_muted( true );
+    // Without it mobile browsers open the stream fullscreen
+    video.set_attribute( "playsinline", "" ).ok();
+    let _ = video.play();
+
+    Self::new( gl, video )
+  }
+
+  /// Whether the video has a frame to upload
+  pub fn is_ready( &self ) -> bool
+  {
+    self.video.ready_state() >= HAVE_CURRENT_DATA && self.video.video_width() > 0
+  }
+
+  /// Uploads the current frame of the video if it's ready and changed since the last upload.
+  /// Returns whether the texture was updated. Leaves the texture bound to `TEXTURE_2D` if it was.
+  pub fn update( &mut self, gl : &GL ) -> bool
+  {
+    if !self.is_ready()
+    {
+      return false;
+    }
+
+    let size = [ self.video.video_width(), self.video.video_height() ];
+    let time = self.video.current_time();
+    // Camera streams don't advance `currentTime` in every browser, so they are uploaded every frame
+    let is_stream = self.video.src_object().is_some();
+    if size == self.size && time == self.time && !is_stream
+    {
+      return false;
+    }
+
+    gl.bind_texture( GL::TEXTURE_2D, Some( &self.texture ) );
+    if size != self.size
+    {
+      gl.tex_image_2d_with_u32_and_u32_and_html_video_element
+      (
+        GL::TEXTURE_2D,
+        0,
+        GL::RGBA as i32,
+        GL::RGBA,
+        GL::UNSIGNED_BYTE,
+        &self.video
+      ).expect( "Failed to upload data to texture" );
+      self.size = size;
+    }
+    else
+    {
+      gl.tex_sub_image_2d_with_u32_and_u32_and_html_video_element
+      (
+        GL::TEXTURE_2D,
+        0,
+        0,
+        0,
+        GL::RGBA,
+        GL::UNSIGNED_BYTE,
+        &self.video
+      ).expect( "Failed to upload data to texture" );
+    }
+    self.time = time;
+
+    true
+  }
+
+  /// The texture frames are uploaded to
+  pub fn texture( &self ) -> &web_sys::WebGlTexture
+  {
+    &self.texture
+  }
+
+  /// The video element frames are taken from
+  pub fn video( &self ) -> &web_sys::HtmlVideoElement
+  {
+    &self.video
+  }
+
+  /// Size of the current frame in pixels, `[ 1, 1 ]` until the first upload
+  pub fn size( &self ) -> [ u32; 2 ]
+  {
+    self.size
+  }
+}
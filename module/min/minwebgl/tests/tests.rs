@@ -0,0 +1,14 @@
+
+#[ allow( unused_imports ) ]
+use test_tools::exposed::*;
+#[ allow( unused_imports ) ]
+use minwebgl as the_module;
+
+mod tests
+{
+  #[ allow( unused_imports ) ]
+  use super::*;
+
+  mod hdr_test;
+
+}
@@ -0,0 +1,83 @@
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::ibl::{ Error, HdrImage };
+
+fn file( resolution : &str, data : &[ u8 ] ) -> Vec< u8 >
+{
+  let mut bytes = format!( "#?RADIANCE\nFORMAT=32-bit_rle_rgbe\nEXPOSURE=1.0\n\n{}\n", resolution ).into_bytes();
+  bytes.extend_from_slice( data );
+  bytes
+}
+
+#[ test ]
+fn flat_scanline()
+{
+  let bytes = file( "-Y 1 +X 2", &[ 128, 64, 0, 129, 0, 0, 0, 0 ] );
+  let image = HdrImage::decode( &bytes ).unwrap();
+
+  assert_eq!( ( image.width, image.height ), ( 2, 1 ) );
+  // Exponent 129 scales mantissas by 2^-7
+  let exp = [ 128.5 / 128.0, 64.5 / 128.0, 0.5 / 128.0, 0.0, 0.0, 0.0 ];
+  assert_eq!( image.data, exp );
+}
+
+#[ test ]
+fn rle_scanline()
+{
+  let mut data = vec![ 2, 2, 0, 8 ];
+  // Red is a run of 128
+  data.extend( [ 128 + 8, 128 ] );
+  // Green is a literal of 0..8
+  data.push( 8 );
+  data.extend( 0..8 );
+  // Blue is a run of 0
+  data.extend( [ 128 + 8, 0 ] );
+  // Exponent is a run of 129
+  data.extend( [ 128 + 8, 129 ] );
+
+  let bytes = file( "-Y 1 +X 8", &data );
+  let image = HdrImage::decode( &bytes ).unwrap();
+
+  assert_eq!( ( image.width, image.height ), ( 8, 1 ) );
+  for ( i, rgb ) in image.data.chunks_exact( 3 ).enumerate()
+  {
+    assert_eq!( rgb, [ 128.5 / 128.0, ( i as f32 + 0.5 ) / 128.0, 0.5 / 128.0 ] );
+  }
+}
+
+#[ test ]
+fn truncated()
+{
+  let bytes = file( "-Y 1 +X 2", &[ 128, 64, 0, 129, 0 ] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  let bytes = file( "-Y 1 +X 8", &[ 2, 2, 0, 8, 128 + 8 ] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  // Header without the end
+  let bytes = b"#?RADIANCE\nFORMAT=32-bit_rle_rgbe\n";
+  assert!( matches!( HdrImage::decode( bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+}
+
+#[ test ]
+fn bad_header()
+{
+  let mut bytes = file( "-Y 1 +X 2", &[ 0; 8 ] );
+  bytes[ 1 ] = b'!';
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  let bytes = b"#?RADIANCE\nFORMAT=32-bit_rle_xyze\n\n-Y 1 +X 2\n\0\0\0\0\0\0\0\0";
+  assert!( matches!( HdrImage::decode( bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  let bytes = file( "+Y 1 +X 2", &[ 0; 8 ] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  let bytes = file( "-Y 0 +X 2", &[] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+
+  // Dimensions which overflow or exceed the cap are rejected before allocating
+  let bytes = file( "-Y 4294967295 +X 4294967295", &[] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+  let bytes = file( "-Y 30000 +X 30000", &[ 0; 16 ] );
+  assert!( matches!( HdrImage::decode( &bytes ), Err( Error::InvalidHdr( _ ) ) ) );
+}
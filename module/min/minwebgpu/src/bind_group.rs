@@ -0,0 +1,110 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  pub fn desc< 'a >( layout : &'a web_sys::GpuBindGroupLayout ) -> BindGroupDescriptor< 'a >
+  {
+    BindGroupDescriptor::new( layout )
+  }
+
+  pub fn create
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : impl Into< web_sys::GpuBindGroupDescriptor >
+  ) -> web_sys::GpuBindGroup
+  {
+    device.create_bind_group( &descriptor.into() )
+  }
+
+  /// Resource which can be bound to a bind group entry
+  pub trait BindingResource
+  {
+    fn binding_resource( &self ) -> JsValue;
+  }
+
+  impl BindingResource for web_sys::GpuBuffer
+  {
+    /// Binds the whole buffer
+    fn binding_resource( &self ) -> JsValue
+    {
+      web_sys::GpuBufferBinding::new( self ).into()
+    }
+  }
+
+  impl BindingResource for web_sys::GpuBufferBinding
+  {
+    fn binding_resource( &self ) -> JsValue
+    {
+      self.clone().into()
+    }
+  }
+
+  impl BindingResource for web_sys::GpuTextureView
+  {
+    fn binding_resource( &self ) -> JsValue
+    {
+      self.clone().into()
+    }
+  }
+
+  impl BindingResource for web_sys::GpuSampler
+  {
+    fn binding_resource( &self ) -> JsValue
+    {
+      self.clone().into()
+    }
+  }
+
+  impl< T : BindingResource + ?Sized > BindingResource for &T
+  {
+    fn binding_resource( &self ) -> JsValue
+    {
+      ( **self ).binding_resource()
+    }
+  }
+
+  /// Struct whose fields are the resources of a bind group.
+  /// Usually derived with `#[ derive( AsBindGroup ) ]` of the `derive` feature,
+  /// which also keeps the WGSL declarations in sync with the layout.
+  pub trait AsBindGroup
+  {
+    /// Layout entries of the bindings, in the order of `resources`
+    fn layout_entries() -> Vec< BindGroupLayoutEntry >;
+
+    /// WGSL declarations of the bindings, to be prepended to the shader code
+    fn wgsl( group : u32 ) -> String;
+
+    /// Resources of the bindings, in the order of `layout_entries`
+    fn resources( &self ) -> Vec< JsValue >;
+
+    fn layout( device : &web_sys::GpuDevice ) -> Result< web_sys::GpuBindGroupLayout, WebGPUError >
+    {
+      let desc = Self::layout_entries().into_iter()
+      .fold( layout::bind_group::desc(), | desc, entry | desc.entry( entry ) );
+      layout::bind_group::create( device, desc )
+    }
+
+    fn bind_group( &self, device : &web_sys::GpuDevice, layout : &web_sys::GpuBindGroupLayout ) -> web_sys::GpuBindGroup
+    {
+      let desc = self.resources().iter().enumerate()
+      .fold( desc( layout ), | desc, ( binding, resource ) | desc.entry( binding as u32, resource ) );
+      create( device, desc )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    create,
+    desc
+  };
+
+  exposed use
+  {
+    AsBindGroup,
+    BindingResource
+  };
+}
@@ -0,0 +1,131 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use wasm_bindgen_futures::JsFuture;
+
+  /// Buffer sizes, copies and writes must be multiples of this number of bytes
+  pub const COPY_BUFFER_ALIGNMENT : usize = 4;
+
+  pub fn desc< 'a >() -> BufferDescriptor< 'a >
+  {
+    BufferDescriptor::new()
+  }
+
+  pub fn create< T : Into< web_sys::GpuBufferDescriptor > >
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : T
+  ) -> Result< web_sys::GpuBuffer, WebGPUError >
+  {
+    let buffer = device.create_buffer( &descriptor.into() )
+    .map_err( | e | DeviceError::FailedToCreateBuffer( format!( "{:?}", e ) ) )?;
+
+    Ok( buffer )
+  }
+
+  /// Creates a buffer of the size of `data` and uploads `data` into it.
+  /// The `COPY_DST` usage is added to the descriptor.
+  /// The size is rounded up to `COPY_BUFFER_ALIGNMENT`, the padding is filled with zeros.
+  pub fn init< T : bytemuck::Pod >
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : BufferDescriptor< '_ >,
+    data : &[ T ]
+  ) -> Result< web_sys::GpuBuffer, WebGPUError >
+  {
+    let data : &[ u8 ] = bytemuck::cast_slice( data );
+    let size = data.len().div_ceil( COPY_BUFFER_ALIGNMENT ) * COPY_BUFFER_ALIGNMENT;
+    let buffer = create( device, descriptor.size( size ).copy_dst() )?;
+    if size == data.len()
+    {
+      write( &device.queue(), &buffer, 0, data )?;
+    }
+    else
+    {
+      let mut padded = data.to_vec();
+      padded.resize( size, 0 );
+      write( &device.queue(), &buffer, 0, &padded )?;
+    }
+
+    Ok( buffer )
+  }
+
+  /// Schedules write of `data` into the buffer at `offset` bytes.
+  /// The buffer must have `COPY_DST` usage, `offset` and size of `data` must be multiples of 4.
+  pub fn write< T : bytemuck::Pod >
+  (
+    queue : &web_sys::GpuQueue,
+    buffer : &web_sys::GpuBuffer,
+    offset : u32,
+    data : &[ T ]
+  ) -> Result< (), WebGPUError >
+  {
+    queue.write_buffer_with_u32_and_u8_slice( buffer, offset, bytemuck::cast_slice( data ) )
+    .map_err( | e | BufferError::FailedToWrite( format!( "{:?}", e ) ) )?;
+
+    Ok( () )
+  }
+
+  /// Reads `size` bytes of the buffer starting at `offset` back to the CPU.
+  ///
+  /// WebGPU can map for reading only buffers with `MAP_READ` usage, which are not usable for anything else,
+  /// so the range is copied into a temporary staging buffer first. The source buffer must have `COPY_SRC` usage,
+  /// `offset` and `size` must be multiples of 4. All work submitted to the queue before the call is finished before the data is read.
+  pub async fn read
+  (
+    device : &web_sys::GpuDevice,
+    buffer : &web_sys::GpuBuffer,
+    offset : u32,
+    size : u32
+  ) -> Result< Vec< u8 >, WebGPUError >
+  {
+    let staging = create
+    (
+      device,
+      desc()
+      .label( "minwebgpu::buffer::read staging" )
+      .size( size as usize )
+      .map_read()
+      .copy_dst()
+    )?;
+
+    let encoder = device.create_command_encoder();
+    encoder.copy_buffer_to_buffer_with_u32_and_u32_and_u32( buffer, offset, &staging, 0, size );
+    queue::submit( &device.queue(), encoder.finish() );
+
+    let data = read_mapped( &staging ).await;
+    staging.destroy();
+
+    data
+  }
+
+  /// Maps the whole buffer with `MAP_READ` usage, copies its content and unmaps it.
+  pub async fn read_mapped( buffer : &web_sys::GpuBuffer ) -> Result< Vec< u8 >, WebGPUError >
+  {
+    JsFuture::from( buffer.map_async( web_sys::gpu_map_mode::READ ) ).await
+    .map_err( | e | BufferError::FailedToMap( format!( "{:?}", e ) ) )?;
+
+    let range = buffer.get_mapped_range()
+    .map_err( | e | BufferError::FailedToGetMappedRange( format!( "{:?}", e ) ) );
+    // The range is a view into the mapped memory, copy it before unmapping.
+    let data = range.map( | range | js_sys::Uint8Array::new( &range ).to_vec() );
+    buffer.unmap();
+
+    Ok( data? )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    COPY_BUFFER_ALIGNMENT,
+    create,
+    desc,
+    init,
+    write,
+    read,
+    read_mapped
+  };
+}
@@ -0,0 +1,108 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Compute pass that ends itself when dropped, so a scope delimits the pass:
+  ///
+  /// ```rust, ignore
+  /// let encoder = device.create_command_encoder();
+  /// {
+  ///   let pass = gl::ComputePass::begin( &encoder );
+  ///   pass.set_pipeline( &pipeline );
+  ///   pass.set_bind_group( 0, &bind_group );
+  ///   pass.dispatch_for( [ count, 1, 1 ], [ 64, 1, 1 ] );
+  /// }
+  /// gl::queue::submit( &queue, encoder.finish() );
+  /// ```
+  pub struct ComputePass
+  {
+    encoder : web_sys::GpuComputePassEncoder
+  }
+
+  impl ComputePass
+  {
+    pub fn begin( encoder : &web_sys::GpuCommandEncoder ) -> Self
+    {
+      let encoder = encoder.begin_compute_pass();
+      ComputePass { encoder }
+    }
+
+    pub fn begin_with_label( encoder : &web_sys::GpuCommandEncoder, label : &str ) -> Self
+    {
+      let desc = web_sys::GpuComputePassDescriptor::new();
+      desc.set_label( label );
+      let encoder = encoder.begin_compute_pass_with_descriptor( &desc );
+      ComputePass { encoder }
+    }
+
+    pub fn set_pipeline( &self, pipeline : &web_sys::GpuComputePipeline )
+    {
+      self.encoder.set_pipeline( pipeline );
+    }
+
+    pub fn set_bind_group( &self, index : u32, bind_group : &web_sys::GpuBindGroup )
+    {
+      self.encoder.set_bind_group( index, Some( bind_group ) );
+    }
+
+    /// Dispatches the given number of workgroups
+    pub fn dispatch( &self, workgroups : [ u32; 3 ] )
+    {
+      let [ x, y, z ] = workgroups;
+      self.encoder.dispatch_workgroups_with_workgroup_count_y_and_workgroup_count_z( x, y, z );
+    }
+
+    /// Dispatches enough workgroups of `workgroup_size` ( as declared in `@workgroup_size` of the shader )
+    /// to cover `size` invocations in every dimension
+    pub fn dispatch_for( &self, size : [ u32; 3 ], workgroup_size : [ u32; 3 ] )
+    {
+      self.dispatch( workgroup_count( size, workgroup_size ) );
+    }
+
+    /// Dispatches with `DispatchIndirectArgs` read from the buffer at `offset` bytes
+    pub fn dispatch_indirect( &self, buffer : &web_sys::GpuBuffer, offset : u32 )
+    {
+      self.encoder.dispatch_workgroups_indirect_with_u32( buffer, offset );
+    }
+
+    /// Underlying encoder, for the calls which are not wrapped
+    pub fn encoder( &self ) -> &web_sys::GpuComputePassEncoder
+    {
+      &self.encoder
+    }
+  }
+
+  impl Drop for ComputePass
+  {
+    fn drop( &mut self )
+    {
+      self.encoder.end();
+    }
+  }
+
+  /// Number of workgroups of `workgroup_size` needed to cover `size` invocations in every dimension.
+  /// Panics if any dimension of `workgroup_size` is zero.
+  pub fn workgroup_count( size : [ u32; 3 ], workgroup_size : [ u32; 3 ] ) -> [ u32; 3 ]
+  {
+    assert!( workgroup_size.iter().all( | s | *s > 0 ), "workgroup size must be non-zero in every dimension, got {workgroup_size:?}" );
+    [
+      size[ 0 ].div_ceil( workgroup_size[ 0 ] ),
+      size[ 1 ].div_ceil( workgroup_size[ 1 ] ),
+      size[ 2 ].div_ceil( workgroup_size[ 2 ] ),
+    ]
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    workgroup_count
+  };
+
+  exposed use
+  {
+    ComputePass
+  };
+}
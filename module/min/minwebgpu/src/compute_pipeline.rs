@@ -0,0 +1,55 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use wasm_bindgen_futures::JsFuture;
+
+  pub fn desc< 'a >( module : &'a web_sys::GpuShaderModule ) -> ComputePipelineDescriptor< 'a >
+  {
+    ComputePipelineDescriptor::new( module )
+  }
+
+  pub fn create
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : impl Into< web_sys::GpuComputePipelineDescriptor >
+  ) -> web_sys::GpuComputePipeline
+  {
+    device.create_compute_pipeline( &descriptor.into() )
+  }
+
+  pub async fn create_async
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : impl Into< web_sys::GpuComputePipelineDescriptor >
+  ) -> Result< web_sys::GpuComputePipeline, WebGPUError >
+  {
+    let pipeline = JsFuture::from( device.create_compute_pipeline_async( &descriptor.into() ) ).await
+    .map_err( | e | DeviceError::FailedToCreateComputePipeline( format!( "{:?}", e ) ) )?;
+
+    let pipeline = web_sys::GpuComputePipeline::from( pipeline );
+    Ok( pipeline )
+  }
+
+  /// Layout of the bind group at `index`. For pipelines with 'auto' layout it is the layout
+  /// the browser inferred from the bindings used by the WGSL entry point.
+  pub fn bind_group_layout
+  (
+    pipeline : &web_sys::GpuComputePipeline,
+    index : u32
+  ) -> web_sys::GpuBindGroupLayout
+  {
+    pipeline.get_bind_group_layout( index )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    create,
+    create_async,
+    desc,
+    bind_group_layout
+  };
+}
@@ -10,4 +10,8 @@ crate::mod_interface!
   layer texture;
   layer render_pipeline;
   layer render_pass;
+  layer buffer;
+  layer bind_group;
+  layer compute_pipeline;
+  layer render_bundle;
 }
@@ -0,0 +1,99 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  pub struct BindGroupDescriptor< 'a >
+  {
+    layout : &'a web_sys::GpuBindGroupLayout,
+    /// Defaults to `None`
+    label : Option< &'a str >,
+    entries : Vec< web_sys::GpuBindGroupEntry >
+  }
+
+  impl< 'a > BindGroupDescriptor< 'a >
+  {
+    pub fn new( layout : &'a web_sys::GpuBindGroupLayout ) -> Self
+    {
+      let label = None;
+      let entries = Vec::new();
+
+      BindGroupDescriptor
+      {
+        layout,
+        label,
+        entries
+      }
+    }
+
+    pub fn label( mut self, label : &'a str ) -> Self
+    {
+      self.label = Some( label );
+      self
+    }
+
+    /// Adds an entry with the explicit `binding`.
+    /// `resource` is one of `GpuBufferBinding`, `GpuSampler`, `GpuTextureView` or `GpuExternalTexture`.
+    pub fn entry( mut self, binding : u32, resource : &JsValue ) -> Self
+    {
+      self.entries.push( web_sys::GpuBindGroupEntry::new( binding, resource ) );
+      self
+    }
+
+    /// Adds the whole buffer as the next entry. Binding is the number of entries added before.
+    pub fn buffer( self, buffer : &web_sys::GpuBuffer ) -> Self
+    {
+      let binding = self.next_binding();
+      self.entry( binding, &web_sys::GpuBufferBinding::new( buffer ) )
+    }
+
+    /// Adds `size` bytes of the buffer starting at `offset` as the next entry. Binding is the number of entries added before.
+    pub fn buffer_range( self, buffer : &web_sys::GpuBuffer, offset : f64, size : f64 ) -> Self
+    {
+      let binding = self.next_binding();
+      let resource = web_sys::GpuBufferBinding::new( buffer );
+      resource.set_offset( offset );
+      resource.set_size( size );
+      self.entry( binding, &resource )
+    }
+
+    /// Adds the sampler as the next entry. Binding is the number of entries added before.
+    pub fn sampler( self, sampler : &web_sys::GpuSampler ) -> Self
+    {
+      let binding = self.next_binding();
+      self.entry( binding, sampler )
+    }
+
+    /// Adds the texture view as the next entry. Binding is the number of entries added before.
+    pub fn texture_view( self, view : &web_sys::GpuTextureView ) -> Self
+    {
+      let binding = self.next_binding();
+      self.entry( binding, view )
+    }
+
+    fn next_binding( &self ) -> u32
+    {
+      self.entries.len() as u32
+    }
+  }
+
+  impl From< BindGroupDescriptor< '_ > > for web_sys::GpuBindGroupDescriptor
+  {
+    fn from( value: BindGroupDescriptor< '_ > ) -> Self
+    {
+      let desc = web_sys::GpuBindGroupDescriptor::new( &value.entries.into(), value.layout );
+
+      if let Some( v ) = value.label { desc.set_label( v ); }
+
+      desc
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BindGroupDescriptor
+  };
+}
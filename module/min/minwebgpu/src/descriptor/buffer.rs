@@ -0,0 +1,147 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  #[ derive( Default, Clone ) ]
+  /// Builder struct for the GpuBufferDescriptor.
+  pub struct BufferDescriptor< 'a >
+  {
+    /// Size of the buffer in bytes. Defaults to `0`
+    size : f64,
+    /// The way buffer is going to be used. Defaults to `0`
+    usage : u32,
+    /// Label for the buffer. Used when an error occurs.
+    label : Option< &'a str >,
+    /// Defaults to `false`
+    mapped_at_creation : Option< bool >
+  }
+
+  impl< 'a > BufferDescriptor< 'a >
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Sets the size of the buffer in bytes
+    pub fn size( mut self, size : usize ) -> Self
+    {
+      self.size = size as f64;
+      self
+    }
+
+    /// Sets the label for the buffer
+    pub fn label( mut self, label : &'a str ) -> Self
+    {
+      self.label = Some( label );
+      self
+    }
+
+    /// Sets the property `mapped_at_creation` of the buffer to `true`
+    pub fn mapped_at_creation( mut self ) -> Self
+    {
+      self.mapped_at_creation = Some( true );
+      self
+    }
+
+    /// Adds the usage flags from the provided value
+    pub fn usage( mut self, usage : u32 ) -> Self
+    {
+      self.usage |= usage;
+      self
+    }
+
+    /// Sets the usage flag to MAP_READ
+    pub fn map_read( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::MAP_READ;
+      self
+    }
+
+    /// Sets the usage flag to MAP_WRITE
+    pub fn map_write( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::MAP_WRITE;
+      self
+    }
+
+    /// Sets the usage flag to COPY_SRC
+    pub fn copy_src( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::COPY_SRC;
+      self
+    }
+
+    /// Sets the usage flag to COPY_DST
+    pub fn copy_dst( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::COPY_DST;
+      self
+    }
+
+    /// Sets the usage flag to INDEX
+    pub fn index( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::INDEX;
+      self
+    }
+
+    /// Sets the usage flag to VERTEX
+    pub fn vertex( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::VERTEX;
+      self
+    }
+
+    /// Sets the usage flag to UNIFORM
+    pub fn uniform( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::UNIFORM;
+      self
+    }
+
+    /// Sets the usage flag to STORAGE
+    pub fn storage( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::STORAGE;
+      self
+    }
+
+    /// Sets the usage flag to INDIRECT
+    pub fn indirect( mut self ) -> Self
+    {
+      self.usage |= web_sys::gpu_buffer_usage::INDIRECT;
+      self
+    }
+  }
+
+  impl From< BufferDescriptor< '_ > > for web_sys::GpuBufferDescriptor
+  {
+    fn from( value: BufferDescriptor< '_ > ) -> Self
+    {
+      ( &value ).into()
+    }
+  }
+
+  impl From< &BufferDescriptor< '_ > > for web_sys::GpuBufferDescriptor
+  {
+    fn from( value: &BufferDescriptor< '_ > ) -> Self
+    {
+      let desc = web_sys::GpuBufferDescriptor::new( value.size, value.usage );
+
+      if let Some( v ) = value.label { desc.set_label( v ); }
+      if let Some( v ) = value.mapped_at_creation { desc.set_mapped_at_creation( v ); }
+
+      desc
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    BufferDescriptor
+  };
+}
@@ -0,0 +1,99 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  pub struct ComputePipelineDescriptor< 'a >
+  {
+    module : &'a web_sys::GpuShaderModule,
+    /// Defaults to `None`, which is allowed if the module has a single compute entry point
+    entry_point : Option< &'a str >,
+    /// Defaults to `None`
+    label : Option< &'a str >,
+    /// Defaults to 'auto'
+    layout : Option< &'a web_sys::GpuPipelineLayout >,
+    /// Values of pipeline-overridable constants. Defaults to none
+    constants : Vec< ( &'a str, f64 ) >
+  }
+
+  impl< 'a > ComputePipelineDescriptor< 'a >
+  {
+    pub fn new( module : &'a web_sys::GpuShaderModule ) -> Self
+    {
+      let entry_point = None;
+      let label = None;
+      let layout = None;
+      let constants = Vec::new();
+
+      ComputePipelineDescriptor
+      {
+        module,
+        entry_point,
+        label,
+        layout,
+        constants
+      }
+    }
+
+    pub fn entry_point( mut self, entry : &'a str ) -> Self
+    {
+      self.entry_point = Some( entry );
+      self
+    }
+
+    pub fn label( mut self, label : &'a str ) -> Self
+    {
+      self.label = Some( label );
+      self
+    }
+
+    /// Sets explicit layout of the pipeline. Without it the layout is inferred from the shader,
+    /// and bind group layouts can be retrieved with `compute_pipeline::bind_group_layout`.
+    pub fn layout( mut self, layout : &'a web_sys::GpuPipelineLayout ) -> Self
+    {
+      self.layout = Some( layout );
+      self
+    }
+
+    /// Sets the value of the `override` constant declared in the shader
+    pub fn constant( mut self, name : &'a str, value : f64 ) -> Self
+    {
+      self.constants.push( ( name, value ) );
+      self
+    }
+  }
+
+  impl From< ComputePipelineDescriptor< '_ > > for web_sys::GpuComputePipelineDescriptor
+  {
+    fn from( value: ComputePipelineDescriptor< '_ > ) -> Self
+    {
+      let layout =
+      if let Some( l ) = value.layout
+      {
+        l.into()
+      }
+      else
+      {
+        "auto".into()
+      };
+
+      let stage = web_sys::GpuProgrammableStage::new( value.module );
+      if let Some( v ) = value.entry_point { stage.set_entry_point( v ); }
+      if !value.constants.is_empty() { stage.set_constants( &shader::constants( &value.constants ) ); }
+
+      let desc = web_sys::GpuComputePipelineDescriptor::new( &layout, &stage );
+
+      if let Some( v ) = value.label { desc.set_label( v ); }
+
+      desc
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ComputePipelineDescriptor
+  };
+}
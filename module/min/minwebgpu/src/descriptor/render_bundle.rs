@@ -0,0 +1,106 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  #[ derive( Default, Clone ) ]
+  /// Builder struct for the GpuRenderBundleEncoderDescriptor.
+  /// Formats must match the attachments of the render passes the bundle is executed in.
+  pub struct RenderBundleEncoderDescriptor< 'a >
+  {
+    color_formats : Vec< GpuTextureFormat >,
+    /// Defaults to `None`
+    depth_stencil_format : Option< GpuTextureFormat >,
+    /// Defaults to `1`
+    sample_count : Option< u32 >,
+    /// Defaults to `false`
+    depth_read_only : Option< bool >,
+    /// Defaults to `false`
+    stencil_read_only : Option< bool >,
+    /// Defaults to `None`
+    label : Option< &'a str >
+  }
+
+  impl< 'a > RenderBundleEncoderDescriptor< 'a >
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Adds format of the next color attachment
+    pub fn color_format( mut self, format : GpuTextureFormat ) -> Self
+    {
+      self.color_formats.push( format );
+      self
+    }
+
+    pub fn depth_stencil_format( mut self, format : GpuTextureFormat ) -> Self
+    {
+      self.depth_stencil_format = Some( format );
+      self
+    }
+
+    pub fn sample_count( mut self, sample_count : u32 ) -> Self
+    {
+      self.sample_count = Some( sample_count );
+      self
+    }
+
+    pub fn depth_read_only( mut self, value : bool ) -> Self
+    {
+      self.depth_read_only = Some( value );
+      self
+    }
+
+    pub fn stencil_read_only( mut self, value : bool ) -> Self
+    {
+      self.stencil_read_only = Some( value );
+      self
+    }
+
+    pub fn label( mut self, label : &'a str ) -> Self
+    {
+      self.label = Some( label );
+      self
+    }
+  }
+
+  impl From< RenderBundleEncoderDescriptor< '_ > > for web_sys::GpuRenderBundleEncoderDescriptor
+  {
+    fn from( value: RenderBundleEncoderDescriptor< '_ > ) -> Self
+    {
+      ( &value ).into()
+    }
+  }
+
+  impl From< &RenderBundleEncoderDescriptor< '_ > > for web_sys::GpuRenderBundleEncoderDescriptor
+  {
+    fn from( value: &RenderBundleEncoderDescriptor< '_ > ) -> Self
+    {
+      let color_formats = js_sys::Array::new();
+      for format in &value.color_formats
+      {
+        color_formats.push( &JsValue::from( *format ) );
+      }
+
+      let desc = web_sys::GpuRenderBundleEncoderDescriptor::new( &color_formats );
+
+      if let Some( v ) = value.depth_stencil_format { desc.set_depth_stencil_format( v ); }
+      if let Some( v ) = value.sample_count { desc.set_sample_count( v ); }
+      if let Some( v ) = value.depth_read_only { desc.set_depth_read_only( v ); }
+      if let Some( v ) = value.stencil_read_only { desc.set_stencil_read_only( v ); }
+      if let Some( v ) = value.label { desc.set_label( v ); }
+
+      desc
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    RenderBundleEncoderDescriptor
+  };
+}
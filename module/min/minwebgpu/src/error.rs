@@ -16,6 +16,12 @@ mod private
     ContexError( #[ from ] ContextError ),
     #[ error( "Device error :: {0}" ) ]
     TextureError( #[ from ] TextureError ),
+    #[ error( "Buffer error :: {0}" ) ]
+    BufferError( #[ from ] BufferError ),
+    #[ error( "Shader error :: {0}" ) ]
+    ShaderError( #[ from ] ShaderError ),
+    #[ error( "Model error :: {0}" ) ]
+    ModelError( #[ from ] ModelError ),
   }
 
 
@@ -37,7 +43,48 @@ mod private
   pub enum TextureError
   {
     #[ error( "Failed to create view for the texture: {0}" )]
-    FailedToCreateView( String )
+    FailedToCreateView( String ),
+    #[ error( "Mipmaps can't be generated for the format: {0}" )]
+    UnsupportedMipmapFormat( String ),
+    #[ error( "Failed to generate mipmaps: {0}" )]
+    FailedToGenerateMipmaps( String )
+  }
+
+  #[ derive( Debug, error::typed::Error ) ]
+  pub enum BufferError
+  {
+    #[ error( "Failed to write to the buffer: {0}" )]
+    FailedToWrite( String ),
+    #[ error( "Failed to map the buffer: {0}" )]
+    FailedToMap( String ),
+    #[ error( "Failed to get mapped range of the buffer: {0}" )]
+    FailedToGetMappedRange( String )
+  }
+
+  #[ derive( Debug, error::typed::Error ) ]
+  pub enum ShaderError
+  {
+    #[ error( "Snippet to include is not registered: {0}" )]
+    IncludeNotFound( String ),
+    #[ error( "Unbalanced #ifdef/#ifndef/#else/#endif: {0}" )]
+    UnbalancedConditional( String ),
+    #[ error( "Unknown preprocessor directive: {0}" )]
+    UnknownDirective( String ),
+    #[ error( "Failed to compile the shader:\n{0}" )]
+    Compilation( String )
+  }
+
+  #[ derive( Debug, error::typed::Error ) ]
+  pub enum ModelError
+  {
+    #[ error( "Failed to parse the model: {0}" )]
+    FailedToParse( String ),
+    #[ error( "Primitive has no positions: {0}" )]
+    MissingPositions( String ),
+    #[ error( "Unsupported image format: {0}" )]
+    UnsupportedImageFormat( String ),
+    #[ error( "Failed to upload the image: {0}" )]
+    FailedToUploadImage( String )
   }
 
   #[ derive( Debug, error::typed::Error ) ]
@@ -48,7 +95,13 @@ mod private
     #[ error( "Failed to create RenderPipeline: {0}" )]
     FailedToCreateRenderPipeline( String ),
     #[ error( "Failed to create Texture: {0}" )]
-    FailedToCreateTexture( String )
+    FailedToCreateTexture( String ),
+    #[ error( "Failed to create Buffer: {0}" )]
+    FailedToCreateBuffer( String ),
+    #[ error( "Failed to create ComputePipeline: {0}" )]
+    FailedToCreateComputePipeline( String ),
+    #[ error( "Failed to create RenderBundleEncoder: {0}" )]
+    FailedToCreateRenderBundleEncoder( String )
   }
 
 }
@@ -67,7 +120,10 @@ crate::mod_interface!
     CanvasError,
     DeviceError,
     ContextError,
-    TextureError
+    TextureError,
+    BufferError,
+    ShaderError,
+    ModelError
   };
 }
 
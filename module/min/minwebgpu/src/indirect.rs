@@ -0,0 +1,105 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Arguments of a non-indexed indirect draw, as laid out in the indirect buffer.
+  #[ repr( C ) ]
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable ) ]
+  pub struct DrawIndirectArgs
+  {
+    pub vertex_count : u32,
+    pub instance_count : u32,
+    pub first_vertex : u32,
+    pub first_instance : u32,
+  }
+
+  /// Arguments of an indexed indirect draw, as laid out in the indirect buffer.
+  #[ repr( C ) ]
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable ) ]
+  pub struct DrawIndexedIndirectArgs
+  {
+    pub index_count : u32,
+    pub instance_count : u32,
+    pub first_index : u32,
+    pub base_vertex : i32,
+    pub first_instance : u32,
+  }
+
+  /// Arguments of an indirect dispatch, as laid out in the indirect buffer.
+  #[ repr( C ) ]
+  #[ derive( Debug, Clone, Copy, Default, PartialEq, bytemuck::Pod, bytemuck::Zeroable ) ]
+  pub struct DispatchIndirectArgs
+  {
+    pub workgroup_count_x : u32,
+    pub workgroup_count_y : u32,
+    pub workgroup_count_z : u32,
+  }
+
+  /// Creates a buffer with the given arguments, usable both as an indirect buffer and as a storage buffer,
+  /// so compute shaders can write draw arguments and drive draw calls from the GPU side.
+  pub fn buffer< T : bytemuck::Pod >
+  (
+    device : &web_sys::GpuDevice,
+    args : &[ T ]
+  ) -> Result< web_sys::GpuBuffer, WebGPUError >
+  {
+    buffer::init( device, buffer::desc().indirect().storage(), args )
+  }
+
+  /// Indirect draw calls, shared by render passes and render bundles.
+  /// `offset` is in bytes and must be a multiple of 4.
+  pub trait IndirectDraw
+  {
+    /// Draws with `DrawIndirectArgs` read from the buffer at `offset`.
+    fn indirect_draw( &self, buffer : &web_sys::GpuBuffer, offset : u32 );
+    /// Draws with `DrawIndexedIndirectArgs` read from the buffer at `offset`.
+    fn indirect_draw_indexed( &self, buffer : &web_sys::GpuBuffer, offset : u32 );
+  }
+
+  impl IndirectDraw for web_sys::GpuRenderPassEncoder
+  {
+    fn indirect_draw( &self, buffer : &web_sys::GpuBuffer, offset : u32 )
+    {
+      self.draw_indirect_with_u32( buffer, offset );
+    }
+
+    fn indirect_draw_indexed( &self, buffer : &web_sys::GpuBuffer, offset : u32 )
+    {
+      self.draw_indexed_indirect_with_u32( buffer, offset );
+    }
+  }
+
+  impl IndirectDraw for web_sys::GpuRenderBundleEncoder
+  {
+    fn indirect_draw( &self, buffer : &web_sys::GpuBuffer, offset : u32 )
+    {
+      self.draw_indirect_with_u32( buffer, offset );
+    }
+
+    fn indirect_draw_indexed( &self, buffer : &web_sys::GpuBuffer, offset : u32 )
+    {
+      self.draw_indexed_indirect_with_u32( buffer, offset );
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    buffer
+  };
+
+  exposed use
+  {
+    DrawIndirectArgs,
+    DrawIndexedIndirectArgs,
+    DispatchIndirectArgs
+  };
+
+  prelude use
+  {
+    IndirectDraw
+  };
+}
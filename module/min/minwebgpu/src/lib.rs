@@ -4,6 +4,9 @@ pub use mingl::mod_interface;
 #[ cfg( feature = "enabled" ) ]
 mod private {}
 
+#[ cfg( all( feature = "enabled", feature = "derive" ) ) ]
+pub use minwebgpu_derive::AsBindGroup;
+
 #[ cfg( feature = "enabled" ) ]
 mod_interface!
 {
@@ -38,6 +41,12 @@ mod_interface!
   layer render_pipeline;
   layer render_pass;
   layer queue;
+  layer buffer;
+  layer bind_group;
+  layer compute_pipeline;
+  layer compute_pass;
+  layer render_bundle;
+  layer indirect;
   #[ cfg( feature = "math" ) ]
   layer math;
 }
\ No newline at end of file
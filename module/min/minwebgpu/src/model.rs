@@ -8,4 +8,7 @@ crate::mod_interface!
   /// Utilities related to the model in obj format
   #[ cfg( feature = "objModel" ) ]
   layer obj;
+  /// Loading of scenes in gltf format into GPU resources
+  #[ cfg( feature = "gltfModel" ) ]
+  layer gltf;
 }
@@ -0,0 +1,620 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use std::collections::HashMap;
+
+  /// Vertex buffer slots used by `Primitive::draw`
+  pub const POSITION_SLOT : u32 = 0;
+  pub const NORMAL_SLOT : u32 = 1;
+  pub const TEX_COORD_SLOT : u32 = 2;
+  pub const TANGENT_SLOT : u32 = 3;
+
+  /// Material factors as laid out in the uniform buffer at binding 0 of the material bind group:
+  ///
+  /// ```wgsl
+  /// struct Material
+  /// {
+  ///   base_color : vec4f,
+  ///   emissive : vec3f,
+  ///   metallic : f32,
+  ///   roughness : f32,
+  ///   normal_scale : f32,
+  ///   occlusion_strength : f32,
+  ///   alpha_cutoff : f32
+  /// }
+  /// ```
+  #[ repr( C ) ]
+  #[ derive( Debug, Clone, Copy, PartialEq, bytemuck::Pod, bytemuck::Zeroable ) ]
+  pub struct MaterialUniform
+  {
+    pub base_color : [ f32; 4 ],
+    pub emissive : [ f32; 3 ],
+    pub metallic : f32,
+    pub roughness : f32,
+    pub normal_scale : f32,
+    pub occlusion_strength : f32,
+    /// `0.0` when the material is not alpha masked
+    pub alpha_cutoff : f32
+  }
+
+  impl Default for MaterialUniform
+  {
+    fn default() -> Self
+    {
+      MaterialUniform
+      {
+        base_color : [ 1.0; 4 ],
+        emissive : [ 0.0; 3 ],
+        metallic : 1.0,
+        roughness : 1.0,
+        normal_scale : 1.0,
+        occlusion_strength : 1.0,
+        alpha_cutoff : 0.0
+      }
+    }
+  }
+
+  /// Metallic-roughness material with its GPU resources
+  pub struct Material
+  {
+    pub name : Option< String >,
+    pub uniform : MaterialUniform,
+    pub double_sided : bool,
+    pub blend : bool,
+    /// Group with the layout of `pbr_bind_group_layout`
+    pub bind_group : web_sys::GpuBindGroup,
+    pub buffer : web_sys::GpuBuffer
+  }
+
+  /// Geometry of a draw call. Attributes are in separate buffers, see the `*_SLOT` constants.
+  /// Only the first set of texture coordinates is loaded.
+  /// Missing attributes are filled with defaults, so every slot is always bound.
+  pub struct Primitive
+  {
+    pub positions : web_sys::GpuBuffer,
+    /// `[ 0, 0, 1 ]` when the primitive has no normals
+    pub normals : web_sys::GpuBuffer,
+    /// `[ 0, 0 ]` when the primitive has no texture coordinates
+    pub tex_coords : web_sys::GpuBuffer,
+    /// `[ 1, 0, 0, 1 ]` when the primitive has no tangents
+    pub tangents : web_sys::GpuBuffer,
+    /// Indices are always `Uint32`
+    pub indices : Option< web_sys::GpuBuffer >,
+    /// Number of indices, or of vertices for non-indexed primitives
+    pub count : u32,
+    /// Index into `Scene::materials`. `None` means the default material
+    pub material : Option< usize >
+  }
+
+  impl Primitive
+  {
+    /// Sets the vertex and index buffers of the primitive and draws it.
+    /// Pipeline and bind groups must be set beforehand.
+    pub fn draw( &self, render_pass : &web_sys::GpuRenderPassEncoder )
+    {
+      render_pass.set_vertex_buffer( POSITION_SLOT, Some( &self.positions ) );
+      render_pass.set_vertex_buffer( NORMAL_SLOT, Some( &self.normals ) );
+      render_pass.set_vertex_buffer( TEX_COORD_SLOT, Some( &self.tex_coords ) );
+      render_pass.set_vertex_buffer( TANGENT_SLOT, Some( &self.tangents ) );
+
+      if let Some( indices ) = &self.indices
+      {
+        render_pass.set_index_buffer( indices, GpuIndexFormat::Uint32 );
+        render_pass.draw_indexed( self.count );
+      }
+      else
+      {
+        render_pass.draw( self.count );
+      }
+    }
+  }
+
+  pub struct Mesh
+  {
+    pub name : Option< String >,
+    pub primitives : Vec< Primitive >
+  }
+
+  /// Instance of a mesh in the scene
+  pub struct Node
+  {
+    pub name : Option< String >,
+    /// Index into `Scene::meshes`
+    pub mesh : usize,
+    /// Column-major world matrix of the node
+    pub transform : [ f32; 16 ]
+  }
+
+  /// Scene loaded into GPU resources
+  pub struct Scene
+  {
+    pub meshes : Vec< Mesh >,
+    pub materials : Vec< Material >,
+    /// Used by primitives without material
+    pub default_material : Material,
+    /// Nodes of the default scene which have a mesh, with the hierarchy flattened into world transforms
+    pub nodes : Vec< Node >,
+    pub textures : Vec< web_sys::GpuTexture >,
+    pub samplers : Vec< web_sys::GpuSampler >,
+    /// Layout of `Material::bind_group`
+    pub material_layout : web_sys::GpuBindGroupLayout
+  }
+
+  impl Scene
+  {
+    /// Material of the primitive
+    pub fn material( &self, primitive : &Primitive ) -> &Material
+    {
+      primitive.material.and_then( | i | self.materials.get( i ) ).unwrap_or( &self.default_material )
+    }
+  }
+
+  /// Layout of the material bind group, visible to the fragment stage:
+  ///
+  /// ```wgsl
+  /// @group( 0 ) @binding( 0 ) var< uniform > material : Material;
+  /// @group( 0 ) @binding( 1 ) var base_color_texture : texture_2d< f32 >;
+  /// @group( 0 ) @binding( 2 ) var base_color_sampler : sampler;
+  /// @group( 0 ) @binding( 3 ) var metallic_roughness_texture : texture_2d< f32 >;
+  /// @group( 0 ) @binding( 4 ) var metallic_roughness_sampler : sampler;
+  /// @group( 0 ) @binding( 5 ) var normal_texture : texture_2d< f32 >;
+  /// @group( 0 ) @binding( 6 ) var normal_sampler : sampler;
+  /// @group( 0 ) @binding( 7 ) var occlusion_texture : texture_2d< f32 >;
+  /// @group( 0 ) @binding( 8 ) var occlusion_sampler : sampler;
+  /// @group( 0 ) @binding( 9 ) var emissive_texture : texture_2d< f32 >;
+  /// @group( 0 ) @binding( 10 ) var emissive_sampler : sampler;
+  /// ```
+  ///
+  /// Base color and emissive textures are sRGB, so they are sampled in linear space.
+  /// Missing textures are replaced with 1x1 textures which leave the factors unchanged.
+  pub fn pbr_bind_group_layout( device : &web_sys::GpuDevice ) -> Result< web_sys::GpuBindGroupLayout, WebGPUError >
+  {
+    let mut desc = layout::bind_group::desc()
+    .fragment()
+    .auto_bindings()
+    .entry_from_ty( binding_type::buffer().uniform() );
+
+    for _ in 0..5
+    {
+      desc = desc
+      .entry_from_ty( binding_type::texture() )
+      .entry_from_ty( binding_type::sampler() );
+    }
+
+    layout::bind_group::create( device, desc )
+  }
+
+  /// Loads the default scene of a gltf or glb file into GPU resources.
+  /// Buffers and images must be embedded, as there is no file system to resolve external uris.
+  pub fn load_from_slice( device : &web_sys::GpuDevice, data : &[ u8 ] ) -> Result< Scene, WebGPUError >
+  {
+    let ( document, buffers, images ) = ::gltf::import_slice( data )
+    .map_err( | e | ModelError::FailedToParse( format!( "{:?}", e ) ) )?;
+
+    let material_layout = pbr_bind_group_layout( device )?;
+    let mut loader = Loader::new( device, &images, material_layout )?;
+
+    let samplers : Vec< _ > = document.samplers().map( | s | sampler::create( device, sampler_desc( &s ) ) ).collect();
+    let default_sampler = sampler::create( device, sampler::desc().repeat().linear().linear_mip() );
+
+    let mut materials = Vec::new();
+    for material in document.materials()
+    {
+      materials.push( loader.material( &material, &samplers, &default_sampler )? );
+    }
+    let default_material = loader.default_material( &default_sampler )?;
+
+    let mut meshes = Vec::new();
+    for mesh in document.meshes()
+    {
+      let mut primitives = Vec::new();
+      for primitive in mesh.primitives()
+      {
+        primitives.push( load_primitive( device, &primitive, &buffers, mesh.name() )? );
+      }
+      meshes.push( Mesh { name : mesh.name().map( str::to_string ), primitives } );
+    }
+
+    let mut nodes = Vec::new();
+    if let Some( scene ) = document.default_scene().or_else( || document.scenes().next() )
+    {
+      for node in scene.nodes()
+      {
+        flatten( &node, IDENTITY, &mut nodes );
+      }
+    }
+
+    Ok
+    (
+      Scene
+      {
+        meshes,
+        materials,
+        default_material,
+        nodes,
+        textures : loader.textures,
+        samplers,
+        material_layout : loader.material_layout
+      }
+    )
+  }
+
+  const IDENTITY : [ f32; 16 ] =
+  [
+    1.0, 0.0, 0.0, 0.0,
+    0.0, 1.0, 0.0, 0.0,
+    0.0, 0.0, 1.0, 0.0,
+    0.0, 0.0, 0.0, 1.0
+  ];
+
+  /// Product of column-major 4x4 matrices
+  fn multiply( a : &[ f32; 16 ], b : &[ f32; 16 ] ) -> [ f32; 16 ]
+  {
+    let mut result = [ 0.0; 16 ];
+    for col in 0..4
+    {
+      for row in 0..4
+      {
+        result[ col * 4 + row ] = ( 0..4 ).map( | k | a[ k * 4 + row ] * b[ col * 4 + k ] ).sum();
+      }
+    }
+    result
+  }
+
+  fn flatten( node : &::gltf::Node< '_ >, parent : [ f32; 16 ], nodes : &mut Vec< Node > )
+  {
+    let local : [ f32; 16 ] = bytemuck::cast( node.transform().matrix() );
+    let transform = multiply( &parent, &local );
+
+    if let Some( mesh ) = node.mesh()
+    {
+      nodes.push( Node { name : node.name().map( str::to_string ), mesh : mesh.index(), transform } );
+    }
+
+    for child in node.children()
+    {
+      flatten( &child, transform, nodes );
+    }
+  }
+
+  fn sampler_desc( s : &::gltf::texture::Sampler< '_ > ) -> SamplerDescriptor< 'static >
+  {
+    use ::gltf::texture::{ MagFilter, MinFilter, WrappingMode };
+
+    let mut desc = sampler::desc();
+
+    desc = match s.wrap_s()
+    {
+      WrappingMode::ClampToEdge => desc.clamp_to_edge_u(),
+      WrappingMode::MirroredRepeat => desc.mirror_repeat_u(),
+      WrappingMode::Repeat => desc.repeat_u(),
+    };
+    desc = match s.wrap_t()
+    {
+      WrappingMode::ClampToEdge => desc.clamp_to_edge_v(),
+      WrappingMode::MirroredRepeat => desc.mirror_repeat_v(),
+      WrappingMode::Repeat => desc.repeat_v(),
+    };
+
+    desc = match s.mag_filter()
+    {
+      Some( MagFilter::Nearest ) => desc.nearest_mag(),
+      _ => desc.linear_mag(),
+    };
+    desc = match s.min_filter()
+    {
+      Some( MinFilter::Nearest ) | Some( MinFilter::NearestMipmapNearest ) => desc.nearest_min().nearest_mip(),
+      Some( MinFilter::NearestMipmapLinear ) => desc.nearest_min().linear_mip(),
+      Some( MinFilter::LinearMipmapNearest ) => desc.linear_min().nearest_mip(),
+      _ => desc.linear_min().linear_mip(),
+    };
+
+    desc
+  }
+
+  fn load_primitive
+  (
+    device : &web_sys::GpuDevice,
+    primitive : &::gltf::Primitive< '_ >,
+    buffers : &[ ::gltf::buffer::Data ],
+    mesh : Option< &str >
+  ) -> Result< Primitive, WebGPUError >
+  {
+    let reader = primitive.reader( | b | Some( &buffers[ b.index() ][ .. ] ) );
+
+    let positions : Vec< [ f32; 3 ] > = reader.read_positions()
+    .ok_or_else( || ModelError::MissingPositions( format!( "{} of mesh {}", primitive.index(), mesh.unwrap_or( "<unnamed>" ) ) ) )?
+    .collect();
+    let len = positions.len();
+    let normals : Vec< [ f32; 3 ] > = reader.read_normals()
+    .map_or_else( || vec![ [ 0.0, 0.0, 1.0 ]; len ], | i | i.collect() );
+    let tex_coords : Vec< [ f32; 2 ] > = reader.read_tex_coords( 0 )
+    .map_or_else( || vec![ [ 0.0, 0.0 ]; len ], | i | i.into_f32().collect() );
+    let tangents : Vec< [ f32; 4 ] > = reader.read_tangents()
+    .map_or_else( || vec![ [ 1.0, 0.0, 0.0, 1.0 ]; len ], | i | i.collect() );
+    let indices : Option< Vec< u32 > > = reader.read_indices().map( | i | i.into_u32().collect() );
+
+    let vertex = | data : &[ f32 ] | buffer::init( device, buffer::desc().vertex(), data );
+    let count = indices.as_ref().map_or( positions.len(), Vec::len ) as u32;
+
+    Ok
+    (
+      Primitive
+      {
+        positions : vertex( bytemuck::cast_slice( &positions ) )?,
+        normals : vertex( bytemuck::cast_slice( &normals ) )?,
+        tex_coords : vertex( bytemuck::cast_slice( &tex_coords ) )?,
+        tangents : vertex( bytemuck::cast_slice( &tangents ) )?,
+        indices : indices.map( | d | buffer::init( device, buffer::desc().index(), &d ) ).transpose()?,
+        count,
+        material : primitive.material().index()
+      }
+    )
+  }
+
+  /// Creates textures on demand, as the color space of an image is known only from the material using it
+  struct Loader< 'a >
+  {
+    device : &'a web_sys::GpuDevice,
+    images : &'a [ ::gltf::image::Data ],
+    mipmaps : MipmapGenerator,
+    material_layout : web_sys::GpuBindGroupLayout,
+    textures : Vec< web_sys::GpuTexture >,
+    /// ( image index, is srgb ) -> index into `textures`
+    uploaded : HashMap< ( usize, bool ), usize >,
+    /// White in sRGB and linear space, and the flat normal
+    fallback : [ web_sys::GpuTextureView; 3 ]
+  }
+
+  impl< 'a > Loader< 'a >
+  {
+    fn new
+    (
+      device : &'a web_sys::GpuDevice,
+      images : &'a [ ::gltf::image::Data ],
+      material_layout : web_sys::GpuBindGroupLayout
+    ) -> Result< Self, WebGPUError >
+    {
+      let pixel = | color : [ u8; 4 ], format : GpuTextureFormat |
+      {
+        let texture = texture::create( device, texture::desc().size( [ 1, 1, 1 ] ).format( format ).texture_binding().copy_dst() )?;
+        upload( device, &texture, &color, 1, 1 )?;
+        texture::view( &texture )
+      };
+
+      let fallback =
+      [
+        pixel( [ 255; 4 ], GpuTextureFormat::Rgba8unormSrgb )?,
+        pixel( [ 255; 4 ], GpuTextureFormat::Rgba8unorm )?,
+        pixel( [ 128, 128, 255, 255 ], GpuTextureFormat::Rgba8unorm )?,
+      ];
+
+      Ok
+      (
+        Loader
+        {
+          device,
+          images,
+          mipmaps : MipmapGenerator::new( device )?,
+          material_layout,
+          textures : Vec::new(),
+          uploaded : HashMap::new(),
+          fallback
+        }
+      )
+    }
+
+    fn texture( &mut self, image : usize, srgb : bool ) -> Result< web_sys::GpuTextureView, WebGPUError >
+    {
+      if let Some( &i ) = self.uploaded.get( &( image, srgb ) )
+      {
+        return texture::view( &self.textures[ i ] );
+      }
+
+      let data = &self.images[ image ];
+      let pixels = rgba8( data )?;
+      let format = if srgb { GpuTextureFormat::Rgba8unormSrgb } else { GpuTextureFormat::Rgba8unorm };
+      let size = [ data.width, data.height, 1 ];
+
+      let texture = texture::create
+      (
+        self.device,
+        texture::desc()
+        .size( size )
+        .format( format )
+        .mip_level( texture::mip_level_count( size ) )
+        .texture_binding()
+        .copy_dst()
+        .render_attachment()
+      )?;
+      upload( self.device, &texture, &pixels, data.width, data.height )?;
+      self.mipmaps.generate( &texture )?;
+
+      let view = texture::view( &texture )?;
+      self.uploaded.insert( ( image, srgb ), self.textures.len() );
+      self.textures.push( texture );
+      Ok( view )
+    }
+
+    fn material
+    (
+      &mut self,
+      material : &::gltf::Material< '_ >,
+      samplers : &[ web_sys::GpuSampler ],
+      default_sampler : &web_sys::GpuSampler
+    ) -> Result< Material, WebGPUError >
+    {
+      let pbr = material.pbr_metallic_roughness();
+      let uniform = MaterialUniform
+      {
+        base_color : pbr.base_color_factor(),
+        emissive : material.emissive_factor(),
+        metallic : pbr.metallic_factor(),
+        roughness : pbr.roughness_factor(),
+        normal_scale : material.normal_texture().map_or( 1.0, | t | t.scale() ),
+        occlusion_strength : material.occlusion_texture().map_or( 1.0, | t | t.strength() ),
+        alpha_cutoff : if material.alpha_mode() == ::gltf::material::AlphaMode::Mask { material.alpha_cutoff().unwrap_or( 0.5 ) } else { 0.0 }
+      };
+
+      let textures =
+      [
+        ( pbr.base_color_texture().map( | t | t.texture() ), true, 0 ),
+        ( pbr.metallic_roughness_texture().map( | t | t.texture() ), false, 1 ),
+        ( material.normal_texture().map( | t | t.texture() ), false, 2 ),
+        ( material.occlusion_texture().map( | t | t.texture() ), false, 1 ),
+        ( material.emissive_texture().map( | t | t.texture() ), true, 0 ),
+      ];
+
+      let mut bindings = Vec::with_capacity( textures.len() );
+      for ( texture, srgb, fallback ) in textures
+      {
+        let binding = match texture
+        {
+          Some( t ) =>
+          {
+            let sampler = t.sampler().index().and_then( | i | samplers.get( i ) ).unwrap_or( default_sampler );
+            ( self.texture( t.source().index(), srgb )?, sampler.clone() )
+          }
+          None => ( self.fallback[ fallback ].clone(), default_sampler.clone() ),
+        };
+        bindings.push( binding );
+      }
+
+      let mut result = self.create_material( uniform, &bindings )?;
+      result.name = material.name().map( str::to_string );
+      result.double_sided = material.double_sided();
+      result.blend = material.alpha_mode() == ::gltf::material::AlphaMode::Blend;
+      Ok( result )
+    }
+
+    fn default_material( &self, default_sampler : &web_sys::GpuSampler ) -> Result< Material, WebGPUError >
+    {
+      let bindings : Vec< _ > = [ 0, 1, 2, 1, 0 ].iter()
+      .map( | &i | ( self.fallback[ i ].clone(), default_sampler.clone() ) )
+      .collect();
+      self.create_material( MaterialUniform::default(), &bindings )
+    }
+
+    fn create_material
+    (
+      &self,
+      uniform : MaterialUniform,
+      textures : &[ ( web_sys::GpuTextureView, web_sys::GpuSampler ) ]
+    ) -> Result< Material, WebGPUError >
+    {
+      let buffer = buffer::init( self.device, buffer::desc().uniform(), &[ uniform ] )?;
+
+      let mut desc = bind_group::desc( &self.material_layout ).buffer( &buffer );
+      for ( view, sampler ) in textures
+      {
+        desc = desc.texture_view( view ).sampler( sampler );
+      }
+      let bind_group = bind_group::create( self.device, desc );
+
+      Ok
+      (
+        Material
+        {
+          name : None,
+          uniform,
+          double_sided : false,
+          blend : false,
+          bind_group,
+          buffer
+        }
+      )
+    }
+  }
+
+  /// Converts the decoded image to RGBA8
+  fn rgba8( image : &::gltf::image::Data ) -> Result< Vec< u8 >, WebGPUError >
+  {
+    use ::gltf::image::Format;
+
+    // Only the most significant byte of 16 bit channels is kept
+    let ( channels, stride ) = match image.format
+    {
+      Format::R8 => ( 1, 1 ),
+      Format::R8G8 => ( 2, 1 ),
+      Format::R8G8B8 => ( 3, 1 ),
+      Format::R8G8B8A8 => return Ok( image.pixels.clone() ),
+      Format::R16 => ( 1, 2 ),
+      Format::R16G16 => ( 2, 2 ),
+      Format::R16G16B16 => ( 3, 2 ),
+      Format::R16G16B16A16 => ( 4, 2 ),
+      other => return Err( ModelError::UnsupportedImageFormat( format!( "{:?}", other ) ).into() ),
+    };
+
+    let mut pixels = Vec::with_capacity( ( image.width * image.height * 4 ) as usize );
+    for texel in image.pixels.chunks_exact( channels * stride )
+    {
+      let channel = | c : usize | match stride
+      {
+        // 16 bit channels are little-endian
+        2 => ( u16::from_le_bytes( [ texel[ c * 2 ], texel[ c * 2 + 1 ] ] ) >> 8 ) as u8,
+        _ => texel[ c ],
+      };
+      let rgba = match channels
+      {
+        1 => [ channel( 0 ), channel( 0 ), channel( 0 ), 255 ],
+        2 => [ channel( 0 ), channel( 1 ), 0, 255 ],
+        3 => [ channel( 0 ), channel( 1 ), channel( 2 ), 255 ],
+        _ => [ channel( 0 ), channel( 1 ), channel( 2 ), channel( 3 ) ],
+      };
+      pixels.extend_from_slice( &rgba );
+    }
+
+    Ok( pixels )
+  }
+
+  /// Writes RGBA8 pixels into the level 0 of the texture
+  fn upload
+  (
+    device : &web_sys::GpuDevice,
+    texture : &web_sys::GpuTexture,
+    pixels : &[ u8 ],
+    width : u32,
+    height : u32
+  ) -> Result< (), WebGPUError >
+  {
+    let destination = web_sys::GpuImageCopyTexture::new( texture );
+    let layout = web_sys::GpuImageDataLayout::new();
+    layout.set_bytes_per_row( width * 4 );
+    layout.set_rows_per_image( height );
+
+    device.queue().write_texture_with_u8_slice_and_u32_sequence
+    (
+      &destination,
+      pixels,
+      &layout,
+      &Vec::from( [ width, height, 1 ] ).into()
+    )
+    .map_err( | e | ModelError::FailedToUploadImage( format!( "{:?}", e ) ) )?;
+
+    Ok( () )
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    load_from_slice,
+    pbr_bind_group_layout,
+    POSITION_SLOT,
+    NORMAL_SLOT,
+    TEX_COORD_SLOT,
+    TANGENT_SLOT
+  };
+
+  exposed use
+  {
+    Scene,
+    Mesh,
+    Primitive,
+    Node,
+    Material,
+    MaterialUniform
+  };
+}
@@ -0,0 +1,48 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  pub fn desc< 'a >() -> RenderBundleEncoderDescriptor< 'a >
+  {
+    RenderBundleEncoderDescriptor::new()
+  }
+
+  /// Creates an encoder which records draw commands once, to be executed in many render passes.
+  /// It accepts the same commands as `GpuRenderPassEncoder` except those changing the pass state ( viewport, scissor, blend constant ).
+  pub fn create_encoder
+  (
+    device : &web_sys::GpuDevice,
+    descriptor : impl Into< web_sys::GpuRenderBundleEncoderDescriptor >
+  ) -> Result< web_sys::GpuRenderBundleEncoder, WebGPUError >
+  {
+    let encoder = device.create_render_bundle_encoder( &descriptor.into() )
+    .map_err( | e | DeviceError::FailedToCreateRenderBundleEncoder( format!( "{:?}", e ) ) )?;
+
+    Ok( encoder )
+  }
+
+  /// Finishes recording of the bundle.
+  pub fn finish( encoder : &web_sys::GpuRenderBundleEncoder ) -> web_sys::GpuRenderBundle
+  {
+    encoder.finish()
+  }
+
+  /// Executes pre-recorded bundles in the render pass.
+  pub fn execute( render_pass : &web_sys::GpuRenderPassEncoder, bundles : &[ web_sys::GpuRenderBundle ] )
+  {
+    let bundles : js_sys::Array = bundles.iter().collect();
+    render_pass.execute_bundles( &bundles );
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    create_encoder,
+    desc,
+    execute,
+    finish
+  };
+}
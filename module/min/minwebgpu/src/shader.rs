@@ -2,6 +2,7 @@
 mod private
 {
   use crate::*;
+  use wasm_bindgen_futures::JsFuture;
 
   pub struct ShaderModule< 'a >
   {
@@ -43,13 +44,72 @@ mod private
   {
     ShaderModule::new( code ).create( device )
   }
+
+  /// Creates the module from the processed shader and waits for its compilation info.
+  /// Errors are reported with locations in the original snippets instead of lines of the composed code.
+  pub async fn create_checked
+  (
+    device : &web_sys::GpuDevice,
+    shader : &ProcessedShader
+  ) -> Result< web_sys::GpuShaderModule, WebGPUError >
+  {
+    let module = create( device, &shader.code );
+
+    let info = JsFuture::from( module.get_compilation_info() ).await
+    .map_err( | e | ShaderError::Compilation( format!( "{:?}", e ) ) )?;
+    let info = web_sys::GpuCompilationInfo::from( info );
+
+    let mut errors = Vec::new();
+    for message in info.messages().iter()
+    {
+      let message = web_sys::GpuCompilationMessage::from( message );
+      if message.type_() != web_sys::GpuCompilationMessageType::Error
+      {
+        continue;
+      }
+      let location = match shader.location( message.line_num() as u32 )
+      {
+        Some( SourceLocation { snippet, line } ) =>
+        {
+          format!( "{}:{}:{}", snippet.as_deref().unwrap_or( "<root>" ), line, message.line_pos() )
+        }
+        None => "<unknown>".to_string(),
+      };
+      errors.push( format!( "{location}: {}", message.message() ) );
+    }
+
+    if errors.is_empty()
+    {
+      Ok( module )
+    }
+    else
+    {
+      Err( ShaderError::Compilation( errors.join( "\n" ) ).into() )
+    }
+  }
+
+  /// Makes the record of pipeline-overridable constants for the `constants` field of a programmable stage.
+  pub fn constants( constants : &[ ( &str, f64 ) ] ) -> js_sys::Object
+  {
+    let object = js_sys::Object::new();
+    for ( name, value ) in constants
+    {
+      let _ = js_sys::Reflect::set( &object, &JsValue::from_str( name ), &JsValue::from_f64( *value ) );
+    }
+    object
+  }
 }
 
 crate::mod_interface!
 {
+  layer preprocessor;
+  layer cache;
+
   own use
   {
-    create
+    create,
+    create_checked,
+    constants
   };
   exposed use
   {
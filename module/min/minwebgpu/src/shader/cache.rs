@@ -0,0 +1,113 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use std::collections::HashMap;
+
+  /// Cache of shader modules keyed by source and defines, so the same variant of a shader is compiled only once.
+  #[ derive( Default ) ]
+  pub struct ShaderCache
+  {
+    preprocessor : Preprocessor,
+    modules : HashMap< String, web_sys::GpuShaderModule >
+  }
+
+  impl ShaderCache
+  {
+    pub fn new( preprocessor : Preprocessor ) -> Self
+    {
+      let modules = HashMap::new();
+
+      ShaderCache
+      {
+        preprocessor,
+        modules
+      }
+    }
+
+    /// Preprocessor resolving `#include` of the sources
+    pub fn preprocessor( &self ) -> &Preprocessor
+    {
+      &self.preprocessor
+    }
+
+    /// Returns the cached module or preprocesses the source with the defines and creates a new one
+    pub fn get_or_create
+    (
+      &mut self,
+      device : &web_sys::GpuDevice,
+      source : &str,
+      defines : &[ &str ]
+    ) -> Result< web_sys::GpuShaderModule, WebGPUError >
+    {
+      let key = Self::key( source, defines );
+      if let Some( module ) = self.modules.get( &key )
+      {
+        return Ok( module.clone() );
+      }
+
+      let processed = self.preprocessor.process( source, defines )?;
+      let module = shader::create( device, &processed.code );
+      self.modules.insert( key, module.clone() );
+
+      Ok( module )
+    }
+
+    /// Same as `get_or_create`, but waits for the compilation info of a new module and reports errors
+    /// with locations in the original snippets. Failed modules are not cached.
+    pub async fn get_or_create_checked
+    (
+      &mut self,
+      device : &web_sys::GpuDevice,
+      source : &str,
+      defines : &[ &str ]
+    ) -> Result< web_sys::GpuShaderModule, WebGPUError >
+    {
+      let key = Self::key( source, defines );
+      if let Some( module ) = self.modules.get( &key )
+      {
+        return Ok( module.clone() );
+      }
+
+      let processed = self.preprocessor.process( source, defines )?;
+      let module = shader::create_checked( device, &processed ).await?;
+      self.modules.insert( key, module.clone() );
+
+      Ok( module )
+    }
+
+    /// Number of cached modules
+    pub fn len( &self ) -> usize
+    {
+      self.modules.len()
+    }
+
+    pub fn is_empty( &self ) -> bool
+    {
+      self.modules.is_empty()
+    }
+
+    /// Drops all cached modules
+    pub fn clear( &mut self )
+    {
+      self.modules.clear();
+    }
+
+    /// Order of defines does not matter, so they are sorted.
+    fn key( source : &str, defines : &[ &str ] ) -> String
+    {
+      let mut defines = defines.to_vec();
+      defines.sort_unstable();
+      defines.dedup();
+      format!( "{}\0{}", defines.join( "\0" ), source )
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    ShaderCache
+  };
+}
@@ -0,0 +1,165 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+  use std::collections::{ HashMap, HashSet };
+
+  /// Composes WGSL sources from registered snippets.
+  ///
+  /// WGSL has no preprocessor, so this one understands a few directives, each on its own line:
+  /// - `#include "name"` inserts the registered snippet. Every snippet is inserted at most once per processed shader,
+  ///   because WGSL does not allow declarations to be repeated.
+  /// - `#ifdef NAME`, `#ifndef NAME`, `#else`, `#endif` keep or drop lines depending on the defines passed to `process`.
+  ///
+  /// Values should be injected with pipeline-overridable constants ( `override` declarations in WGSL and `constant` of the stage builders )
+  /// rather than textually, so that changing them does not require a new shader module.
+  #[ derive( Debug, Default, Clone ) ]
+  pub struct Preprocessor
+  {
+    snippets : HashMap< String, String >
+  }
+
+  /// Location of a line of the processed shader in the original sources.
+  #[ derive( Debug, Clone, PartialEq, Eq ) ]
+  pub struct SourceLocation
+  {
+    /// Name of the snippet, or `None` for the root source.
+    pub snippet : Option< String >,
+    /// 1-based line number in the snippet.
+    pub line : u32
+  }
+
+  /// Result of the preprocessing.
+  #[ derive( Debug, Clone ) ]
+  pub struct ProcessedShader
+  {
+    /// WGSL code ready to be compiled.
+    pub code : String,
+    /// Origin of every line of `code`.
+    pub line_map : Vec< SourceLocation >
+  }
+
+  impl ProcessedShader
+  {
+    /// Origin of the 1-based line of the processed code, as reported by the compilation info.
+    pub fn location( &self, line : u32 ) -> Option< &SourceLocation >
+    {
+      self.line_map.get( ( line as usize ).checked_sub( 1 )? )
+    }
+  }
+
+  impl Preprocessor
+  {
+    pub fn new() -> Self
+    {
+      Self::default()
+    }
+
+    /// Registers a snippet available for `#include "name"`
+    pub fn snippet( mut self, name : &str, source : &str ) -> Self
+    {
+      self.snippets.insert( name.to_string(), source.to_string() );
+      self
+    }
+
+    /// Resolves the directives of the source.
+    pub fn process( &self, source : &str, defines : &[ &str ] ) -> Result< ProcessedShader, ShaderError >
+    {
+      let defines : HashSet< &str > = defines.iter().copied().collect();
+      let mut included = HashSet::new();
+      let mut result = ProcessedShader { code : String::with_capacity( source.len() ), line_map : Vec::new() };
+      self.process_impl( source, None, &defines, &mut included, &mut result )?;
+      Ok( result )
+    }
+
+    fn process_impl< 'a >
+    (
+      &'a self,
+      source : &str,
+      snippet : Option< &str >,
+      defines : &HashSet< &str >,
+      included : &mut HashSet< &'a str >,
+      result : &mut ProcessedShader
+    ) -> Result< (), ShaderError >
+    {
+      let origin = || snippet.unwrap_or( "<root>" ).to_string();
+      // Every entry is ( the branch is active, the `#else` was seen ).
+      let mut conditions : Vec< ( bool, bool ) > = Vec::new();
+
+      for ( i, line ) in source.lines().enumerate()
+      {
+        let active = conditions.iter().all( | ( active, _ ) | *active );
+        let trimmed = line.trim();
+
+        if !trimmed.starts_with( '#' )
+        {
+          if active
+          {
+            result.code.push_str( line );
+            result.code.push( '\n' );
+            result.line_map.push( SourceLocation { snippet : snippet.map( str::to_string ), line : i as u32 + 1 } );
+          }
+          continue;
+        }
+
+        let ( directive, argument ) = trimmed.split_once( char::is_whitespace ).unwrap_or( ( trimmed, "" ) );
+        let argument = argument.trim();
+        match directive
+        {
+          "#ifdef" => conditions.push( ( defines.contains( argument ), false ) ),
+          "#ifndef" => conditions.push( ( !defines.contains( argument ), false ) ),
+          "#else" =>
+          {
+            match conditions.last_mut()
+            {
+              Some( ( active, seen_else ) ) if !*seen_else =>
+              {
+                *active = !*active;
+                *seen_else = true;
+              }
+              _ => return Err( ShaderError::UnbalancedConditional( format!( "{}:{}", origin(), i + 1 ) ) ),
+            }
+          }
+          "#endif" =>
+          {
+            if conditions.pop().is_none()
+            {
+              return Err( ShaderError::UnbalancedConditional( format!( "{}:{}", origin(), i + 1 ) ) );
+            }
+          }
+          "#include" if active =>
+          {
+            let name = argument.trim_matches( | c | c == '"' || c == '<' || c == '>' );
+            let ( name, code ) = self.snippets.get_key_value( name )
+            .ok_or_else( || ShaderError::IncludeNotFound( format!( "{} at {}:{}", name, origin(), i + 1 ) ) )?;
+            if included.insert( name.as_str() )
+            {
+              self.process_impl( code, Some( name.as_str() ), defines, included, result )?;
+            }
+          }
+          "#include" => {}
+          _ => return Err( ShaderError::UnknownDirective( format!( "{} at {}:{}", directive, origin(), i + 1 ) ) ),
+        }
+      }
+
+      if conditions.is_empty()
+      {
+        Ok( () )
+      }
+      else
+      {
+        Err( ShaderError::UnbalancedConditional( format!( "missing #endif in {}", origin() ) ) )
+      }
+    }
+  }
+}
+
+crate::mod_interface!
+{
+  exposed use
+  {
+    Preprocessor,
+    ProcessedShader,
+    SourceLocation
+  };
+}
@@ -7,7 +7,9 @@ mod private
   {
     module : &'a web_sys::GpuShaderModule,
     entry_point : Option< &'a str >,
-    targets : Vec< ColorTargetState >
+    targets : Vec< ColorTargetState >,
+    /// Values of pipeline-overridable constants. Defaults to none
+    constants : Vec< ( &'a str, f64 ) >
   }
 
   impl< 'a > FragmentState< 'a > 
@@ -16,12 +18,14 @@ mod private
     {
       let entry_point = None;
       let targets = Vec::with_capacity( 1 );
+      let constants = Vec::new();
 
       FragmentState
       {
         module,
         entry_point,
-        targets
+        targets,
+        constants
       }
     }
 
@@ -35,7 +39,14 @@ mod private
     {
       self.targets.push( target );
       self
-    } 
+    }
+
+    /// Sets the value of the `override` constant declared in the shader
+    pub fn constant( mut self, name : &'a str, value : f64 ) -> Self
+    {
+      self.constants.push( ( name, value ) );
+      self
+    }
   }
 
   impl From< FragmentState< '_ > > for web_sys::GpuFragmentState 
@@ -46,6 +57,7 @@ mod private
       let state = web_sys::GpuFragmentState::new( &value.module, &targets.into() );
 
       if let Some( v ) = value.entry_point { state.set_entry_point( v ); }
+      if !value.constants.is_empty() { state.set_constants( &shader::constants( &value.constants ) ); }
 
       state
     }   
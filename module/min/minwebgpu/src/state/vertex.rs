@@ -7,7 +7,9 @@ mod private
   {
     module : &'a web_sys::GpuShaderModule,
     entry_point : Option< &'a str >,
-    buffers : Vec< web_sys::GpuVertexBufferLayout >
+    buffers : Vec< web_sys::GpuVertexBufferLayout >,
+    /// Values of pipeline-overridable constants. Defaults to none
+    constants : Vec< ( &'a str, f64 ) >
   }
 
   impl< 'a > VertexState< 'a > 
@@ -16,12 +18,14 @@ mod private
     {
       let entry_point = None;
       let buffers = Vec::new();
+      let constants = Vec::new();
 
       VertexState
       {
         module,
         entry_point,
-        buffers
+        buffers,
+        constants
       }
     }
 
@@ -42,6 +46,13 @@ mod private
       self.buffers.extend_from_slice( buffers );
       self
     }
+
+    /// Sets the value of the `override` constant declared in the shader
+    pub fn constant( mut self, name : &'a str, value : f64 ) -> Self
+    {
+      self.constants.push( ( name, value ) );
+      self
+    }
   }
 
   impl From< VertexState< '_ > > for web_sys::GpuVertexState 
@@ -52,6 +63,7 @@ mod private
 
       if let Some( v ) = value.entry_point { state.set_entry_point( v ); }
       if !value.buffers.is_empty() { state.set_buffers( &value.buffers.into() ); }
+      if !value.constants.is_empty() { state.set_constants( &shader::constants( &value.constants ) ); }
 
       state
     }   
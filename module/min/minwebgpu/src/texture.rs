@@ -39,14 +39,24 @@ mod private
 
     Ok( view )
   }
+
+  /// Number of mip levels of the full chain for the texture of the given size, down to 1x1
+  pub fn mip_level_count( size : [ u32; 3 ] ) -> u32
+  {
+    let max = size[ 0 ].max( size[ 1 ] ).max( 1 );
+    u32::BITS - max.leading_zeros()
+  }
 }
 
 crate::mod_interface!
 {
+  layer mipmap;
+
   own use
   {
     create,
     desc,
+    mip_level_count,
     view,
     view_with_descriptor
   };
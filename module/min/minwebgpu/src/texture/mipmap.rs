@@ -0,0 +1,205 @@
+/// Internal namespace.
+mod private
+{
+  use crate::*;
+
+  /// Fills mip levels of 2d textures from their level 0, since WebGPU has no `generateMipmap`.
+  ///
+  /// Every level is rendered from the previous one, so the texture needs both
+  /// `RENDER_ATTACHMENT` and `TEXTURE_BINDING` usages. Pipelines are created lazily, one per texture format,
+  /// and reused, so keep the generator around when mipmaps are generated for many textures.
+  pub struct MipmapGenerator
+  {
+    device : web_sys::GpuDevice,
+    module : web_sys::GpuShaderModule,
+    bind_group_layout : web_sys::GpuBindGroupLayout,
+    pipeline_layout : web_sys::GpuPipelineLayout,
+    pipelines : Vec< ( GpuTextureFormat, web_sys::GpuRenderPipeline ) >
+  }
+
+  impl MipmapGenerator
+  {
+    pub fn new( device : &web_sys::GpuDevice ) -> Result< Self, WebGPUError >
+    {
+      let module = ShaderModule::new( include_str!( "mipmap.wgsl" ) )
+      .label( "mipmap" )
+      .create( device );
+
+      let bind_group_layout = layout::bind_group::create
+      (
+        device,
+        layout::bind_group::desc()
+        .fragment()
+        .auto_bindings()
+        .entry_from_ty( binding_type::texture().sample_unfilterable_float() )
+      )?;
+
+      let pipeline_layout = PipelineLayout::new()
+      .label( "mipmap" )
+      .bind_group( bind_group_layout.clone() )
+      .create( device );
+
+      Ok
+      (
+        MipmapGenerator
+        {
+          device : device.clone(),
+          module,
+          bind_group_layout,
+          pipeline_layout,
+          pipelines : Vec::new()
+        }
+      )
+    }
+
+    /// Generates all mip levels after the first one and submits the work to the queue of the device
+    pub fn generate( &mut self, texture : &web_sys::GpuTexture ) -> Result< (), WebGPUError >
+    {
+      let encoder = self.device.create_command_encoder();
+      self.encode( &encoder, texture )?;
+      queue::submit( &self.device.queue(), encoder.finish() );
+      Ok( () )
+    }
+
+    /// Records generation of all mip levels after the first one into the encoder.
+    /// Every layer of an array or cube texture gets its own chain.
+    pub fn encode
+    (
+      &mut self,
+      encoder : &web_sys::GpuCommandEncoder,
+      texture : &web_sys::GpuTexture
+    ) -> Result< (), WebGPUError >
+    {
+      let format = texture.format();
+      if !is_supported( format ) || texture.dimension() != GpuTextureDimension::N2d
+      {
+        return Err( TextureError::UnsupportedMipmapFormat( format!( "{:?} {:?}", format, texture.dimension() ) ).into() );
+      }
+
+      let required = web_sys::gpu_texture_usage::RENDER_ATTACHMENT | web_sys::gpu_texture_usage::TEXTURE_BINDING;
+      if texture.usage() & required != required
+      {
+        return Err
+        (
+          TextureError::FailedToGenerateMipmaps
+          (
+            "the texture must have RENDER_ATTACHMENT and TEXTURE_BINDING usages".to_string()
+          ).into()
+        );
+      }
+
+      let pipeline = self.pipeline( format )?;
+
+      for layer in 0..texture.depth_or_array_layers()
+      {
+        for level in 1..texture.mip_level_count()
+        {
+          let source = texture::view_with_descriptor( texture, &level_view( level - 1, layer ) )?;
+          let target = texture::view_with_descriptor( texture, &level_view( level, layer ) )?;
+
+          let bind_group = bind_group::create
+          (
+            &self.device,
+            bind_group::desc( &self.bind_group_layout ).texture_view( &source )
+          );
+
+          let render_pass = encoder.begin_render_pass
+          (
+            &render_pass::desc()
+            .label( "mipmap" )
+            .color_attachment( ColorAttachment::new( &target ).load_op( GpuLoadOp::Clear ) )
+            .into()
+          )
+          .map_err( | e | TextureError::FailedToGenerateMipmaps( format!( "{:?}", e ) ) )?;
+
+          render_pass.set_pipeline( &pipeline );
+          render_pass.set_bind_group( 0, Some( &bind_group ) );
+          render_pass.draw( 3 );
+          render_pass.end();
+        }
+      }
+
+      Ok( () )
+    }
+
+    fn pipeline( &mut self, format : GpuTextureFormat ) -> Result< web_sys::GpuRenderPipeline, WebGPUError >
+    {
+      if let Some( ( _, pipeline ) ) = self.pipelines.iter().find( | ( f, _ ) | *f == format )
+      {
+        return Ok( pipeline.clone() );
+      }
+
+      let pipeline = render_pipeline::create
+      (
+        &self.device,
+        render_pipeline::desc( VertexState::new( &self.module ).entry_point( "vs_main" ) )
+        .label( "mipmap" )
+        .layout( &self.pipeline_layout )
+        .fragment
+        (
+          FragmentState::new( &self.module )
+          .entry_point( "fs_main" )
+          .target( ColorTargetState::new().format( format ) )
+        )
+      )?;
+
+      self.pipelines.push( ( format, pipeline.clone() ) );
+      Ok( pipeline )
+    }
+  }
+
+  /// Generates the mip chain of the texture with a temporary generator.
+  /// Prefer `MipmapGenerator` when processing several textures.
+  pub fn generate( device : &web_sys::GpuDevice, texture : &web_sys::GpuTexture ) -> Result< (), WebGPUError >
+  {
+    MipmapGenerator::new( device )?.generate( texture )
+  }
+
+  /// Whether mipmaps of the format can be generated: the format must be renderable and sampled as float.
+  /// Covers the unorm, sRGB and float color formats, but not the integer, depth or compressed ones.
+  pub fn is_supported( format : GpuTextureFormat ) -> bool
+  {
+    matches!
+    (
+      format,
+      GpuTextureFormat::R8unorm
+      | GpuTextureFormat::Rg8unorm
+      | GpuTextureFormat::Rgba8unorm
+      | GpuTextureFormat::Rgba8unormSrgb
+      | GpuTextureFormat::Bgra8unorm
+      | GpuTextureFormat::Bgra8unormSrgb
+      | GpuTextureFormat::Rgb10a2unorm
+      | GpuTextureFormat::R16float
+      | GpuTextureFormat::Rg16float
+      | GpuTextureFormat::Rgba16float
+      | GpuTextureFormat::R32float
+      | GpuTextureFormat::Rg32float
+      | GpuTextureFormat::Rgba32float
+    )
+  }
+
+  fn level_view( level : u32, layer : u32 ) -> web_sys::GpuTextureViewDescriptor
+  {
+    let desc = web_sys::GpuTextureViewDescriptor::new();
+    desc.set_dimension( GpuTextureViewDimension::N2d );
+    desc.set_base_mip_level( level );
+    desc.set_mip_level_count( 1 );
+    desc.set_base_array_layer( layer );
+    desc.set_array_layer_count( 1 );
+    desc
+  }
+}
+
+crate::mod_interface!
+{
+  own use
+  {
+    generate,
+    is_supported
+  };
+
+  exposed use
+  {
+    MipmapGenerator
+  };
+}
@@ -0,0 +1,30 @@
+@group( 0 ) @binding( 0 ) var source : texture_2d< f32 >;
+
+// Single triangle covering the whole target
+@vertex
+fn vs_main( @builtin( vertex_index ) id : u32 ) -> @builtin( position ) vec4f
+{
+  let uv = vec2f( f32( ( id << 1u ) & 2u ), f32( id & 2u ) );
+  return vec4f( uv * 2.0 - 1.0, 0.0, 1.0 );
+}
+
+// Box filter over the 2x2 block of the previous level.
+// `textureLoad` works for every float format, including those which are not filterable,
+// and decodes sRGB, so averaging happens in linear space
+@fragment
+fn fs_main( @builtin( position ) position : vec4f ) -> @location( 0 ) vec4f
+{
+  let last = vec2i( textureDimensions( source ) ) - 1;
+  let base = vec2i( position.xy ) * 2;
+
+  var color = vec4f( 0.0 );
+  for ( var y = 0; y < 2; y++ )
+  {
+    for ( var x = 0; x < 2; x++ )
+    {
+      color += textureLoad( source, min( base + vec2i( x, y ), last ), 0 );
+    }
+  }
+
+  return color * 0.25;
+}
@@ -0,0 +1,17 @@
+
+#[ allow( unused_imports ) ]
+use test_tools::exposed::*;
+#[ allow( unused_imports ) ]
+use minwebgpu as the_module;
+
+mod tests
+{
+  #[ allow( unused_imports ) ]
+  use super::*;
+
+  mod preprocessor_test;
+  mod compute_pass_test;
+  #[ cfg( feature = "derive" ) ]
+  mod as_bind_group_test;
+
+}
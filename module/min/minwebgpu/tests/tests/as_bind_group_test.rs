@@ -0,0 +1,88 @@
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::{ AsBindGroup, web_sys };
+
+#[ allow( dead_code ) ]
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  #[ uniform( vertex, fragment, ty = "MaterialFactors" ) ]
+  factors : &'a web_sys::GpuBuffer,
+  #[ storage( compute, read_only ) ]
+  lights : &'a web_sys::GpuBuffer,
+  #[ texture( fragment, dimension = "cube" ) ]
+  environment : &'a web_sys::GpuTextureView,
+  #[ texture( fragment, depth ) ]
+  shadow_map : &'a web_sys::GpuTextureView,
+  #[ sampler( fragment, comparison ) ]
+  shadow_sampler : &'a web_sys::GpuSampler,
+  #[ sampler( fragment ) ]
+  linear_sampler : &'a web_sys::GpuSampler,
+}
+
+/// Property of a JS object, `undefined` if it's missing
+fn get( value : &the_module::JsValue, key : &str ) -> the_module::JsValue
+{
+  the_module::js_sys::Reflect::get( value, &key.into() ).unwrap()
+}
+
+#[ test ]
+fn layout_entries()
+{
+  use web_sys::gpu_shader_stage::{ VERTEX, FRAGMENT, COMPUTE };
+
+  let entries : Vec< web_sys::GpuBindGroupLayoutEntry > = Material::layout_entries().iter().map( Into::into ).collect();
+  assert_eq!( entries.len(), 6 );
+
+  // Binding index, visibility, resource kind and the expected properties of the resource
+  let exp : [ ( u32, u32, &str, &[ ( &str, &str ) ] ); 6 ] =
+  [
+    ( 0, VERTEX | FRAGMENT, "buffer", &[ ( "type", "uniform" ) ] ),
+    ( 1, COMPUTE, "buffer", &[ ( "type", "read-only-storage" ) ] ),
+    ( 2, FRAGMENT, "texture", &[ ( "sampleType", "float" ), ( "viewDimension", "cube" ) ] ),
+    ( 3, FRAGMENT, "texture", &[ ( "sampleType", "depth" ), ( "viewDimension", "2d" ) ] ),
+    ( 4, FRAGMENT, "sampler", &[ ( "type", "comparison" ) ] ),
+    ( 5, FRAGMENT, "sampler", &[ ( "type", "filtering" ) ] ),
+  ];
+
+  for ( entry, ( binding, visibility, kind, properties ) ) in entries.iter().zip( exp )
+  {
+    assert_eq!( get( entry, "binding" ).as_f64(), Some( binding as f64 ) );
+    assert_eq!( get( entry, "visibility" ).as_f64(), Some( visibility as f64 ), "visibility of binding {binding}" );
+    for other in [ "buffer", "texture", "sampler", "storageTexture", "externalTexture" ]
+    {
+      assert_eq!( get( entry, other ).is_undefined(), other != kind, "{other} of binding {binding}" );
+    }
+
+    let resource = get( entry, kind );
+    for ( key, value ) in properties
+    {
+      assert_eq!( get( &resource, key ).as_string().as_deref(), Some( *value ), "{key} of binding {binding}" );
+    }
+    if kind == "texture"
+    {
+      assert_ne!( get( &resource, "multisampled" ).as_bool(), Some( true ) );
+    }
+  }
+}
+
+#[ test ]
+fn wgsl()
+{
+  let exp = "\
+@group( 1 ) @binding( 0 ) var< uniform > factors : MaterialFactors;
+@group( 1 ) @binding( 1 ) var< storage, read > lights : Lights;
+@group( 1 ) @binding( 2 ) var environment : texture_cube< f32 >;
+@group( 1 ) @binding( 3 ) var shadow_map : texture_depth_2d;
+@group( 1 ) @binding( 4 ) var shadow_sampler : sampler_comparison;
+@group( 1 ) @binding( 5 ) var linear_sampler : sampler;
+";
+  assert_eq!( Material::wgsl( 1 ), exp );
+}
+
+#[ test ]
+fn failures()
+{
+  let t = trybuild::TestCases::new();
+  t.compile_fail( "tests/ui/*.rs" );
+}
@@ -0,0 +1,17 @@
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::compute_pass::workgroup_count;
+
+#[ test ]
+fn covers_size()
+{
+  assert_eq!( workgroup_count( [ 64, 65, 1 ], [ 64, 64, 1 ] ), [ 1, 2, 1 ] );
+  assert_eq!( workgroup_count( [ 0, 1, 7 ], [ 8, 8, 4 ] ), [ 0, 1, 2 ] );
+}
+
+#[ test ]
+#[ should_panic( expected = "workgroup size must be non-zero" ) ]
+fn zero_workgroup_size()
+{
+  workgroup_count( [ 16, 16, 1 ], [ 8, 0, 1 ] );
+}
@@ -0,0 +1,94 @@
+#[ allow( unused_imports ) ]
+use super::*;
+use the_module::shader::{ Preprocessor, SourceLocation };
+use the_module::error::ShaderError;
+
+fn lines( code : &str ) -> Vec< &str >
+{
+  code.lines().map( str::trim ).collect()
+}
+
+#[ test ]
+fn nested_conditionals()
+{
+  let source = "\
+a
+#ifdef X
+b
+#ifndef Y
+c
+#else
+d
+#endif
+#else
+e
+#ifdef Y
+f
+#endif
+#endif
+g";
+  let p = Preprocessor::new();
+
+  let got = p.process( source, &[] ).unwrap();
+  assert_eq!( lines( &got.code ), [ "a", "e", "g" ] );
+
+  let got = p.process( source, &[ "X" ] ).unwrap();
+  assert_eq!( lines( &got.code ), [ "a", "b", "c", "g" ] );
+
+  let got = p.process( source, &[ "X", "Y" ] ).unwrap();
+  assert_eq!( lines( &got.code ), [ "a", "b", "d", "g" ] );
+
+  let got = p.process( source, &[ "Y" ] ).unwrap();
+  assert_eq!( lines( &got.code ), [ "a", "e", "f", "g" ] );
+}
+
+#[ test ]
+fn unbalanced_conditionals()
+{
+  let p = Preprocessor::new();
+  assert!( matches!( p.process( "#ifdef X\na", &[] ), Err( ShaderError::UnbalancedConditional( _ ) ) ) );
+  assert!( matches!( p.process( "a\n#endif", &[] ), Err( ShaderError::UnbalancedConditional( _ ) ) ) );
+  assert!( matches!( p.process( "#ifdef X\n#else\n#else\n#endif", &[] ), Err( ShaderError::UnbalancedConditional( _ ) ) ) );
+  assert!( matches!( p.process( "#define X", &[] ), Err( ShaderError::UnknownDirective( _ ) ) ) );
+}
+
+#[ test ]
+fn missing_include()
+{
+  let p = Preprocessor::new().snippet( "a", "x" );
+  let got = p.process( "#include \"b\"", &[] );
+  assert!( matches!( got, Err( ShaderError::IncludeNotFound( ref e ) ) if e.starts_with( "b at <root>:1" ) ) );
+
+  // Includes of dropped branches are not resolved
+  let got = p.process( "#ifdef X\n#include \"b\"\n#endif", &[] ).unwrap();
+  assert_eq!( got.code, "" );
+}
+
+#[ test ]
+fn include_cycle()
+{
+  let p = Preprocessor::new()
+  .snippet( "a", "#include \"b\"\nfn a() {}" )
+  .snippet( "b", "#include \"a\"\nfn b() {}" );
+
+  // Every snippet is inserted once, so the cycle ends
+  let got = p.process( "#include \"a\"\n#include \"b\"\nfn main() {}", &[] ).unwrap();
+  assert_eq!( lines( &got.code ), [ "fn b() {}", "fn a() {}", "fn main() {}" ] );
+}
+
+#[ test ]
+fn line_map()
+{
+  let p = Preprocessor::new().snippet( "common", "// common\nfn common() {}" );
+  let got = p.process( "// root\n#include \"common\"\nfn main() {}", &[] ).unwrap();
+
+  assert_eq!( lines( &got.code ), [ "// root", "// common", "fn common() {}", "fn main() {}" ] );
+  let root = | line | SourceLocation { snippet : None, line };
+  let common = | line | SourceLocation { snippet : Some( "common".to_string() ), line };
+  assert_eq!( got.line_map, [ root( 1 ), common( 1 ), common( 2 ), root( 3 ) ] );
+
+  assert_eq!( got.location( 3 ), Some( &common( 2 ) ) );
+  assert_eq!( got.location( 4 ), Some( &root( 3 ) ) );
+  assert_eq!( got.location( 0 ), None );
+  assert_eq!( got.location( 5 ), None );
+}
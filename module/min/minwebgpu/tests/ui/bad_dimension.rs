@@ -0,0 +1,10 @@
+use minwebgpu::{ AsBindGroup, web_sys };
+
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  #[ texture( fragment, dimension = "4d" ) ]
+  albedo : &'a web_sys::GpuTextureView,
+}
+
+fn main() {}
@@ -0,0 +1,10 @@
+use minwebgpu::{ AsBindGroup, web_sys };
+
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  #[ texture( fragment, depth, dimension = "1d" ) ]
+  shadow : &'a web_sys::GpuTextureView,
+}
+
+fn main() {}
@@ -0,0 +1,11 @@
+use minwebgpu::{ AsBindGroup, web_sys };
+
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  #[ uniform( fragment ) ]
+  #[ storage( fragment ) ]
+  factors : &'a web_sys::GpuBuffer,
+}
+
+fn main() {}
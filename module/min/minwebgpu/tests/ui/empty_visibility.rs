@@ -0,0 +1,10 @@
+use minwebgpu::{ AsBindGroup, web_sys };
+
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  #[ sampler( comparison ) ]
+  shadow_sampler : &'a web_sys::GpuSampler,
+}
+
+fn main() {}
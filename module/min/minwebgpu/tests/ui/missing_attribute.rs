@@ -0,0 +1,9 @@
+use minwebgpu::{ AsBindGroup, web_sys };
+
+#[ derive( AsBindGroup ) ]
+struct Material< 'a >
+{
+  factors : &'a web_sys::GpuBuffer,
+}
+
+fn main() {}
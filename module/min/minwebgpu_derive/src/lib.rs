@@ -0,0 +1,316 @@
+#![ doc = include_str!( "../readme.md" ) ]
+
+use proc_macro2::TokenStream;
+use quote::{ quote, format_ident };
+use syn::{ parse_macro_input, spanned::Spanned, DeriveInput, Data, Fields, Ident, LitStr, Error };
+
+/// Implements `minwebgpu::AsBindGroup` for a struct whose fields are the resources of a bind group.
+///
+/// Bindings are numbered in the order of the fields. Every field has exactly one of the attributes,
+/// which lists the shader stages the binding is visible to ( `vertex`, `fragment`, `compute` ) and its options:
+///
+/// - `#[ uniform( .., ty = "Material" ) ]` - uniform buffer. `ty` is the WGSL type, defaults to the field name in PascalCase.
+/// - `#[ storage( .., read_only, ty = "array< f32 >" ) ]` - storage buffer, `read_write` unless `read_only`.
+/// - `#[ texture( .., unfilterable | depth | sint | uint, multisampled, dimension = "cube" ) ]` - sampled texture.
+///   The sample type defaults to filterable float, the dimension to `2d`. Other dimensions are `1d`, `2d_array`, `cube`, `cube_array` and `3d`.
+///   Depth textures can't be `1d` or `3d`, multisampled textures must be `2d`.
+/// - `#[ sampler( .., non_filtering | comparison ) ]` - sampler, filtering by default.
+///
+/// ```rust, ignore
+/// #[ derive( gl::AsBindGroup ) ]
+/// struct Material< 'a >
+/// {
+///   #[ uniform( fragment, ty = "MaterialFactors" ) ]
+///   factors : &'a gl::web_sys::GpuBuffer,
+///   #[ texture( fragment ) ]
+///   albedo : &'a gl::web_sys::GpuTextureView,
+///   #[ sampler( fragment ) ]
+///   albedo_sampler : &'a gl::web_sys::GpuSampler,
+/// }
+/// ```
+#[ proc_macro_derive( AsBindGroup, attributes( uniform, storage, texture, sampler ) ) ]
+pub fn as_bind_group( input : proc_macro::TokenStream ) -> proc_macro::TokenStream
+{
+  let input = parse_macro_input!( input as DeriveInput );
+  as_bind_group_impl( &input ).unwrap_or_else( Error::into_compile_error ).into()
+}
+
+enum Kind
+{
+  Uniform,
+  Storage { read_only : bool },
+  Texture { sample : Sample, multisampled : bool, dimension : String },
+  Sampler { sampler : SamplerKind },
+}
+
+#[ derive( Clone, Copy ) ]
+enum Sample
+{
+  Float,
+  Unfilterable,
+  Depth,
+  Sint,
+  Uint,
+}
+
+#[ derive( Clone, Copy ) ]
+enum SamplerKind
+{
+  Filtering,
+  NonFiltering,
+  Comparison,
+}
+
+struct Binding
+{
+  field : Ident,
+  kind : Kind,
+  visibility : Vec< Ident >,
+  ty : Option< String >,
+}
+
+const DIMENSIONS : [ &str; 6 ] = [ "1d", "2d", "2d_array", "cube", "cube_array", "3d" ];
+
+fn as_bind_group_impl( input : &DeriveInput ) -> syn::Result< TokenStream >
+{
+  let Data::Struct( data ) = &input.data else
+  {
+    return Err( Error::new( input.span(), "AsBindGroup can be derived only for structs" ) );
+  };
+  let Fields::Named( fields ) = &data.fields else
+  {
+    return Err( Error::new( input.span(), "AsBindGroup requires named fields" ) );
+  };
+
+  let bindings = fields.named.iter()
+  .map( | f | parse_field( f.ident.clone().expect( "Named field" ), &f.attrs, f.span() ) )
+  .collect::< syn::Result< Vec< _ > > >()?;
+
+  let mut entries = Vec::new();
+  let mut declarations = Vec::new();
+  let mut resources = Vec::new();
+  for ( i, binding ) in bindings.iter().enumerate()
+  {
+    let i = i as u32;
+    entries.push( layout_entry( i, binding ) );
+    declarations.push( format!( "@binding( {} ) {}", i, declaration( binding ) ) );
+    let field = &binding.field;
+    resources.push( quote!{ ::minwebgpu::BindingResource::binding_resource( &self.#field ) } );
+  }
+
+  let name = &input.ident;
+  let ( impl_generics, ty_generics, where_clause ) = input.generics.split_for_impl();
+
+  Ok
+  (
+    quote!
+    {
+      impl #impl_generics ::minwebgpu::AsBindGroup for #name #ty_generics #where_clause
+      {
+        fn layout_entries() -> ::std::vec::Vec< ::minwebgpu::BindGroupLayoutEntry >
+        {
+          ::std::vec![ #( #entries ),* ]
+        }
+
+        fn wgsl( group : u32 ) -> ::std::string::String
+        {
+          [ #( #declarations ),* ].iter()
+          .map( | d | ::std::format!( "@group( {} ) {}\n", group, d ) )
+          .collect()
+        }
+
+        fn resources( &self ) -> ::std::vec::Vec< ::minwebgpu::JsValue >
+        {
+          ::std::vec![ #( #resources ),* ]
+        }
+      }
+    }
+  )
+}
+
+fn parse_field( field : Ident, attrs : &[ syn::Attribute ], span : proc_macro2::Span ) -> syn::Result< Binding >
+{
+  let mut result : Option< Binding > = None;
+
+  for attr in attrs
+  {
+    let mut kind =
+    if attr.path().is_ident( "uniform" ) { Kind::Uniform }
+    else if attr.path().is_ident( "storage" ) { Kind::Storage { read_only : false } }
+    else if attr.path().is_ident( "texture" ) { Kind::Texture { sample : Sample::Float, multisampled : false, dimension : "2d".into() } }
+    else if attr.path().is_ident( "sampler" ) { Kind::Sampler { sampler : SamplerKind::Filtering } }
+    else { continue };
+
+    if result.is_some()
+    {
+      return Err( Error::new( attr.span(), "Field can have only one binding attribute" ) );
+    }
+
+    let mut visibility = Vec::new();
+    let mut ty = None;
+
+    attr.parse_nested_meta
+    (
+      | meta |
+      {
+        let path = &meta.path;
+        if path.is_ident( "vertex" ) || path.is_ident( "fragment" ) || path.is_ident( "compute" )
+        {
+          visibility.push( path.get_ident().expect( "Single ident" ).clone() );
+          return Ok( () );
+        }
+
+        match &mut kind
+        {
+          Kind::Uniform | Kind::Storage { .. } if path.is_ident( "ty" ) =>
+          {
+            ty = Some( meta.value()?.parse::< LitStr >()?.value() );
+          }
+          Kind::Storage { read_only } if path.is_ident( "read_only" ) => *read_only = true,
+          Kind::Texture { sample, multisampled, dimension } =>
+          {
+            if path.is_ident( "multisampled" ) { *multisampled = true; }
+            else if path.is_ident( "unfilterable" ) { *sample = Sample::Unfilterable; }
+            else if path.is_ident( "depth" ) { *sample = Sample::Depth; }
+            else if path.is_ident( "sint" ) { *sample = Sample::Sint; }
+            else if path.is_ident( "uint" ) { *sample = Sample::Uint; }
+            else if path.is_ident( "dimension" )
+            {
+              let value = meta.value()?.parse::< LitStr >()?;
+              if !DIMENSIONS.contains( &value.value().as_str() )
+              {
+                return Err( Error::new( value.span(), format!( "Dimension must be one of {:?}", DIMENSIONS ) ) );
+              }
+              *dimension = value.value();
+            }
+            else { return Err( meta.error( "Unknown texture option" ) ); }
+          }
+          Kind::Sampler { sampler } if path.is_ident( "non_filtering" ) => *sampler = SamplerKind::NonFiltering,
+          Kind::Sampler { sampler } if path.is_ident( "comparison" ) => *sampler = SamplerKind::Comparison,
+          _ => return Err( meta.error( "Unknown binding option" ) ),
+        }
+
+        Ok( () )
+      }
+    )?;
+
+    if visibility.is_empty()
+    {
+      return Err( Error::new( attr.span(), "Binding must be visible to at least one stage: vertex, fragment or compute" ) );
+    }
+
+    // WGSL has no `texture_depth_1d`, `texture_depth_3d` and multisampled textures of other dimensions than `2d`
+    if let Kind::Texture { sample, multisampled, dimension } = &kind
+    {
+      if matches!( sample, Sample::Depth ) && ( dimension == "1d" || dimension == "3d" )
+      {
+        return Err( Error::new( attr.span(), format!( "Depth textures can't have dimension {}", dimension ) ) );
+      }
+      if *multisampled && dimension != "2d"
+      {
+        return Err( Error::new( attr.span(), "Multisampled textures must have dimension 2d" ) );
+      }
+    }
+
+    result = Some( Binding { field : field.clone(), kind, visibility, ty } );
+  }
+
+  result.ok_or_else
+  (
+    || Error::new( span, "Field needs one of the binding attributes: uniform, storage, texture or sampler" )
+  )
+}
+
+fn layout_entry( binding : u32, b : &Binding ) -> TokenStream
+{
+  let visibility = &b.visibility;
+  let ty = match &b.kind
+  {
+    Kind::Uniform => quote!{ ::minwebgpu::binding_type::buffer().uniform() },
+    Kind::Storage { read_only : false } => quote!{ ::minwebgpu::binding_type::buffer().storage() },
+    Kind::Storage { read_only : true } => quote!{ ::minwebgpu::binding_type::buffer().storage_readonly() },
+    Kind::Texture { sample, multisampled, dimension } =>
+    {
+      let sample = match sample
+      {
+        Sample::Float => format_ident!( "sample_float" ),
+        Sample::Unfilterable => format_ident!( "sample_unfilterable_float" ),
+        Sample::Depth => format_ident!( "sample_depth" ),
+        Sample::Sint => format_ident!( "sample_sint" ),
+        Sample::Uint => format_ident!( "sample_uint" ),
+      };
+      let dimension = format_ident!( "view_{}", dimension );
+      let multisampled = multisampled.then( || quote!{ .multisampled() } );
+      quote!{ ::minwebgpu::binding_type::texture().#sample().#dimension() #multisampled }
+    }
+    Kind::Sampler { sampler } =>
+    {
+      let sampler = match sampler
+      {
+        SamplerKind::Filtering => format_ident!( "filtering" ),
+        SamplerKind::NonFiltering => format_ident!( "non_filtering" ),
+        SamplerKind::Comparison => format_ident!( "comparison" ),
+      };
+      quote!{ ::minwebgpu::binding_type::sampler().#sampler() }
+    }
+  };
+
+  quote!
+  {
+    ::minwebgpu::layout::bind_group::entry()
+    .binding( #binding )
+    #( .#visibility() )*
+    .ty( #ty )
+  }
+}
+
+/// WGSL declaration of the binding, without the `@group` and `@binding` attributes
+fn declaration( b : &Binding ) -> String
+{
+  let name = b.field.to_string();
+  let ty = || b.ty.clone().unwrap_or_else( || pascal_case( &name ) );
+
+  match &b.kind
+  {
+    Kind::Uniform => format!( "var< uniform > {} : {};", name, ty() ),
+    Kind::Storage { read_only } =>
+    {
+      let access = if *read_only { "read" } else { "read_write" };
+      format!( "var< storage, {} > {} : {};", access, name, ty() )
+    }
+    Kind::Texture { sample, multisampled, dimension } =>
+    {
+      let ty = match ( sample, multisampled )
+      {
+        ( Sample::Depth, true ) => "texture_depth_multisampled_2d".to_string(),
+        ( Sample::Depth, false ) => format!( "texture_depth_{}", dimension ),
+        ( _, multisampled ) =>
+        {
+          let scalar = match sample
+          {
+            Sample::Sint => "i32",
+            Sample::Uint => "u32",
+            _ => "f32",
+          };
+          let texture = if *multisampled { "multisampled_2d" } else { dimension.as_str() };
+          format!( "texture_{}< {} >", texture, scalar )
+        }
+      };
+      format!( "var {} : {};", name, ty )
+    }
+    Kind::Sampler { sampler : SamplerKind::Comparison } => format!( "var {} : sampler_comparison;", name ),
+    Kind::Sampler { .. } => format!( "var {} : sampler;", name ),
+  }
+}
+
+fn pascal_case( name : &str ) -> String
+{
+  name.split( '_' )
+  .filter( | part | !part.is_empty() )
+  .map( | part |
+  {
+    let mut chars = part.chars();
+    chars.next().map( | c | c.to_uppercase().chain( chars ).collect::< String >() ).unwrap_or_default()
+  })
+  .collect()
+}